@@ -1,7 +1,7 @@
 use substreams_ethereum::Abigen;
 
 fn main() -> Result<(), anyhow::Error> {
-    let abis = vec!["tests"];
+    let abis = vec!["foundry_artifact", "tests"];
 
     for abi in abis {
         // All `path` arguments is relative to crate's Cargo.toml directory, in this example, it's 'abigen'
@@ -13,5 +13,58 @@ fn main() -> Result<(), anyhow::Error> {
             .write_to_file(&out_path)?;
     }
 
+    Abigen::new("address_types", "abi/address_types.json")?
+        .address_types(true)
+        .generate()?
+        .write_to_file("src/abi/address_types.rs")?;
+
+    Abigen::new("native_ints", "abi/native_ints.json")?
+        .native_ints(true)
+        .generate()?
+        .write_to_file("src/abi/native_ints.rs")?;
+
+    Abigen::from_signatures(&[
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "function balanceOf(address owner) view returns (uint256)",
+        "function transferFrom(address from, address to, uint256 amount) returns (bool)",
+        "error InsufficientBalance(uint256 available, uint256 required)",
+    ])?
+    .generate()?
+    .write_to_file("src/abi/signatures.rs")?;
+
+    Abigen::from_signatures(&[
+        "event OwnershipTransferred(address indexed previousOwner, address indexed newOwner)",
+        "function setName(string newName, bool isEnabled)",
+        "function owner() view returns (address)",
+    ])?
+    .add_import("serde::Serialize")
+    .add_derive("Eq")
+    .add_derive("Serialize")
+    .add_event_derive("OwnershipTransferred", "Hash")
+    .add_event_derive("OwnershipTransferred", "Default")
+    .add_function_attribute("setName", r#"serde(rename_all = "camelCase")"#)
+    .generate()?
+    .write_to_file("src/abi/derives.rs")?;
+
+    Abigen::from_signatures(&[
+        "event Deposit(address indexed owner, uint256 amount)",
+        "event Deposit(address indexed owner, uint256 amount, bytes data)",
+        "function safeTransferFrom(address from, address to, uint256 tokenId)",
+        "function safeTransferFrom(address from, address to, uint256 tokenId, bytes data)",
+    ])?
+    .typed_overload_names(true)
+    .rename_function(
+        "safeTransferFrom(address,address,uint256,bytes)",
+        "SafeTransferFromWithData",
+    )
+    .generate()?
+    .write_to_file("src/abi/overloads.rs")?;
+
+    let bindings = Abigen::new("proto", "abi/proto.json")?
+        .proto("contract.v1", "crate::pb::contract::v1")
+        .generate()?;
+    bindings.write_to_file("src/abi/proto.rs")?;
+    bindings.write_proto_to_file("proto/contract.proto")?;
+
     Ok(())
 }
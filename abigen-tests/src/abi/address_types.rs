@@ -0,0 +1,521 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's structs.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod structs {
+    use super::structs;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Entry {
+        pub owner: substreams_ethereum::Address,
+        pub node: substreams_ethereum::H256,
+    }
+}
+/// Contract deployed at an address, calling its `view` and `pure` functions through RPC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contract {
+    address: Vec<u8>,
+}
+impl Contract {
+    pub fn at<A: AsRef<[u8]>>(address: A) -> Self {
+        Self {
+            address: address.as_ref().to_vec(),
+        }
+    }
+    pub fn address(&self) -> &[u8] {
+        &self.address
+    }
+    /// Starts collecting calls to the contract into a single RPC batch.
+    pub fn batch(&self) -> ContractBatch {
+        self.batch_with(substreams_ethereum::rpc::RpcBatch::new())
+    }
+    /// Collects calls to the contract into an existing RPC `batch`, e.g. one holding
+    /// calls to other contracts.
+    pub fn batch_with(
+        &self,
+        batch: substreams_ethereum::rpc::RpcBatch,
+    ) -> ContractBatch {
+        ContractBatch {
+            address: self.address.clone(),
+            batch,
+        }
+    }
+    pub fn fun_addresses(
+        &self,
+        accounts: Vec<substreams_ethereum::Address>,
+        entry: structs::Entry,
+    ) -> Option<substreams_ethereum::H256> {
+        functions::FunAddresses {
+            accounts,
+            entry,
+        }
+            .call(self.address.clone())
+    }
+}
+/// Calls to a [Contract] collected into a single RPC batch, the responses are in
+/// the order of the calls and decode with `RpcBatch::decode`.
+pub struct ContractBatch {
+    address: Vec<u8>,
+    batch: substreams_ethereum::rpc::RpcBatch,
+}
+impl ContractBatch {
+    pub fn into_rpc_batch(self) -> substreams_ethereum::rpc::RpcBatch {
+        self.batch
+    }
+    pub fn execute(
+        self,
+    ) -> Result<substreams_ethereum::pb::eth::rpc::RpcResponses, String> {
+        self.batch.execute()
+    }
+    pub fn fun_addresses(
+        self,
+        accounts: Vec<substreams_ethereum::Address>,
+        entry: structs::Entry,
+    ) -> Self {
+        let batch = self
+            .batch
+            .add(
+                functions::FunAddresses {
+                    accounts,
+                    entry,
+                },
+                self.address.clone(),
+            );
+        Self {
+            address: self.address,
+            batch,
+        }
+    }
+}
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+    use super::structs;
+    /// Function `funAddresses(address[],(address,bytes32))`, selector `0x7c6660b9`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FunAddresses {
+        pub accounts: Vec<substreams_ethereum::Address>,
+        pub entry: structs::Entry,
+    }
+    impl FunAddresses {
+        const METHOD_ID: [u8; 4] = [124u8, 102u8, 96u8, 185u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funAddresses".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address)),
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Address,
+                                ethabi::ParamType::FixedBytes(32usize)
+                            ],
+                        ),
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funAddresses".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                accounts: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| substreams_ethereum::Address(
+                        inner.into_address().expect(INTERNAL_ERR).to_fixed_bytes(),
+                    ))
+                    .collect(),
+                entry: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    structs::Entry {
+                        owner: substreams_ethereum::Address(
+                            tuple_elements[0usize]
+                                .clone()
+                                .into_address()
+                                .expect(INTERNAL_ERR)
+                                .to_fixed_bytes(),
+                        ),
+                        node: substreams_ethereum::H256(
+                            tuple_elements[1usize]
+                                .clone()
+                                .into_fixed_bytes()
+                                .expect(INTERNAL_ERR)
+                                .try_into()
+                                .expect(INTERNAL_ERR),
+                        ),
+                    }
+                },
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    {
+                        let v = self
+                            .accounts
+                            .iter()
+                            .map(|inner| ethabi::Token::Address(
+                                ethabi::Address::from(inner.0),
+                            ))
+                            .collect();
+                        ethabi::Token::Array(v)
+                    },
+                    ethabi::Token::Tuple(
+                        vec![
+                            ethabi::Token::Address(ethabi::Address::from(self.entry.owner
+                            .0)), ethabi::Token::FixedBytes(self.entry.node.0.to_vec())
+                        ],
+                    ),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn output_call(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<substreams_ethereum::H256, substreams_ethereum::DecodeError> {
+            Self::output(call.return_data.as_ref())
+        }
+        pub fn output(
+            data: &[u8],
+        ) -> Result<substreams_ethereum::H256, substreams_ethereum::DecodeError> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::FixedBytes(32usize)],
+                    data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funAddresses".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            Ok(
+                substreams_ethereum::H256(
+                    values
+                        .pop()
+                        .expect("one output data should have existed")
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR)
+                        .try_into()
+                        .expect(INTERNAL_ERR),
+                ),
+            )
+        }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &substreams_ethereum::H256) -> Vec<u8> {
+            ethabi::encode(&[ethabi::Token::FixedBytes(value.0.to_vec())])
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+        pub fn call(&self, address: Vec<u8>) -> Option<substreams_ethereum::H256> {
+            use substreams_ethereum::pb::eth::rpc;
+            let rpc_calls = rpc::RpcCalls {
+                calls: vec![rpc::RpcCall { to_addr : address, data : self.encode(), }],
+            };
+            let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+            let response = responses.get(0).expect("one response should have existed");
+            if response.failed {
+                return None;
+            }
+            match Self::output(response.raw.as_ref()) {
+                Ok(data) => Some(data),
+                Err(err) => {
+                    use substreams_ethereum::Function;
+                    substreams::log::info!(
+                        "Call output for function `{}` failed to decode with error: {}",
+                        Self::NAME, err
+                    );
+                    None
+                }
+            }
+        }
+    }
+    impl substreams_ethereum::Function for FunAddresses {
+        const NAME: &'static str = "funAddresses";
+        const SIGNATURE: &'static str = "funAddresses(address[],(address,bytes32))";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address)),
+                ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::FixedBytes(32usize)])
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::FixedBytes(32usize)]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    impl substreams_ethereum::rpc::RPCDecodable<substreams_ethereum::H256>
+    for FunAddresses {
+        fn output(
+            data: &[u8],
+        ) -> Result<substreams_ethereum::H256, substreams_ethereum::DecodeError> {
+            Self::output(data)
+        }
+    }
+    /// All functions of the contract, use [Functions::decode_any] to decode any call
+    /// into the matching function.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Functions {
+        FunAddresses(FunAddresses),
+    }
+    impl Functions {
+        /// Decodes the `call` into the function whose selector matches the first 4 bytes
+        /// of the call's input. Returns `None` if no function of the contract matches.
+        pub fn decode_any(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = call
+                .input
+                .get(0..4)?
+                .try_into()
+                .expect(INTERNAL_ERR);
+            match selector {
+                FunAddresses::METHOD_ID => {
+                    Some(FunAddresses::decode(call).map(Functions::FunAddresses))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    use super::structs;
+    /// Event `Deployed(address,bytes32,address,bytes32)`, topic 0 `0xc503c7bd1701df8b8e079a6e87a5b46b1f6856c45dcf55f573a7d65ca9a048f1`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Deployed {
+        pub from: substreams_ethereum::Address,
+        pub salt: substreams_ethereum::H256,
+        pub to: substreams_ethereum::Address,
+        pub hash: substreams_ethereum::H256,
+    }
+    impl Deployed {
+        const TOPIC_ID: [u8; 32] = [
+            197u8,
+            3u8,
+            199u8,
+            189u8,
+            23u8,
+            1u8,
+            223u8,
+            139u8,
+            142u8,
+            7u8,
+            154u8,
+            110u8,
+            135u8,
+            165u8,
+            180u8,
+            107u8,
+            31u8,
+            104u8,
+            86u8,
+            196u8,
+            93u8,
+            207u8,
+            85u8,
+            245u8,
+            115u8,
+            167u8,
+            214u8,
+            92u8,
+            169u8,
+            160u8,
+            72u8,
+            241u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() != 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 3usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "Deployed".into(),
+                    expected: 3usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Address,
+                        ethabi::ParamType::FixedBytes(32usize),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Deployed".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                from: substreams_ethereum::Address(
+                    ethabi::decode(
+                            &[ethabi::ParamType::Address],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "Deployed".into(),
+                            param: Some("from".into()),
+                            reason: e.to_string(),
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_address()
+                        .expect(INTERNAL_ERR)
+                        .to_fixed_bytes(),
+                ),
+                salt: substreams_ethereum::H256(
+                    ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[2usize].as_ref(),
+                        )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "Deployed".into(),
+                            param: Some("salt".into()),
+                            reason: e.to_string(),
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR)
+                        .try_into()
+                        .expect(INTERNAL_ERR),
+                ),
+                to: substreams_ethereum::Address(
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_address()
+                        .expect(INTERNAL_ERR)
+                        .to_fixed_bytes(),
+                ),
+                hash: substreams_ethereum::H256(
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR)
+                        .try_into()
+                        .expect(INTERNAL_ERR),
+                ),
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from(self.from.0))]),
+                    ethabi::encode(& [ethabi::Token::FixedBytes(self.salt.0.to_vec())])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Address(ethabi::Address::from(self.to.0)),
+                        ethabi::Token::FixedBytes(self.hash.0.to_vec()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for Deployed {
+        const NAME: &'static str = "Deployed";
+        const SIGNATURE: &'static str = "Deployed(address,bytes32,address,bytes32)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::FixedBytes(32usize),
+                ethabi::ParamType::Address, ethabi::ParamType::FixedBytes(32usize)
+            ]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+    /// log into the matching event.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Events {
+        Deployed(Deployed),
+    }
+    impl Events {
+        /// Decodes the `log` into the event whose signature matches the log's first topic.
+        /// Returns `None` if no event of the contract matches the log, anonymous events
+        /// are never matched.
+        pub fn decode_any(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+            match topic_id {
+                Deployed::TOPIC_ID if Deployed::match_log(log) => {
+                    Some(Deployed::decode(log).map(Events::Deployed))
+                }
+                _ => None,
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,449 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract deployed at an address, calling its `view` and `pure` functions through RPC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contract {
+    address: Vec<u8>,
+}
+impl Contract {
+    pub fn at<A: AsRef<[u8]>>(address: A) -> Self {
+        Self {
+            address: address.as_ref().to_vec(),
+        }
+    }
+    pub fn address(&self) -> &[u8] {
+        &self.address
+    }
+    /// Starts collecting calls to the contract into a single RPC batch.
+    pub fn batch(&self) -> ContractBatch {
+        self.batch_with(substreams_ethereum::rpc::RpcBatch::new())
+    }
+    /// Collects calls to the contract into an existing RPC `batch`, e.g. one holding
+    /// calls to other contracts.
+    pub fn batch_with(
+        &self,
+        batch: substreams_ethereum::rpc::RpcBatch,
+    ) -> ContractBatch {
+        ContractBatch {
+            address: self.address.clone(),
+            batch,
+        }
+    }
+    pub fn owner(&self) -> Option<Vec<u8>> {
+        functions::Owner {}.call(self.address.clone())
+    }
+}
+/// Calls to a [Contract] collected into a single RPC batch, the responses are in
+/// the order of the calls and decode with `RpcBatch::decode`.
+pub struct ContractBatch {
+    address: Vec<u8>,
+    batch: substreams_ethereum::rpc::RpcBatch,
+}
+impl ContractBatch {
+    pub fn into_rpc_batch(self) -> substreams_ethereum::rpc::RpcBatch {
+        self.batch
+    }
+    pub fn execute(
+        self,
+    ) -> Result<substreams_ethereum::pb::eth::rpc::RpcResponses, String> {
+        self.batch.execute()
+    }
+    pub fn owner(self) -> Self {
+        let batch = self.batch.add(functions::Owner {}, self.address.clone());
+        Self {
+            address: self.address,
+            batch,
+        }
+    }
+}
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+    use serde::Serialize;
+    /// Function `owner()`, selector `0x8da5cb5b`.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct Owner {}
+    impl Owner {
+        const METHOD_ID: [u8; 4] = [141u8, 165u8, 203u8, 91u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Ok(Self {})
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(&[]);
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn output_call(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+            Self::output(call.return_data.as_ref())
+        }
+        pub fn output(data: &[u8]) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+            let mut values = ethabi::decode(&[ethabi::ParamType::Address], data.as_ref())
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "owner".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            Ok(
+                values
+                    .pop()
+                    .expect("one output data should have existed")
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+            )
+        }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &Vec<u8>) -> Vec<u8> {
+            ethabi::encode(
+                &[ethabi::Token::Address(ethabi::Address::from_slice(&value))],
+            )
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+        pub fn call(&self, address: Vec<u8>) -> Option<Vec<u8>> {
+            use substreams_ethereum::pb::eth::rpc;
+            let rpc_calls = rpc::RpcCalls {
+                calls: vec![rpc::RpcCall { to_addr : address, data : self.encode(), }],
+            };
+            let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+            let response = responses.get(0).expect("one response should have existed");
+            if response.failed {
+                return None;
+            }
+            match Self::output(response.raw.as_ref()) {
+                Ok(data) => Some(data),
+                Err(err) => {
+                    use substreams_ethereum::Function;
+                    substreams::log::info!(
+                        "Call output for function `{}` failed to decode with error: {}",
+                        Self::NAME, err
+                    );
+                    None
+                }
+            }
+        }
+    }
+    impl substreams_ethereum::Function for Owner {
+        const NAME: &'static str = "owner";
+        const SIGNATURE: &'static str = "owner()";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Address]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    impl substreams_ethereum::rpc::RPCDecodable<Vec<u8>> for Owner {
+        fn output(data: &[u8]) -> Result<Vec<u8>, substreams_ethereum::DecodeError> {
+            Self::output(data)
+        }
+    }
+    /// Function `setName(string,bool)`, selector `0x12b1415b`.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SetName {
+        pub new_name: String,
+        pub is_enabled: bool,
+    }
+    impl SetName {
+        const METHOD_ID: [u8; 4] = [18u8, 177u8, 65u8, 91u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "setName".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::String, ethabi::ParamType::Bool],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "setName".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                new_name: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+                is_enabled: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_bool()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::String(self.new_name.clone()),
+                    ethabi::Token::Bool(self.is_enabled.clone()),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+    }
+    impl substreams_ethereum::Function for SetName {
+        const NAME: &'static str = "setName";
+        const SIGNATURE: &'static str = "setName(string,bool)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String, ethabi::ParamType::Bool]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    /// All functions of the contract, use [Functions::decode_any] to decode any call
+    /// into the matching function.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Functions {
+        Owner(Owner),
+        SetName(SetName),
+    }
+    impl Functions {
+        /// Decodes the `call` into the function whose selector matches the first 4 bytes
+        /// of the call's input. Returns `None` if no function of the contract matches.
+        pub fn decode_any(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = call
+                .input
+                .get(0..4)?
+                .try_into()
+                .expect(INTERNAL_ERR);
+            match selector {
+                Owner::METHOD_ID => Some(Owner::decode(call).map(Functions::Owner)),
+                SetName::METHOD_ID => Some(SetName::decode(call).map(Functions::SetName)),
+                _ => None,
+            }
+        }
+    }
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    use serde::Serialize;
+    /// Event `OwnershipTransferred(address,address)`, topic 0 `0x8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0`.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Hash, Default)]
+    pub struct OwnershipTransferred {
+        pub previous_owner: Vec<u8>,
+        pub new_owner: Vec<u8>,
+    }
+    impl OwnershipTransferred {
+        const TOPIC_ID: [u8; 32] = [
+            139u8,
+            224u8,
+            7u8,
+            156u8,
+            83u8,
+            22u8,
+            89u8,
+            20u8,
+            19u8,
+            68u8,
+            205u8,
+            31u8,
+            208u8,
+            164u8,
+            242u8,
+            132u8,
+            25u8,
+            73u8,
+            127u8,
+            151u8,
+            34u8,
+            163u8,
+            218u8,
+            175u8,
+            227u8,
+            180u8,
+            24u8,
+            111u8,
+            107u8,
+            100u8,
+            87u8,
+            224u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() != 0usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 3usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "OwnershipTransferred".into(),
+                    expected: 3usize,
+                    actual: log.topics.len(),
+                });
+            }
+            Ok(Self {
+                previous_owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "OwnershipTransferred".into(),
+                        param: Some("previous_owner".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                new_owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "OwnershipTransferred".into(),
+                        param: Some("new_owner".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self
+                    .previous_owner))]), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self
+                    .new_owner))])
+                ],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for OwnershipTransferred {
+        const NAME: &'static str = "OwnershipTransferred";
+        const SIGNATURE: &'static str = "OwnershipTransferred(address,address)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Address, ethabi::ParamType::Address]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+    /// log into the matching event.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Events {
+        OwnershipTransferred(OwnershipTransferred),
+    }
+    impl Events {
+        /// Decodes the `log` into the event whose signature matches the log's first topic.
+        /// Returns `None` if no event of the contract matches the log, anonymous events
+        /// are never matched.
+        pub fn decode_any(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+            match topic_id {
+                OwnershipTransferred::TOPIC_ID if OwnershipTransferred::match_log(
+                    log,
+                ) => {
+                    Some(
+                        OwnershipTransferred::decode(log)
+                            .map(Events::OwnershipTransferred),
+                    )
+                }
+                _ => None,
+            }
+        }
+    }
+}
\ No newline at end of file
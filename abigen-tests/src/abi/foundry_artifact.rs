@@ -0,0 +1,438 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's constructor arguments, decoded from the input of the contract
+/// creation call which is the init bytecode followed by the ABI encoded arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constructor {
+    pub owner: Vec<u8>,
+    pub supply: substreams::scalar::BigInt,
+}
+impl Constructor {
+    /// Returns `true` if the call is a contract creation call.
+    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+        call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+    }
+    /// Decodes the constructor arguments of the contract creation `call` by removing
+    /// the known init `bytecode` prefix from the call's input.
+    pub fn decode_with_bytecode(
+        call: &substreams_ethereum::pb::eth::v2::Call,
+        bytecode: &[u8],
+    ) -> Result<Self, substreams_ethereum::DecodeError> {
+        match call.input.strip_prefix(bytecode) {
+            Some(data) => Self::decode_data(data),
+            None => {
+                Err(substreams_ethereum::DecodeError::BytecodeMismatch {
+                    item: "constructor".into(),
+                })
+            }
+        }
+    }
+    /// Decodes the constructor arguments of the contract creation `call` by reading
+    /// the ABI encoded tail found after the first `code_len` bytes of creation code.
+    pub fn decode_with_code_len(
+        call: &substreams_ethereum::pb::eth::v2::Call,
+        code_len: usize,
+    ) -> Result<Self, substreams_ethereum::DecodeError> {
+        match call.input.get(code_len..) {
+            Some(data) => Self::decode_data(data),
+            None => {
+                Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "constructor".into(),
+                    expected: code_len,
+                    actual: call.input.len(),
+                })
+            }
+        }
+    }
+    /// Decodes the ABI encoded constructor arguments, `data` must not contain
+    /// the init bytecode.
+    pub fn decode_data(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+        let mut values = ethabi::decode(
+                &[ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)],
+                data,
+            )
+            .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                item: "constructor".into(),
+                param: None,
+                reason: e.to_string(),
+            })?;
+        values.reverse();
+        Ok(Self {
+            owner: values
+                .pop()
+                .expect(INTERNAL_ERR)
+                .into_address()
+                .expect(INTERNAL_ERR)
+                .as_bytes()
+                .to_vec(),
+            supply: {
+                let mut v = [0 as u8; 32];
+                values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_uint()
+                    .expect(INTERNAL_ERR)
+                    .to_big_endian(v.as_mut_slice());
+                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+            },
+        })
+    }
+    /// Encodes the contract creation input, which is the init `bytecode` followed
+    /// by the ABI encoded constructor arguments.
+    pub fn encode(&self, bytecode: &[u8]) -> Vec<u8> {
+        let data = ethabi::encode(
+            &[
+                ethabi::Token::Address(ethabi::Address::from_slice(&self.owner)),
+                ethabi::Token::Uint(
+                    ethabi::Uint::from_big_endian(
+                        match self.supply.clone().to_bytes_be() {
+                            (num_bigint::Sign::Plus, bytes) => bytes,
+                            (num_bigint::Sign::NoSign, bytes) => bytes,
+                            (num_bigint::Sign::Minus, _) => {
+                                panic!("negative numbers are not supported")
+                            }
+                        }
+                            .as_slice(),
+                    ),
+                ),
+            ],
+        );
+        let mut encoded = Vec::with_capacity(bytecode.len() + data.len());
+        encoded.extend_from_slice(bytecode);
+        encoded.extend(data);
+        encoded
+    }
+}
+/// Contract's init bytecode, as found in the compilation artifact.
+pub const BYTECODE: &[u8] = b"`\x80`@R4\x80\x15`\x0FW`\0\x80\xFD[P`?\x80`\x1D`\09`\0\xF3\xFE";
+impl Constructor {
+    /// Decodes the constructor arguments of the contract creation `call`
+    /// by removing the contract's [BYTECODE] prefix from the call's input.
+    pub fn decode(
+        call: &substreams_ethereum::pb::eth::v2::Call,
+    ) -> Result<Self, substreams_ethereum::DecodeError> {
+        Self::decode_with_bytecode(call, BYTECODE)
+    }
+}
+/// Contract's runtime bytecode, as found in the compilation artifact.
+pub const DEPLOYED_BYTECODE: &[u8] = b"`\x80`@R`\0\x80\xFD\xFE\xA1dsolcC\0\x08\x14\0\n";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+    /// Mints `amount` tokens to `to`
+    ///
+    /// Only callable by the owner.
+    ///
+    /// Function `mint(address,uint256)`, selector `0x40c10f19`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Mint {
+        /// Receiver of the tokens
+        pub to: Vec<u8>,
+        /// Amount of tokens to mint
+        pub amount: substreams::scalar::BigInt,
+    }
+    impl Mint {
+        const METHOD_ID: [u8; 4] = [64u8, 193u8, 15u8, 25u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "mint".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "mint".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                to: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                amount: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.to)),
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match self.amount.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+    }
+    impl substreams_ethereum::Function for Mint {
+        const NAME: &'static str = "mint";
+        const SIGNATURE: &'static str = "mint(address,uint256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    /// All functions of the contract, use [Functions::decode_any] to decode any call
+    /// into the matching function.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Functions {
+        Mint(Mint),
+    }
+    impl Functions {
+        /// Decodes the `call` into the function whose selector matches the first 4 bytes
+        /// of the call's input. Returns `None` if no function of the contract matches.
+        pub fn decode_any(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = call
+                .input
+                .get(0..4)?
+                .try_into()
+                .expect(INTERNAL_ERR);
+            match selector {
+                Mint::METHOD_ID => Some(Mint::decode(call).map(Functions::Mint)),
+                _ => None,
+            }
+        }
+    }
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    /// Emitted when tokens are minted
+    ///
+    /// Event `Minted(address,uint256)`, topic 0 `0x30385c845b448a36257a6a1716e6ad2e1bc2cbe333cde1e69fe849ad6511adfe`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Minted {
+        /// Receiver of the tokens
+        pub to: Vec<u8>,
+        /// Amount of tokens minted
+        pub amount: substreams::scalar::BigInt,
+    }
+    impl Minted {
+        const TOPIC_ID: [u8; 32] = [
+            48u8,
+            56u8,
+            92u8,
+            132u8,
+            91u8,
+            68u8,
+            138u8,
+            54u8,
+            37u8,
+            122u8,
+            106u8,
+            23u8,
+            22u8,
+            230u8,
+            173u8,
+            46u8,
+            27u8,
+            194u8,
+            203u8,
+            227u8,
+            51u8,
+            205u8,
+            225u8,
+            230u8,
+            159u8,
+            232u8,
+            73u8,
+            173u8,
+            101u8,
+            17u8,
+            173u8,
+            254u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "Minted".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize)],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Minted".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                to: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "Minted".into(),
+                        param: Some("to".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                amount: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.to))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.amount.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for Minted {
+        const NAME: &'static str = "Minted";
+        const SIGNATURE: &'static str = "Minted(address,uint256)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+    /// log into the matching event.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Events {
+        Minted(Minted),
+    }
+    impl Events {
+        /// Decodes the `log` into the event whose signature matches the log's first topic.
+        /// Returns `None` if no event of the contract matches the log, anonymous events
+        /// are never matched.
+        pub fn decode_any(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+            match topic_id {
+                Minted::TOPIC_ID if Minted::match_log(log) => {
+                    Some(Minted::decode(log).map(Events::Minted))
+                }
+                _ => None,
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -1,4 +1,32 @@
 #[rustfmt::skip]
 #[allow(dead_code)]
 #[allow(unused_variables)]
+pub mod address_types;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod derives;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod foundry_artifact;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod native_ints;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod overloads;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod proto;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+pub mod signatures;
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(unused_variables)]
 pub mod tests;
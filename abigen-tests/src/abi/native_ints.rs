@@ -0,0 +1,683 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's structs.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod structs {
+    use super::structs;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Fee {
+        pub tier: u32,
+        pub offset: i128,
+    }
+}
+/// Contract deployed at an address, calling its `view` and `pure` functions through RPC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contract {
+    address: Vec<u8>,
+}
+impl Contract {
+    pub fn at<A: AsRef<[u8]>>(address: A) -> Self {
+        Self {
+            address: address.as_ref().to_vec(),
+        }
+    }
+    pub fn address(&self) -> &[u8] {
+        &self.address
+    }
+    /// Starts collecting calls to the contract into a single RPC batch.
+    pub fn batch(&self) -> ContractBatch {
+        self.batch_with(substreams_ethereum::rpc::RpcBatch::new())
+    }
+    /// Collects calls to the contract into an existing RPC `batch`, e.g. one holding
+    /// calls to other contracts.
+    pub fn batch_with(
+        &self,
+        batch: substreams_ethereum::rpc::RpcBatch,
+    ) -> ContractBatch {
+        ContractBatch {
+            address: self.address.clone(),
+            batch,
+        }
+    }
+    pub fn fun_native_ints(
+        &self,
+        small: u8,
+        pair: [i16; 2usize],
+        fee: structs::Fee,
+    ) -> Option<u64> {
+        functions::FunNativeInts {
+            small,
+            pair,
+            fee,
+        }
+            .call(self.address.clone())
+    }
+}
+/// Calls to a [Contract] collected into a single RPC batch, the responses are in
+/// the order of the calls and decode with `RpcBatch::decode`.
+pub struct ContractBatch {
+    address: Vec<u8>,
+    batch: substreams_ethereum::rpc::RpcBatch,
+}
+impl ContractBatch {
+    pub fn into_rpc_batch(self) -> substreams_ethereum::rpc::RpcBatch {
+        self.batch
+    }
+    pub fn execute(
+        self,
+    ) -> Result<substreams_ethereum::pb::eth::rpc::RpcResponses, String> {
+        self.batch.execute()
+    }
+    pub fn fun_native_ints(
+        self,
+        small: u8,
+        pair: [i16; 2usize],
+        fee: structs::Fee,
+    ) -> Self {
+        let batch = self
+            .batch
+            .add(
+                functions::FunNativeInts {
+                    small,
+                    pair,
+                    fee,
+                },
+                self.address.clone(),
+            );
+        Self {
+            address: self.address,
+            batch,
+        }
+    }
+}
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+    use super::structs;
+    /// Function `funNativeInts(uint8,int16[2],(uint24,int128))`, selector `0x4a43dd81`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FunNativeInts {
+        pub small: u8,
+        pub pair: [i16; 2usize],
+        pub fee: structs::Fee,
+    }
+    impl FunNativeInts {
+        const METHOD_ID: [u8; 4] = [74u8, 67u8, 221u8, 129u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funNativeInts".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Uint(8usize),
+                        ethabi::ParamType::FixedArray(
+                            Box::new(ethabi::ParamType::Int(16usize)),
+                            2usize,
+                        ),
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Uint(24usize),
+                                ethabi::ParamType::Int(128usize)
+                            ],
+                        ),
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funNativeInts".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                small: {
+                    let v = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR);
+                    if v.bits() > 8usize {
+                        return Err(substreams_ethereum::DecodeError::Overflow {
+                            item: "funNativeInts".into(),
+                            param: "small".into(),
+                            kind: "uint8",
+                            value: format!("{:#x}", v),
+                        });
+                    }
+                    v.low_u128() as u8
+                },
+                pair: {
+                    let mut iter = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_array()
+                        .expect(INTERNAL_ERR)
+                        .into_iter()
+                        .map(|inner| -> Result<_, substreams_ethereum::DecodeError> {
+                            Ok({
+                                let v = inner.into_int().expect(INTERNAL_ERR);
+                                let magnitude = if v.bit(255) { !v } else { v };
+                                if magnitude.bits() >= 16usize {
+                                    return Err(substreams_ethereum::DecodeError::Overflow {
+                                        item: "funNativeInts".into(),
+                                        param: "pair".into(),
+                                        kind: "int16",
+                                        value: format!("{:#x}", v),
+                                    });
+                                }
+                                v.low_u128() as i16
+                            })
+                        });
+                    [
+                        iter.next().expect(INTERNAL_ERR)?,
+                        iter.next().expect(INTERNAL_ERR)?,
+                    ]
+                },
+                fee: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    structs::Fee {
+                        tier: {
+                            let v = tuple_elements[0usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR);
+                            if v.bits() > 24usize {
+                                return Err(substreams_ethereum::DecodeError::Overflow {
+                                    item: "funNativeInts".into(),
+                                    param: "fee".into(),
+                                    kind: "uint24",
+                                    value: format!("{:#x}", v),
+                                });
+                            }
+                            v.low_u128() as u32
+                        },
+                        offset: {
+                            let v = tuple_elements[1usize]
+                                .clone()
+                                .into_int()
+                                .expect(INTERNAL_ERR);
+                            let magnitude = if v.bit(255) { !v } else { v };
+                            if magnitude.bits() >= 128usize {
+                                return Err(substreams_ethereum::DecodeError::Overflow {
+                                    item: "funNativeInts".into(),
+                                    param: "fee".into(),
+                                    kind: "int128",
+                                    value: format!("{:#x}", v),
+                                });
+                            }
+                            v.low_u128() as i128
+                        },
+                    }
+                },
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Uint(ethabi::Uint::from(self.small.to_owned())),
+                    {
+                        let v = self
+                            .pair
+                            .iter()
+                            .map(|inner| {
+                                let v = i128::from(inner.to_owned());
+                                let mut full_signed_bytes = [if v < 0 {
+                                    0xff
+                                } else {
+                                    0x00
+                                }; 32];
+                                full_signed_bytes[16..].copy_from_slice(&v.to_be_bytes());
+                                ethabi::Token::Int(
+                                    ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
+                                )
+                            })
+                            .collect();
+                        ethabi::Token::FixedArray(v)
+                    },
+                    ethabi::Token::Tuple(
+                        vec![
+                            ethabi::Token::Uint(ethabi::Uint::from(self.fee.tier
+                            .to_owned())), { let v = self.fee.offset.to_owned(); let mut
+                            full_signed_bytes = [if v < 0 { 0xff } else { 0x00 }; 32];
+                            full_signed_bytes[16..].copy_from_slice(& v.to_be_bytes());
+                            ethabi::Token::Int(ethabi::Int::from_big_endian(full_signed_bytes
+                            .as_ref())) }
+                        ],
+                    ),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn output_call(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<u64, substreams_ethereum::DecodeError> {
+            Self::output(call.return_data.as_ref())
+        }
+        pub fn output(data: &[u8]) -> Result<u64, substreams_ethereum::DecodeError> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(64usize)],
+                    data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funNativeInts".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            Ok({
+                let v = values
+                    .pop()
+                    .expect("one output data should have existed")
+                    .into_uint()
+                    .expect(INTERNAL_ERR);
+                if v.bits() > 64usize {
+                    return Err(substreams_ethereum::DecodeError::Overflow {
+                        item: "funNativeInts".into(),
+                        param: "output0".into(),
+                        kind: "uint64",
+                        value: format!("{:#x}", v),
+                    });
+                }
+                v.low_u128() as u64
+            })
+        }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &u64) -> Vec<u8> {
+            ethabi::encode(&[ethabi::Token::Uint(ethabi::Uint::from(value.to_owned()))])
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+        pub fn call(&self, address: Vec<u8>) -> Option<u64> {
+            use substreams_ethereum::pb::eth::rpc;
+            let rpc_calls = rpc::RpcCalls {
+                calls: vec![rpc::RpcCall { to_addr : address, data : self.encode(), }],
+            };
+            let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+            let response = responses.get(0).expect("one response should have existed");
+            if response.failed {
+                return None;
+            }
+            match Self::output(response.raw.as_ref()) {
+                Ok(data) => Some(data),
+                Err(err) => {
+                    use substreams_ethereum::Function;
+                    substreams::log::info!(
+                        "Call output for function `{}` failed to decode with error: {}",
+                        Self::NAME, err
+                    );
+                    None
+                }
+            }
+        }
+    }
+    impl substreams_ethereum::Function for FunNativeInts {
+        const NAME: &'static str = "funNativeInts";
+        const SIGNATURE: &'static str = "funNativeInts(uint8,int16[2],(uint24,int128))";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Uint(8usize),
+                ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Int(16usize)),
+                2usize), ethabi::ParamType::Tuple(vec![ethabi::ParamType::Uint(24usize),
+                ethabi::ParamType::Int(128usize)])
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(64usize)]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    impl substreams_ethereum::rpc::RPCDecodable<u64> for FunNativeInts {
+        fn output(data: &[u8]) -> Result<u64, substreams_ethereum::DecodeError> {
+            Self::output(data)
+        }
+    }
+    /// All functions of the contract, use [Functions::decode_any] to decode any call
+    /// into the matching function.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Functions {
+        FunNativeInts(FunNativeInts),
+    }
+    impl Functions {
+        /// Decodes the `call` into the function whose selector matches the first 4 bytes
+        /// of the call's input. Returns `None` if no function of the contract matches.
+        pub fn decode_any(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = call
+                .input
+                .get(0..4)?
+                .try_into()
+                .expect(INTERNAL_ERR);
+            match selector {
+                FunNativeInts::METHOD_ID => {
+                    Some(FunNativeInts::decode(call).map(Functions::FunNativeInts))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    use super::structs;
+    /// Event `Tick(int24,uint8,uint128,int8[],uint256)`, topic 0 `0x173a104a4f8d5b8ea492ee5e2501044a3f5c28c74daafbd3a3a88e7a36245be9`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Tick {
+        pub tick: i32,
+        pub decimals: u8,
+        pub liquidity: u128,
+        pub deltas: Vec<i8>,
+        pub amount: substreams::scalar::BigInt,
+    }
+    impl Tick {
+        const TOPIC_ID: [u8; 32] = [
+            23u8,
+            58u8,
+            16u8,
+            74u8,
+            79u8,
+            141u8,
+            91u8,
+            142u8,
+            164u8,
+            146u8,
+            238u8,
+            94u8,
+            37u8,
+            1u8,
+            4u8,
+            74u8,
+            63u8,
+            92u8,
+            40u8,
+            199u8,
+            77u8,
+            170u8,
+            251u8,
+            211u8,
+            163u8,
+            168u8,
+            142u8,
+            122u8,
+            54u8,
+            36u8,
+            91u8,
+            233u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() < 128usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 3usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "Tick".into(),
+                    expected: 3usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Uint(128usize),
+                        ethabi::ParamType::Array(
+                            Box::new(ethabi::ParamType::Int(8usize)),
+                        ),
+                        ethabi::ParamType::Uint(256usize),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Tick".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                tick: {
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::Int(24usize)],
+                            log.topics[1usize].as_ref(),
+                        )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "Tick".into(),
+                            param: Some("tick".into()),
+                            reason: e.to_string(),
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_int()
+                        .expect(INTERNAL_ERR);
+                    let magnitude = if v.bit(255) { !v } else { v };
+                    if magnitude.bits() >= 24usize {
+                        return Err(substreams_ethereum::DecodeError::Overflow {
+                            item: "Tick".into(),
+                            param: "tick".into(),
+                            kind: "int24",
+                            value: format!("{:#x}", v),
+                        });
+                    }
+                    v.low_u128() as i32
+                },
+                decimals: {
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::Uint(8usize)],
+                            log.topics[2usize].as_ref(),
+                        )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "Tick".into(),
+                            param: Some("decimals".into()),
+                            reason: e.to_string(),
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR);
+                    if v.bits() > 8usize {
+                        return Err(substreams_ethereum::DecodeError::Overflow {
+                            item: "Tick".into(),
+                            param: "decimals".into(),
+                            kind: "uint8",
+                            value: format!("{:#x}", v),
+                        });
+                    }
+                    v.low_u128() as u8
+                },
+                liquidity: {
+                    let v = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR);
+                    if v.bits() > 128usize {
+                        return Err(substreams_ethereum::DecodeError::Overflow {
+                            item: "Tick".into(),
+                            param: "liquidity".into(),
+                            kind: "uint128",
+                            value: format!("{:#x}", v),
+                        });
+                    }
+                    v.low_u128()
+                },
+                deltas: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| -> Result<_, substreams_ethereum::DecodeError> {
+                        Ok({
+                            let v = inner.into_int().expect(INTERNAL_ERR);
+                            let magnitude = if v.bit(255) { !v } else { v };
+                            if magnitude.bits() >= 8usize {
+                                return Err(substreams_ethereum::DecodeError::Overflow {
+                                    item: "Tick".into(),
+                                    param: "deltas".into(),
+                                    kind: "int8",
+                                    value: format!("{:#x}", v),
+                                });
+                            }
+                            v.low_u128() as i8
+                        })
+                    })
+                    .collect::<Result<_, substreams_ethereum::DecodeError>>()?,
+                amount: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(& [{ let v = i128::from(self
+                    .tick.to_owned()); let mut full_signed_bytes = [if v < 0 { 0xff }
+                    else { 0x00 }; 32]; full_signed_bytes[16..].copy_from_slice(& v
+                    .to_be_bytes());
+                    ethabi::Token::Int(ethabi::Int::from_big_endian(full_signed_bytes
+                    .as_ref())) }]), ethabi::encode(&
+                    [ethabi::Token::Uint(ethabi::Uint::from(self.decimals.to_owned()))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from(self.liquidity.to_owned()),
+                        ),
+                        {
+                            let v = self
+                                .deltas
+                                .iter()
+                                .map(|inner| {
+                                    let v = i128::from(inner.to_owned());
+                                    let mut full_signed_bytes = [if v < 0 {
+                                        0xff
+                                    } else {
+                                        0x00
+                                    }; 32];
+                                    full_signed_bytes[16..].copy_from_slice(&v.to_be_bytes());
+                                    ethabi::Token::Int(
+                                        ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
+                                    )
+                                })
+                                .collect();
+                            ethabi::Token::Array(v)
+                        },
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.amount.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for Tick {
+        const NAME: &'static str = "Tick";
+        const SIGNATURE: &'static str = "Tick(int24,uint8,uint128,int8[],uint256)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Int(24usize), ethabi::ParamType::Uint(8usize),
+                ethabi::ParamType::Uint(128usize),
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Int(8usize))),
+                ethabi::ParamType::Uint(256usize)
+            ]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+    /// log into the matching event.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Events {
+        Tick(Tick),
+    }
+    impl Events {
+        /// Decodes the `log` into the event whose signature matches the log's first topic.
+        /// Returns `None` if no event of the contract matches the log, anonymous events
+        /// are never matched.
+        pub fn decode_any(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+            match topic_id {
+                Tick::TOPIC_ID if Tick::match_log(log) => {
+                    Some(Tick::decode(log).map(Events::Tick))
+                }
+                _ => None,
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,636 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+    /// Function `safeTransferFrom(address,address,uint256)`, selector `0x42842e0e`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SafeTransferFromAddressAddressUint256 {
+        pub from: Vec<u8>,
+        pub to: Vec<u8>,
+        pub token_id: substreams::scalar::BigInt,
+    }
+    impl SafeTransferFromAddressAddressUint256 {
+        const METHOD_ID: [u8; 4] = [66u8, 132u8, 46u8, 14u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "safeTransferFrom".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Address,
+                        ethabi::ParamType::Address,
+                        ethabi::ParamType::Uint(256usize),
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "safeTransferFrom".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                from: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                to: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                token_id: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.from)),
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.to)),
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match self.token_id.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+    }
+    impl substreams_ethereum::Function for SafeTransferFromAddressAddressUint256 {
+        const NAME: &'static str = "safeTransferFrom";
+        const SIGNATURE: &'static str = "safeTransferFrom(address,address,uint256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(256usize)
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    /// Function `safeTransferFrom(address,address,uint256,bytes)`, selector `0xb88d4fde`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SafeTransferFromWithData {
+        pub from: Vec<u8>,
+        pub to: Vec<u8>,
+        pub token_id: substreams::scalar::BigInt,
+        pub data: Vec<u8>,
+    }
+    impl SafeTransferFromWithData {
+        const METHOD_ID: [u8; 4] = [184u8, 141u8, 79u8, 222u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "safeTransferFrom".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Address,
+                        ethabi::ParamType::Address,
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Bytes,
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "safeTransferFrom".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                from: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                to: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                token_id: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                data: values.pop().expect(INTERNAL_ERR).into_bytes().expect(INTERNAL_ERR),
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.from)),
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.to)),
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match self.token_id.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                    ethabi::Token::Bytes(self.data.clone()),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+    }
+    impl substreams_ethereum::Function for SafeTransferFromWithData {
+        const NAME: &'static str = "safeTransferFrom";
+        const SIGNATURE: &'static str = "safeTransferFrom(address,address,uint256,bytes)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(256usize), ethabi::ParamType::Bytes
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    /// All functions of the contract, use [Functions::decode_any] to decode any call
+    /// into the matching function.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Functions {
+        SafeTransferFromAddressAddressUint256(SafeTransferFromAddressAddressUint256),
+        SafeTransferFromWithData(SafeTransferFromWithData),
+    }
+    impl Functions {
+        /// Decodes the `call` into the function whose selector matches the first 4 bytes
+        /// of the call's input. Returns `None` if no function of the contract matches.
+        pub fn decode_any(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = call
+                .input
+                .get(0..4)?
+                .try_into()
+                .expect(INTERNAL_ERR);
+            match selector {
+                SafeTransferFromAddressAddressUint256::METHOD_ID => {
+                    Some(
+                        SafeTransferFromAddressAddressUint256::decode(call)
+                            .map(Functions::SafeTransferFromAddressAddressUint256),
+                    )
+                }
+                SafeTransferFromWithData::METHOD_ID => {
+                    Some(
+                        SafeTransferFromWithData::decode(call)
+                            .map(Functions::SafeTransferFromWithData),
+                    )
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    /// Event `Deposit(address,uint256)`, topic 0 `0xe1fffcc4923d04b559f4d29a8bfc6cda04eb5b0d3c460751c2402c5c5cc9109c`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DepositAddressUint256 {
+        pub owner: Vec<u8>,
+        pub amount: substreams::scalar::BigInt,
+    }
+    impl DepositAddressUint256 {
+        const TOPIC_ID: [u8; 32] = [
+            225u8,
+            255u8,
+            252u8,
+            196u8,
+            146u8,
+            61u8,
+            4u8,
+            181u8,
+            89u8,
+            244u8,
+            210u8,
+            154u8,
+            139u8,
+            252u8,
+            108u8,
+            218u8,
+            4u8,
+            235u8,
+            91u8,
+            13u8,
+            60u8,
+            70u8,
+            7u8,
+            81u8,
+            194u8,
+            64u8,
+            44u8,
+            92u8,
+            92u8,
+            201u8,
+            16u8,
+            156u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "Deposit".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize)],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Deposit".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "Deposit".into(),
+                        param: Some("owner".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                amount: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.owner))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.amount.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for DepositAddressUint256 {
+        const NAME: &'static str = "Deposit";
+        const SIGNATURE: &'static str = "Deposit(address,uint256)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// Event `Deposit(address,uint256,bytes)`, topic 0 `0x87de3cfd3de44d9b830a98f5554f3ff79f1fafd7b3579d113ce92d82d3def2a1`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DepositAddressUint256Bytes {
+        pub owner: Vec<u8>,
+        pub amount: substreams::scalar::BigInt,
+        pub data: Vec<u8>,
+    }
+    impl DepositAddressUint256Bytes {
+        const TOPIC_ID: [u8; 32] = [
+            135u8,
+            222u8,
+            60u8,
+            253u8,
+            61u8,
+            228u8,
+            77u8,
+            155u8,
+            131u8,
+            10u8,
+            152u8,
+            245u8,
+            85u8,
+            79u8,
+            63u8,
+            247u8,
+            159u8,
+            31u8,
+            175u8,
+            215u8,
+            179u8,
+            87u8,
+            157u8,
+            17u8,
+            60u8,
+            233u8,
+            45u8,
+            130u8,
+            211u8,
+            222u8,
+            242u8,
+            161u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() < 96usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "Deposit".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize), ethabi::ParamType::Bytes],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Deposit".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                owner: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "Deposit".into(),
+                        param: Some("owner".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                amount: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                data: values.pop().expect(INTERNAL_ERR).into_bytes().expect(INTERNAL_ERR),
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.owner))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.amount.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                        ethabi::Token::Bytes(self.data.clone()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for DepositAddressUint256Bytes {
+        const NAME: &'static str = "Deposit";
+        const SIGNATURE: &'static str = "Deposit(address,uint256,bytes)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize),
+                ethabi::ParamType::Bytes
+            ]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+    /// log into the matching event.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Events {
+        DepositAddressUint256(DepositAddressUint256),
+        DepositAddressUint256Bytes(DepositAddressUint256Bytes),
+    }
+    impl Events {
+        /// Decodes the `log` into the event whose signature matches the log's first topic.
+        /// Returns `None` if no event of the contract matches the log, anonymous events
+        /// are never matched.
+        pub fn decode_any(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+            match topic_id {
+                DepositAddressUint256::TOPIC_ID if DepositAddressUint256::match_log(
+                    log,
+                ) => {
+                    Some(
+                        DepositAddressUint256::decode(log)
+                            .map(Events::DepositAddressUint256),
+                    )
+                }
+                DepositAddressUint256Bytes::TOPIC_ID if DepositAddressUint256Bytes::match_log(
+                    log,
+                ) => {
+                    Some(
+                        DepositAddressUint256Bytes::decode(log)
+                            .map(Events::DepositAddressUint256Bytes),
+                    )
+                }
+                _ => None,
+            }
+        }
+    }
+}
\ No newline at end of file
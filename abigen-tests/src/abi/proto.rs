@@ -0,0 +1,829 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's structs.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod structs {
+    use super::structs;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Entry {
+        pub owner: Vec<u8>,
+        pub weight: substreams::scalar::BigInt,
+    }
+    impl From<Entry> for crate::pb::contract::v1::Entry {
+        fn from(value: Entry) -> Self {
+            Self {
+                owner: value.owner,
+                weight: value.weight.to_string(),
+            }
+        }
+    }
+}
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+    use super::structs;
+    /// Function `register((address,uint64),(address,uint64)[],bytes32,address[],bool,uint256[][])`, selector `0xcebef195`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Register {
+        pub entry: structs::Entry,
+        pub entries: Vec<structs::Entry>,
+        pub salt: [u8; 32usize],
+        pub operators: Vec<Vec<u8>>,
+        pub enabled: bool,
+        pub grid: Vec<Vec<substreams::scalar::BigInt>>,
+    }
+    impl Register {
+        const METHOD_ID: [u8; 4] = [206u8, 190u8, 241u8, 149u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "register".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Address, ethabi::ParamType::Uint(64usize)
+                            ],
+                        ),
+                        ethabi::ParamType::Array(
+                            Box::new(
+                                ethabi::ParamType::Tuple(
+                                    vec![
+                                        ethabi::ParamType::Address, ethabi::ParamType::Uint(64usize)
+                                    ],
+                                ),
+                            ),
+                        ),
+                        ethabi::ParamType::FixedBytes(32usize),
+                        ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address)),
+                        ethabi::ParamType::Bool,
+                        ethabi::ParamType::Array(
+                            Box::new(
+                                ethabi::ParamType::Array(
+                                    Box::new(ethabi::ParamType::Uint(256usize)),
+                                ),
+                            ),
+                        ),
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "register".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                entry: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    structs::Entry {
+                        owner: tuple_elements[0usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        weight: {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[1usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                    }
+                },
+                entries: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        let tuple_elements = inner.into_tuple().expect(INTERNAL_ERR);
+                        structs::Entry {
+                            owner: tuple_elements[0usize]
+                                .clone()
+                                .into_address()
+                                .expect(INTERNAL_ERR)
+                                .as_bytes()
+                                .to_vec(),
+                            weight: {
+                                let mut v = [0 as u8; 32];
+                                tuple_elements[1usize]
+                                    .clone()
+                                    .into_uint()
+                                    .expect(INTERNAL_ERR)
+                                    .to_big_endian(v.as_mut_slice());
+                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                            },
+                        }
+                    })
+                    .collect(),
+                salt: {
+                    let mut result = [0u8; 32];
+                    let v = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                operators: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner.into_address().expect(INTERNAL_ERR).as_bytes().to_vec()
+                    })
+                    .collect(),
+                enabled: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_bool()
+                    .expect(INTERNAL_ERR),
+                grid: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        inner
+                            .into_array()
+                            .expect(INTERNAL_ERR)
+                            .into_iter()
+                            .map(|inner| {
+                                let mut v = [0 as u8; 32];
+                                inner
+                                    .into_uint()
+                                    .expect(INTERNAL_ERR)
+                                    .to_big_endian(v.as_mut_slice());
+                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Tuple(
+                        vec![
+                            ethabi::Token::Address(ethabi::Address::from_slice(& self
+                            .entry.owner)),
+                            ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self
+                            .entry.weight.clone().to_bytes_be() {
+                            (num_bigint::Sign::Plus, bytes) => bytes,
+                            (num_bigint::Sign::NoSign, bytes) => bytes,
+                            (num_bigint::Sign::Minus, _) => {
+                            panic!("negative numbers are not supported") }, }
+                            .as_slice(),),)
+                        ],
+                    ),
+                    {
+                        let v = self
+                            .entries
+                            .iter()
+                            .map(|inner| ethabi::Token::Tuple(
+                                vec![
+                                    ethabi::Token::Address(ethabi::Address::from_slice(& inner
+                                    .owner)),
+                                    ethabi::Token::Uint(ethabi::Uint::from_big_endian(match
+                                    inner.weight.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported") }, }
+                                    .as_slice(),),)
+                                ],
+                            ))
+                            .collect();
+                        ethabi::Token::Array(v)
+                    },
+                    ethabi::Token::FixedBytes(self.salt.as_ref().to_vec()),
+                    {
+                        let v = self
+                            .operators
+                            .iter()
+                            .map(|inner| ethabi::Token::Address(
+                                ethabi::Address::from_slice(&inner),
+                            ))
+                            .collect();
+                        ethabi::Token::Array(v)
+                    },
+                    ethabi::Token::Bool(self.enabled.clone()),
+                    {
+                        let v = self
+                            .grid
+                            .iter()
+                            .map(|inner| {
+                                let v = inner
+                                    .iter()
+                                    .map(|inner| ethabi::Token::Uint(
+                                        ethabi::Uint::from_big_endian(
+                                            match inner.clone().to_bytes_be() {
+                                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                                (num_bigint::Sign::Minus, _) => {
+                                                    panic!("negative numbers are not supported")
+                                                }
+                                            }
+                                                .as_slice(),
+                                        ),
+                                    ))
+                                    .collect();
+                                ethabi::Token::Array(v)
+                            })
+                            .collect();
+                        ethabi::Token::Array(v)
+                    },
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+    }
+    impl substreams_ethereum::Function for Register {
+        const NAME: &'static str = "register";
+        const SIGNATURE: &'static str = "register((address,uint64),(address,uint64)[],bytes32,address[],bool,uint256[][])";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(64usize)]),
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(64usize)]))),
+                ethabi::ParamType::FixedBytes(32usize),
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address)),
+                ethabi::ParamType::Bool,
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(256usize)))))
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    impl From<Register> for crate::pb::contract::v1::RegisterCall {
+        fn from(value: Register) -> Self {
+            Self {
+                entry: Some(value.entry.into()),
+                entries: value
+                    .entries
+                    .into_iter()
+                    .map(|element| element.into())
+                    .collect(),
+                salt: value.salt.to_vec(),
+                operators: value.operators,
+                enabled: value.enabled,
+                grid: {
+                    let encoded = value.grid;
+                    ethabi::encode(
+                        &[
+                            {
+                                let v = encoded
+                                    .iter()
+                                    .map(|inner| {
+                                        let v = inner
+                                            .iter()
+                                            .map(|inner| ethabi::Token::Uint(
+                                                ethabi::Uint::from_big_endian(
+                                                    match inner.clone().to_bytes_be() {
+                                                        (num_bigint::Sign::Plus, bytes) => bytes,
+                                                        (num_bigint::Sign::NoSign, bytes) => bytes,
+                                                        (num_bigint::Sign::Minus, _) => {
+                                                            panic!("negative numbers are not supported")
+                                                        }
+                                                    }
+                                                        .as_slice(),
+                                                ),
+                                            ))
+                                            .collect();
+                                        ethabi::Token::Array(v)
+                                    })
+                                    .collect();
+                                ethabi::Token::Array(v)
+                            },
+                        ],
+                    )
+                },
+            }
+        }
+    }
+    /// All functions of the contract, use [Functions::decode_any] to decode any call
+    /// into the matching function.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Functions {
+        Register(Register),
+    }
+    impl Functions {
+        /// Decodes the `call` into the function whose selector matches the first 4 bytes
+        /// of the call's input. Returns `None` if no function of the contract matches.
+        pub fn decode_any(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = call
+                .input
+                .get(0..4)?
+                .try_into()
+                .expect(INTERNAL_ERR);
+            match selector {
+                Register::METHOD_ID => {
+                    Some(Register::decode(call).map(Functions::Register))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    use super::structs;
+    /// Event `Registered(string,int24[],(uint8,bool))`, topic 0 `0xa6809b70d146fefc659d3e1c19aa9f01ce27786c7701324981b163736985f5aa`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Registered {
+        pub label: substreams_ethereum::IndexedDynamicValue<String>,
+        pub ticks: Vec<substreams::scalar::BigInt>,
+        pub flags: (substreams::scalar::BigInt, bool),
+    }
+    impl Registered {
+        const TOPIC_ID: [u8; 32] = [
+            166u8,
+            128u8,
+            155u8,
+            112u8,
+            209u8,
+            70u8,
+            254u8,
+            252u8,
+            101u8,
+            157u8,
+            62u8,
+            28u8,
+            25u8,
+            170u8,
+            159u8,
+            1u8,
+            206u8,
+            39u8,
+            120u8,
+            108u8,
+            119u8,
+            1u8,
+            50u8,
+            73u8,
+            129u8,
+            177u8,
+            99u8,
+            115u8,
+            105u8,
+            133u8,
+            245u8,
+            170u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() < 128usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "Registered".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Array(
+                            Box::new(ethabi::ParamType::Int(24usize)),
+                        ),
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Uint(8usize), ethabi::ParamType::Bool
+                            ],
+                        ),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Registered".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                label: ethabi::decode(
+                        &[ethabi::ParamType::FixedBytes(32)],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "Registered".into(),
+                        param: Some("label".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_fixed_bytes()
+                    .expect(INTERNAL_ERR)
+                    .into(),
+                ticks: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        let mut v = [0 as u8; 32];
+                        inner
+                            .into_int()
+                            .expect(INTERNAL_ERR)
+                            .to_big_endian(v.as_mut_slice());
+                        substreams::scalar::BigInt::from_signed_bytes_be(&v)
+                    })
+                    .collect(),
+                flags: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    (
+                        {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[0usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                        tuple_elements[1usize].clone().into_bool().expect(INTERNAL_ERR),
+                    )
+                },
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec(), self.label.hash.clone()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .ticks
+                                .iter()
+                                .map(|inner| {
+                                    let non_full_signed_bytes = inner.to_signed_bytes_be();
+                                    let full_signed_bytes_init = if non_full_signed_bytes[0]
+                                        & 0x80 == 0x80
+                                    {
+                                        0xff
+                                    } else {
+                                        0x00
+                                    };
+                                    let mut full_signed_bytes = [full_signed_bytes_init
+                                        as u8; 32];
+                                    non_full_signed_bytes
+                                        .into_iter()
+                                        .rev()
+                                        .enumerate()
+                                        .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
+                                    ethabi::Token::Int(
+                                        ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
+                                    )
+                                })
+                                .collect();
+                            ethabi::Token::Array(v)
+                        },
+                        ethabi::Token::Tuple(
+                            vec![
+                                ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self
+                                .flags.0.clone().to_bytes_be() { (num_bigint::Sign::Plus,
+                                bytes) => bytes, (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                panic!("negative numbers are not supported") }, }
+                                .as_slice(),),), ethabi::Token::Bool(self.flags.1.clone())
+                            ],
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for Registered {
+        const NAME: &'static str = "Registered";
+        const SIGNATURE: &'static str = "Registered(string,int24[],(uint8,bool))";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::String,
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Int(24usize))),
+                ethabi::ParamType::Tuple(vec![ethabi::ParamType::Uint(8usize),
+                ethabi::ParamType::Bool])
+            ]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    impl From<Registered> for crate::pb::contract::v1::Registered {
+        fn from(value: Registered) -> Self {
+            Self {
+                label: value.label.hash,
+                ticks: value
+                    .ticks
+                    .into_iter()
+                    .map(|element| element.to_string())
+                    .collect(),
+                flags: {
+                    let encoded = value.flags;
+                    ethabi::encode(
+                        &[
+                            ethabi::Token::Tuple(
+                                vec![
+                                    ethabi::Token::Uint(ethabi::Uint::from_big_endian(match
+                                    encoded.0.clone().to_bytes_be() { (num_bigint::Sign::Plus,
+                                    bytes) => bytes, (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported") }, }
+                                    .as_slice(),),), ethabi::Token::Bool(encoded.1.clone())
+                                ],
+                            ),
+                        ],
+                    )
+                },
+            }
+        }
+    }
+    /// Event `Transfer(address,address,uint256)`, topic 0 `0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Transfer {
+        pub from: Vec<u8>,
+        pub to: Vec<u8>,
+        pub value: substreams::scalar::BigInt,
+    }
+    impl Transfer {
+        const TOPIC_ID: [u8; 32] = [
+            221u8,
+            242u8,
+            82u8,
+            173u8,
+            27u8,
+            226u8,
+            200u8,
+            155u8,
+            105u8,
+            194u8,
+            176u8,
+            104u8,
+            252u8,
+            55u8,
+            141u8,
+            170u8,
+            149u8,
+            43u8,
+            167u8,
+            241u8,
+            99u8,
+            196u8,
+            161u8,
+            22u8,
+            40u8,
+            245u8,
+            90u8,
+            77u8,
+            245u8,
+            35u8,
+            179u8,
+            239u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 3usize {
+                return false;
+            }
+            if log.data.len() != 32usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 3usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "Transfer".into(),
+                    expected: 3usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize)],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Transfer".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                from: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "Transfer".into(),
+                        param: Some("from".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                to: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[2usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "Transfer".into(),
+                        param: Some("to".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                value: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.from))]),
+                    ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.to))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.value.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for Transfer {
+        const NAME: &'static str = "Transfer";
+        const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(256usize)
+            ]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    impl From<Transfer> for crate::pb::contract::v1::Transfer {
+        fn from(value: Transfer) -> Self {
+            Self {
+                from: value.from,
+                to: value.to,
+                value: value.value.to_string(),
+            }
+        }
+    }
+    /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+    /// log into the matching event.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Events {
+        Registered(Registered),
+        Transfer(Transfer),
+    }
+    impl Events {
+        /// Decodes the `log` into the event whose signature matches the log's first topic.
+        /// Returns `None` if no event of the contract matches the log, anonymous events
+        /// are never matched.
+        pub fn decode_any(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+            match topic_id {
+                Registered::TOPIC_ID if Registered::match_log(log) => {
+                    Some(Registered::decode(log).map(Events::Registered))
+                }
+                Transfer::TOPIC_ID if Transfer::match_log(log) => {
+                    Some(Transfer::decode(log).map(Events::Transfer))
+                }
+                _ => None,
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -1,8 +1,475 @@
 const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's structs.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod structs {
+    use super::structs;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Asset {
+        pub token: Vec<u8>,
+        pub amount: substreams::scalar::BigInt,
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ExactInputSingleParams {
+        pub token_in: Vec<u8>,
+        pub token_out: Vec<u8>,
+        pub fee: substreams::scalar::BigInt,
+        pub recipient: Vec<u8>,
+        pub amount_in: substreams::scalar::BigInt,
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Order {
+        pub maker: Vec<u8>,
+        pub assets: Vec<structs::Asset>,
+    }
+}
+/// Contract's constructor arguments, decoded from the input of the contract
+/// creation call which is the init bytecode followed by the ABI encoded arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constructor {
+    pub name: String,
+    pub initial_supply: substreams::scalar::BigInt,
+    pub owner: Vec<u8>,
+}
+impl Constructor {
+    /// Returns `true` if the call is a contract creation call.
+    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+        call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+    }
+    /// Decodes the constructor arguments of the contract creation `call` by removing
+    /// the known init `bytecode` prefix from the call's input.
+    pub fn decode_with_bytecode(
+        call: &substreams_ethereum::pb::eth::v2::Call,
+        bytecode: &[u8],
+    ) -> Result<Self, substreams_ethereum::DecodeError> {
+        match call.input.strip_prefix(bytecode) {
+            Some(data) => Self::decode_data(data),
+            None => {
+                Err(substreams_ethereum::DecodeError::BytecodeMismatch {
+                    item: "constructor".into(),
+                })
+            }
+        }
+    }
+    /// Decodes the constructor arguments of the contract creation `call` by reading
+    /// the ABI encoded tail found after the first `code_len` bytes of creation code.
+    pub fn decode_with_code_len(
+        call: &substreams_ethereum::pb::eth::v2::Call,
+        code_len: usize,
+    ) -> Result<Self, substreams_ethereum::DecodeError> {
+        match call.input.get(code_len..) {
+            Some(data) => Self::decode_data(data),
+            None => {
+                Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "constructor".into(),
+                    expected: code_len,
+                    actual: call.input.len(),
+                })
+            }
+        }
+    }
+    /// Decodes the ABI encoded constructor arguments, `data` must not contain
+    /// the init bytecode.
+    pub fn decode_data(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+        let mut values = ethabi::decode(
+                &[
+                    ethabi::ParamType::String,
+                    ethabi::ParamType::Uint(256usize),
+                    ethabi::ParamType::Address,
+                ],
+                data,
+            )
+            .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                item: "constructor".into(),
+                param: None,
+                reason: e.to_string(),
+            })?;
+        values.reverse();
+        Ok(Self {
+            name: values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
+            initial_supply: {
+                let mut v = [0 as u8; 32];
+                values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_uint()
+                    .expect(INTERNAL_ERR)
+                    .to_big_endian(v.as_mut_slice());
+                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+            },
+            owner: values
+                .pop()
+                .expect(INTERNAL_ERR)
+                .into_address()
+                .expect(INTERNAL_ERR)
+                .as_bytes()
+                .to_vec(),
+        })
+    }
+    /// Encodes the contract creation input, which is the init `bytecode` followed
+    /// by the ABI encoded constructor arguments.
+    pub fn encode(&self, bytecode: &[u8]) -> Vec<u8> {
+        let data = ethabi::encode(
+            &[
+                ethabi::Token::String(self.name.clone()),
+                ethabi::Token::Uint(
+                    ethabi::Uint::from_big_endian(
+                        match self.initial_supply.clone().to_bytes_be() {
+                            (num_bigint::Sign::Plus, bytes) => bytes,
+                            (num_bigint::Sign::NoSign, bytes) => bytes,
+                            (num_bigint::Sign::Minus, _) => {
+                                panic!("negative numbers are not supported")
+                            }
+                        }
+                            .as_slice(),
+                    ),
+                ),
+                ethabi::Token::Address(ethabi::Address::from_slice(&self.owner)),
+            ],
+        );
+        let mut encoded = Vec::with_capacity(bytecode.len() + data.len());
+        encoded.extend_from_slice(bytecode);
+        encoded.extend(data);
+        encoded
+    }
+}
+/// Contract deployed at an address, calling its `view` and `pure` functions through RPC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contract {
+    address: Vec<u8>,
+}
+impl Contract {
+    pub fn at<A: AsRef<[u8]>>(address: A) -> Self {
+        Self {
+            address: address.as_ref().to_vec(),
+        }
+    }
+    pub fn address(&self) -> &[u8] {
+        &self.address
+    }
+    /// Starts collecting calls to the contract into a single RPC batch.
+    pub fn batch(&self) -> ContractBatch {
+        self.batch_with(substreams_ethereum::rpc::RpcBatch::new())
+    }
+    /// Collects calls to the contract into an existing RPC `batch`, e.g. one holding
+    /// calls to other contracts.
+    pub fn batch_with(
+        &self,
+        batch: substreams_ethereum::rpc::RpcBatch,
+    ) -> ContractBatch {
+        ContractBatch {
+            address: self.address.clone(),
+            batch,
+        }
+    }
+    pub fn fixed_array_address_array_address_returns_uint256_string(
+        &self,
+        param0: [Vec<u8>; 2usize],
+        param1: Vec<Vec<u8>>,
+    ) -> Option<(substreams::scalar::BigInt, String)> {
+        functions::FixedArrayAddressArrayAddressReturnsUint256String {
+            param0,
+            param1,
+        }
+            .call(self.address.clone())
+    }
+    pub fn fixed_array_address_array_uint256_returns_uint256_string(
+        &self,
+        param0: [Vec<u8>; 2usize],
+        param1: Vec<substreams::scalar::BigInt>,
+    ) -> Option<(substreams::scalar::BigInt, String)> {
+        functions::FixedArrayAddressArrayUint256ReturnsUint256String {
+            param0,
+            param1,
+        }
+            .call(self.address.clone())
+    }
+    pub fn fun_struct_nested(
+        &self,
+        order: structs::Order,
+    ) -> Option<Vec<structs::Asset>> {
+        functions::FunStructNested {
+            order,
+        }
+            .call(self.address.clone())
+    }
+}
+/// Calls to a [Contract] collected into a single RPC batch, the responses are in
+/// the order of the calls and decode with `RpcBatch::decode`.
+pub struct ContractBatch {
+    address: Vec<u8>,
+    batch: substreams_ethereum::rpc::RpcBatch,
+}
+impl ContractBatch {
+    pub fn into_rpc_batch(self) -> substreams_ethereum::rpc::RpcBatch {
+        self.batch
+    }
+    pub fn execute(
+        self,
+    ) -> Result<substreams_ethereum::pb::eth::rpc::RpcResponses, String> {
+        self.batch.execute()
+    }
+    pub fn fixed_array_address_array_address_returns_uint256_string(
+        self,
+        param0: [Vec<u8>; 2usize],
+        param1: Vec<Vec<u8>>,
+    ) -> Self {
+        let batch = self
+            .batch
+            .add(
+                functions::FixedArrayAddressArrayAddressReturnsUint256String {
+                    param0,
+                    param1,
+                },
+                self.address.clone(),
+            );
+        Self {
+            address: self.address,
+            batch,
+        }
+    }
+    pub fn fixed_array_address_array_uint256_returns_uint256_string(
+        self,
+        param0: [Vec<u8>; 2usize],
+        param1: Vec<substreams::scalar::BigInt>,
+    ) -> Self {
+        let batch = self
+            .batch
+            .add(
+                functions::FixedArrayAddressArrayUint256ReturnsUint256String {
+                    param0,
+                    param1,
+                },
+                self.address.clone(),
+            );
+        Self {
+            address: self.address,
+            batch,
+        }
+    }
+    pub fn fun_struct_nested(self, order: structs::Order) -> Self {
+        let batch = self
+            .batch
+            .add(
+                functions::FunStructNested {
+                    order,
+                },
+                self.address.clone(),
+            );
+        Self {
+            address: self.address,
+            batch,
+        }
+    }
+}
 /// Contract's functions.
 #[allow(dead_code, unused_imports, unused_variables)]
 pub mod functions {
     use super::INTERNAL_ERR;
+    use super::structs;
+    /// Function `exactInputSingle((address,address,uint24,address,uint256))`, selector `0x7c7f132d`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ExactInputSingle {
+        pub params: structs::ExactInputSingleParams,
+    }
+    impl ExactInputSingle {
+        const METHOD_ID: [u8; 4] = [124u8, 127u8, 19u8, 45u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::Payable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "exactInputSingle".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Address, ethabi::ParamType::Address,
+                                ethabi::ParamType::Uint(24usize),
+                                ethabi::ParamType::Address,
+                                ethabi::ParamType::Uint(256usize)
+                            ],
+                        ),
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "exactInputSingle".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                params: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    structs::ExactInputSingleParams {
+                        token_in: tuple_elements[0usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        token_out: tuple_elements[1usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        fee: {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[2usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                        recipient: tuple_elements[3usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        amount_in: {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[4usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                    }
+                },
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Tuple(
+                        vec![
+                            ethabi::Token::Address(ethabi::Address::from_slice(& self
+                            .params.token_in)),
+                            ethabi::Token::Address(ethabi::Address::from_slice(& self
+                            .params.token_out)),
+                            ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self
+                            .params.fee.clone().to_bytes_be() { (num_bigint::Sign::Plus,
+                            bytes) => bytes, (num_bigint::Sign::NoSign, bytes) => bytes,
+                            (num_bigint::Sign::Minus, _) => {
+                            panic!("negative numbers are not supported") }, }
+                            .as_slice(),),),
+                            ethabi::Token::Address(ethabi::Address::from_slice(& self
+                            .params.recipient)),
+                            ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self
+                            .params.amount_in.clone().to_bytes_be() {
+                            (num_bigint::Sign::Plus, bytes) => bytes,
+                            (num_bigint::Sign::NoSign, bytes) => bytes,
+                            (num_bigint::Sign::Minus, _) => {
+                            panic!("negative numbers are not supported") }, }
+                            .as_slice(),),)
+                        ],
+                    ),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn output_call(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+            Self::output(call.return_data.as_ref())
+        }
+        pub fn output(
+            data: &[u8],
+        ) -> Result<substreams::scalar::BigInt, substreams_ethereum::DecodeError> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize)],
+                    data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "exactInputSingle".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            Ok({
+                let mut v = [0 as u8; 32];
+                values
+                    .pop()
+                    .expect("one output data should have existed")
+                    .into_uint()
+                    .expect(INTERNAL_ERR)
+                    .to_big_endian(v.as_mut_slice());
+                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+            })
+        }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &substreams::scalar::BigInt) -> Vec<u8> {
+            ethabi::encode(
+                &[
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match value.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                ],
+            )
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+    }
+    impl substreams_ethereum::Function for ExactInputSingle {
+        const NAME: &'static str = "exactInputSingle";
+        const SIGNATURE: &'static str = "exactInputSingle((address,address,uint24,address,uint256))";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::Address, ethabi::ParamType::Uint(24usize),
+                ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)])
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize)]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    /// Function `fixedArrayAddressArrayAddressReturnsUint256String(address[2],address[])`, selector `0xdec4311a`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FixedArrayAddressArrayAddressReturnsUint256String {
         pub param0: [Vec<u8>; 2usize],
@@ -10,12 +477,21 @@ pub mod functions {
     }
     impl FixedArrayAddressArrayAddressReturnsUint256String {
         const METHOD_ID: [u8; 4] = [222u8, 196u8, 49u8, 26u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "fixedArrayAddressArrayAddressReturnsUint256String".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[
@@ -27,7 +503,11 @@ pub mod functions {
                     ],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "fixedArrayAddressArrayAddressReturnsUint256String".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -86,17 +566,27 @@ pub mod functions {
         }
         pub fn output_call(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<(substreams::scalar::BigInt, String), String> {
+        ) -> Result<
+            (substreams::scalar::BigInt, String),
+            substreams_ethereum::DecodeError,
+        > {
             Self::output(call.return_data.as_ref())
         }
         pub fn output(
             data: &[u8],
-        ) -> Result<(substreams::scalar::BigInt, String), String> {
+        ) -> Result<
+            (substreams::scalar::BigInt, String),
+            substreams_ethereum::DecodeError,
+        > {
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Uint(256usize), ethabi::ParamType::String],
                     data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "fixedArrayAddressArrayAddressReturnsUint256String".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok((
                 {
@@ -112,6 +602,26 @@ pub mod functions {
                 values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
             ))
         }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &(substreams::scalar::BigInt, String)) -> Vec<u8> {
+            ethabi::encode(
+                &[
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match value.0.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                    ethabi::Token::String(value.1.clone()),
+                ],
+            )
+        }
         pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             match call.input.get(0..4) {
                 Some(signature) => Self::METHOD_ID == signature,
@@ -147,12 +657,23 @@ pub mod functions {
     impl substreams_ethereum::Function
     for FixedArrayAddressArrayAddressReturnsUint256String {
         const NAME: &'static str = "fixedArrayAddressArrayAddressReturnsUint256String";
+        const SIGNATURE: &'static str = "fixedArrayAddressArrayAddressReturnsUint256String(address[2],address[])";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address),
+                2usize), ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address))
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize), ethabi::ParamType::String]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
@@ -161,10 +682,16 @@ pub mod functions {
     }
     impl substreams_ethereum::rpc::RPCDecodable<(substreams::scalar::BigInt, String)>
     for FixedArrayAddressArrayAddressReturnsUint256String {
-        fn output(data: &[u8]) -> Result<(substreams::scalar::BigInt, String), String> {
+        fn output(
+            data: &[u8],
+        ) -> Result<
+            (substreams::scalar::BigInt, String),
+            substreams_ethereum::DecodeError,
+        > {
             Self::output(data)
         }
     }
+    /// Function `FixedArrayAddressArrayUint256ReturnsUint256String(address[2],uint256[])`, selector `0x88e5a46d`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FixedArrayAddressArrayUint256ReturnsUint256String {
         pub param0: [Vec<u8>; 2usize],
@@ -172,12 +699,21 @@ pub mod functions {
     }
     impl FixedArrayAddressArrayUint256ReturnsUint256String {
         const METHOD_ID: [u8; 4] = [136u8, 229u8, 164u8, 109u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "FixedArrayAddressArrayUint256ReturnsUint256String".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[
@@ -191,7 +727,11 @@ pub mod functions {
                     ],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "FixedArrayAddressArrayUint256ReturnsUint256String".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -264,17 +804,27 @@ pub mod functions {
         }
         pub fn output_call(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<(substreams::scalar::BigInt, String), String> {
+        ) -> Result<
+            (substreams::scalar::BigInt, String),
+            substreams_ethereum::DecodeError,
+        > {
             Self::output(call.return_data.as_ref())
         }
         pub fn output(
             data: &[u8],
-        ) -> Result<(substreams::scalar::BigInt, String), String> {
+        ) -> Result<
+            (substreams::scalar::BigInt, String),
+            substreams_ethereum::DecodeError,
+        > {
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Uint(256usize), ethabi::ParamType::String],
                     data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "FixedArrayAddressArrayUint256ReturnsUint256String".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok((
                 {
@@ -290,6 +840,26 @@ pub mod functions {
                 values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
             ))
         }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &(substreams::scalar::BigInt, String)) -> Vec<u8> {
+            ethabi::encode(
+                &[
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match value.0.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                    ethabi::Token::String(value.1.clone()),
+                ],
+            )
+        }
         pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             match call.input.get(0..4) {
                 Some(signature) => Self::METHOD_ID == signature,
@@ -325,12 +895,24 @@ pub mod functions {
     impl substreams_ethereum::Function
     for FixedArrayAddressArrayUint256ReturnsUint256String {
         const NAME: &'static str = "FixedArrayAddressArrayUint256ReturnsUint256String";
+        const SIGNATURE: &'static str = "FixedArrayAddressArrayUint256ReturnsUint256String(address[2],uint256[])";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address),
+                2usize),
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(256usize)))
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize), ethabi::ParamType::String]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
@@ -339,10 +921,16 @@ pub mod functions {
     }
     impl substreams_ethereum::rpc::RPCDecodable<(substreams::scalar::BigInt, String)>
     for FixedArrayAddressArrayUint256ReturnsUint256String {
-        fn output(data: &[u8]) -> Result<(substreams::scalar::BigInt, String), String> {
+        fn output(
+            data: &[u8],
+        ) -> Result<
+            (substreams::scalar::BigInt, String),
+            substreams_ethereum::DecodeError,
+        > {
             Self::output(data)
         }
     }
+    /// Function `funAll(address,bytes,bytes8,bytes32,int256,uint256,bool,string,address[2],address[])`, selector `0x1af93c31`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunAll {
         pub param0: Vec<u8>,
@@ -358,12 +946,21 @@ pub mod functions {
     }
     impl FunAll {
         const METHOD_ID: [u8; 4] = [26u8, 249u8, 60u8, 49u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::Pure;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funAll".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[
@@ -383,7 +980,11 @@ pub mod functions {
                     ],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funAll".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: values
@@ -548,36 +1149,66 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunAll {
         const NAME: &'static str = "funAll";
+        const SIGNATURE: &'static str = "funAll(address,bytes,bytes8,bytes32,int256,uint256,bool,string,address[2],address[])";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::Bytes,
+                ethabi::ParamType::FixedBytes(8usize),
+                ethabi::ParamType::FixedBytes(32usize), ethabi::ParamType::Int(256usize),
+                ethabi::ParamType::Uint(256usize), ethabi::ParamType::Bool,
+                ethabi::ParamType::String,
+                ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address),
+                2usize), ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address))
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funDynamicBoolArray(bool[])`, selector `0xb0e61578`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunDynamicBoolArray {
         pub param0: Vec<bool>,
     }
     impl FunDynamicBoolArray {
         const METHOD_ID: [u8; 4] = [176u8, 230u8, 21u8, 120u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funDynamicBoolArray".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bool))],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funDynamicBoolArray".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: values
@@ -617,36 +1248,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunDynamicBoolArray {
         const NAME: &'static str = "funDynamicBoolArray";
+        const SIGNATURE: &'static str = "funDynamicBoolArray(bool[])";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bool))]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funInt128(int128)`, selector `0x5b3357ff`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunInt128 {
         pub arg0: substreams::scalar::BigInt,
     }
     impl FunInt128 {
         const METHOD_ID: [u8; 4] = [91u8, 51u8, 87u8, 255u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funInt128".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(128usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funInt128".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 arg0: {
@@ -699,36 +1352,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunInt128 {
         const NAME: &'static str = "funInt128";
+        const SIGNATURE: &'static str = "funInt128(int128)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(128usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funInt256(int256)`, selector `0xf70af73b`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunInt256 {
         pub param0: substreams::scalar::BigInt,
     }
     impl FunInt256 {
         const METHOD_ID: [u8; 4] = [247u8, 10u8, 247u8, 59u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::Pure;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funInt256".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(256usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funInt256".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -781,36 +1456,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunInt256 {
         const NAME: &'static str = "funInt256";
+        const SIGNATURE: &'static str = "funInt256(int256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(256usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funInt32(int32)`, selector `0xd78caab3`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunInt32 {
         pub param0: substreams::scalar::BigInt,
     }
     impl FunInt32 {
         const METHOD_ID: [u8; 4] = [215u8, 140u8, 170u8, 179u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::Pure;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funInt32".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(32usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funInt32".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -863,36 +1560,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunInt32 {
         const NAME: &'static str = "funInt32";
+        const SIGNATURE: &'static str = "funInt32(int32)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(32usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funInt8(int8)`, selector `0x3036e687`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunInt8 {
         pub param0: substreams::scalar::BigInt,
     }
     impl FunInt8 {
         const METHOD_ID: [u8; 4] = [48u8, 54u8, 230u8, 135u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::Pure;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funInt8".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(8usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funInt8".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -945,18 +1664,27 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunInt8 {
         const NAME: &'static str = "funInt8";
+        const SIGNATURE: &'static str = "funInt8(int8)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(8usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funInt8Int32Int64Int256(int8,int32,int64,int256)`, selector `0xdb617e8f`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunInt8Int32Int64Int256 {
         pub param0: substreams::scalar::BigInt,
@@ -966,12 +1694,21 @@ pub mod functions {
     }
     impl FunInt8Int32Int64Int256 {
         const METHOD_ID: [u8; 4] = [219u8, 97u8, 126u8, 143u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::Pure;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funInt8Int32Int64Int256".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[
@@ -982,7 +1719,11 @@ pub mod functions {
                     ],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funInt8Int32Int64Int256".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -1122,25 +1863,42 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunInt8Int32Int64Int256 {
         const NAME: &'static str = "funInt8Int32Int64Int256";
+        const SIGNATURE: &'static str = "funInt8Int32Int64Int256(int8,int32,int64,int256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Int(8usize), ethabi::ParamType::Int(32usize),
+                ethabi::ParamType::Int(64usize), ethabi::ParamType::Int(256usize)
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funReturnsString()`, selector `0x7a3719f0`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunReturnsString1 {}
     impl FunReturnsString1 {
         const METHOD_ID: [u8; 4] = [122u8, 55u8, 25u8, 240u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Ok(Self {})
         }
         pub fn encode(&self) -> Vec<u8> {
@@ -1152,12 +1910,16 @@ pub mod functions {
         }
         pub fn output_call(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<String, String> {
+        ) -> Result<String, substreams_ethereum::DecodeError> {
             Self::output(call.return_data.as_ref())
         }
-        pub fn output(data: &[u8]) -> Result<String, String> {
+        pub fn output(data: &[u8]) -> Result<String, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(&[ethabi::ParamType::String], data.as_ref())
-                .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funReturnsString".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             Ok(
                 values
                     .pop()
@@ -1166,61 +1928,52 @@ pub mod functions {
                     .expect(INTERNAL_ERR),
             )
         }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &String) -> Vec<u8> {
+            ethabi::encode(&[ethabi::Token::String(value.clone())])
+        }
         pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             match call.input.get(0..4) {
                 Some(signature) => Self::METHOD_ID == signature,
                 None => false,
             }
         }
-        pub fn call(&self, address: Vec<u8>) -> Option<String> {
-            use substreams_ethereum::pb::eth::rpc;
-            let rpc_calls = rpc::RpcCalls {
-                calls: vec![rpc::RpcCall { to_addr : address, data : self.encode(), }],
-            };
-            let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
-            let response = responses.get(0).expect("one response should have existed");
-            if response.failed {
-                return None;
-            }
-            match Self::output(response.raw.as_ref()) {
-                Ok(data) => Some(data),
-                Err(err) => {
-                    use substreams_ethereum::Function;
-                    substreams::log::info!(
-                        "Call output for function `{}` failed to decode with error: {}",
-                        Self::NAME, err
-                    );
-                    None
-                }
-            }
-        }
     }
     impl substreams_ethereum::Function for FunReturnsString1 {
         const NAME: &'static str = "funReturnsString";
+        const SIGNATURE: &'static str = "funReturnsString()";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
-    impl substreams_ethereum::rpc::RPCDecodable<String> for FunReturnsString1 {
-        fn output(data: &[u8]) -> Result<String, String> {
-            Self::output(data)
-        }
-    }
+    /// Function `funReturnsString()`, selector `0x7a3719f0`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunReturnsString2 {}
     impl FunReturnsString2 {
         const METHOD_ID: [u8; 4] = [122u8, 55u8, 25u8, 240u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Ok(Self {})
         }
         pub fn encode(&self) -> Vec<u8> {
@@ -1232,12 +1985,16 @@ pub mod functions {
         }
         pub fn output_call(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<String, String> {
+        ) -> Result<String, substreams_ethereum::DecodeError> {
             Self::output(call.return_data.as_ref())
         }
-        pub fn output(data: &[u8]) -> Result<String, String> {
+        pub fn output(data: &[u8]) -> Result<String, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(&[ethabi::ParamType::String], data.as_ref())
-                .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funReturnsString".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             Ok(
                 values
                     .pop()
@@ -1246,61 +2003,52 @@ pub mod functions {
                     .expect(INTERNAL_ERR),
             )
         }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &String) -> Vec<u8> {
+            ethabi::encode(&[ethabi::Token::String(value.clone())])
+        }
         pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             match call.input.get(0..4) {
                 Some(signature) => Self::METHOD_ID == signature,
                 None => false,
             }
         }
-        pub fn call(&self, address: Vec<u8>) -> Option<String> {
-            use substreams_ethereum::pb::eth::rpc;
-            let rpc_calls = rpc::RpcCalls {
-                calls: vec![rpc::RpcCall { to_addr : address, data : self.encode(), }],
-            };
-            let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
-            let response = responses.get(0).expect("one response should have existed");
-            if response.failed {
-                return None;
-            }
-            match Self::output(response.raw.as_ref()) {
-                Ok(data) => Some(data),
-                Err(err) => {
-                    use substreams_ethereum::Function;
-                    substreams::log::info!(
-                        "Call output for function `{}` failed to decode with error: {}",
-                        Self::NAME, err
-                    );
-                    None
-                }
-            }
-        }
     }
     impl substreams_ethereum::Function for FunReturnsString2 {
         const NAME: &'static str = "funReturnsString";
+        const SIGNATURE: &'static str = "funReturnsString()";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
-    impl substreams_ethereum::rpc::RPCDecodable<String> for FunReturnsString2 {
-        fn output(data: &[u8]) -> Result<String, String> {
-            Self::output(data)
-        }
-    }
+    /// Function `funReturnsStringString()`, selector `0x85032f7c`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunReturnsStringString {}
     impl FunReturnsStringString {
         const METHOD_ID: [u8; 4] = [133u8, 3u8, 47u8, 124u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Ok(Self {})
         }
         pub fn encode(&self) -> Vec<u8> {
@@ -1312,88 +2060,97 @@ pub mod functions {
         }
         pub fn output_call(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<(String, String), String> {
+        ) -> Result<(String, String), substreams_ethereum::DecodeError> {
             Self::output(call.return_data.as_ref())
         }
-        pub fn output(data: &[u8]) -> Result<(String, String), String> {
+        pub fn output(
+            data: &[u8],
+        ) -> Result<(String, String), substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::String, ethabi::ParamType::String],
                     data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funReturnsStringString".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok((
                 values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
                 values.pop().expect(INTERNAL_ERR).into_string().expect(INTERNAL_ERR),
             ))
         }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &(String, String)) -> Vec<u8> {
+            ethabi::encode(
+                &[
+                    ethabi::Token::String(value.0.clone()),
+                    ethabi::Token::String(value.1.clone()),
+                ],
+            )
+        }
         pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             match call.input.get(0..4) {
                 Some(signature) => Self::METHOD_ID == signature,
                 None => false,
             }
         }
-        pub fn call(&self, address: Vec<u8>) -> Option<(String, String)> {
-            use substreams_ethereum::pb::eth::rpc;
-            let rpc_calls = rpc::RpcCalls {
-                calls: vec![rpc::RpcCall { to_addr : address, data : self.encode(), }],
-            };
-            let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
-            let response = responses.get(0).expect("one response should have existed");
-            if response.failed {
-                return None;
-            }
-            match Self::output(response.raw.as_ref()) {
-                Ok(data) => Some(data),
-                Err(err) => {
-                    use substreams_ethereum::Function;
-                    substreams::log::info!(
-                        "Call output for function `{}` failed to decode with error: {}",
-                        Self::NAME, err
-                    );
-                    None
-                }
-            }
-        }
     }
     impl substreams_ethereum::Function for FunReturnsStringString {
         const NAME: &'static str = "funReturnsStringString";
+        const SIGNATURE: &'static str = "funReturnsStringString()";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String, ethabi::ParamType::String]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
-    impl substreams_ethereum::rpc::RPCDecodable<(String, String)>
-    for FunReturnsStringString {
-        fn output(data: &[u8]) -> Result<(String, String), String> {
-            Self::output(data)
-        }
-    }
+    /// Function `funString(string)`, selector `0xb0d94419`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunString {
         pub first: String,
     }
     impl FunString {
         const METHOD_ID: [u8; 4] = [176u8, 217u8, 68u8, 25u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funString".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::String],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funString".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 first: values
@@ -1419,18 +2176,27 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunString {
         const NAME: &'static str = "funString";
+        const SIGNATURE: &'static str = "funString(string)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funStringString(string,string)`, selector `0x10adeb1b`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunStringString {
         pub first: String,
@@ -1438,18 +2204,31 @@ pub mod functions {
     }
     impl FunStringString {
         const METHOD_ID: [u8; 4] = [16u8, 173u8, 235u8, 27u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funStringString".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::String, ethabi::ParamType::String],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funStringString".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 first: values
@@ -1485,36 +2264,320 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunStringString {
         const NAME: &'static str = "funStringString";
+        const SIGNATURE: &'static str = "funStringString(string,string)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String, ethabi::ParamType::String]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
+        fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            Self::match_call(call)
+        }
+        fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(call)
+        }
+        fn encode(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+    /// Function `funStructNested((address,(address,uint256)[]))`, selector `0xc4c7946e`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FunStructNested {
+        pub order: structs::Order,
+    }
+    impl FunStructNested {
+        const METHOD_ID: [u8; 4] = [196u8, 199u8, 148u8, 110u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
+        pub fn decode(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = call.input.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funStructNested".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Address,
+                                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                                ethabi::ParamType::Uint(256usize)])))
+                            ],
+                        ),
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funStructNested".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                order: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    structs::Order {
+                        maker: tuple_elements[0usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        assets: tuple_elements[1usize]
+                            .clone()
+                            .into_array()
+                            .expect(INTERNAL_ERR)
+                            .into_iter()
+                            .map(|inner| {
+                                let tuple_elements = inner
+                                    .into_tuple()
+                                    .expect(INTERNAL_ERR);
+                                structs::Asset {
+                                    token: tuple_elements[0usize]
+                                        .clone()
+                                        .into_address()
+                                        .expect(INTERNAL_ERR)
+                                        .as_bytes()
+                                        .to_vec(),
+                                    amount: {
+                                        let mut v = [0 as u8; 32];
+                                        tuple_elements[1usize]
+                                            .clone()
+                                            .into_uint()
+                                            .expect(INTERNAL_ERR)
+                                            .to_big_endian(v.as_mut_slice());
+                                        substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                                    },
+                                }
+                            })
+                            .collect(),
+                    }
+                },
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Tuple(
+                        vec![
+                            ethabi::Token::Address(ethabi::Address::from_slice(& self
+                            .order.maker)), { let v = self.order.assets.iter().map(|
+                            inner |
+                            ethabi::Token::Tuple(vec![ethabi::Token::Address(ethabi::Address::from_slice(&
+                            inner.token)),
+                            ethabi::Token::Uint(ethabi::Uint::from_big_endian(match inner
+                            .amount.clone().to_bytes_be() { (num_bigint::Sign::Plus,
+                            bytes) => bytes, (num_bigint::Sign::NoSign, bytes) => bytes,
+                            (num_bigint::Sign::Minus, _) => {
+                            panic!("negative numbers are not supported") }, }
+                            .as_slice(),),)])).collect(); ethabi::Token::Array(v) }
+                        ],
+                    ),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::METHOD_ID);
+            encoded.extend(data);
+            encoded
+        }
+        pub fn output_call(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Result<Vec<structs::Asset>, substreams_ethereum::DecodeError> {
+            Self::output(call.return_data.as_ref())
+        }
+        pub fn output(
+            data: &[u8],
+        ) -> Result<Vec<structs::Asset>, substreams_ethereum::DecodeError> {
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Array(
+                            Box::new(
+                                ethabi::ParamType::Tuple(
+                                    vec![
+                                        ethabi::ParamType::Address,
+                                        ethabi::ParamType::Uint(256usize)
+                                    ],
+                                ),
+                            ),
+                        ),
+                    ],
+                    data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funStructNested".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            Ok(
+                values
+                    .pop()
+                    .expect("one output data should have existed")
+                    .into_array()
+                    .expect(INTERNAL_ERR)
+                    .into_iter()
+                    .map(|inner| {
+                        let tuple_elements = inner.into_tuple().expect(INTERNAL_ERR);
+                        structs::Asset {
+                            token: tuple_elements[0usize]
+                                .clone()
+                                .into_address()
+                                .expect(INTERNAL_ERR)
+                                .as_bytes()
+                                .to_vec(),
+                            amount: {
+                                let mut v = [0 as u8; 32];
+                                tuple_elements[1usize]
+                                    .clone()
+                                    .into_uint()
+                                    .expect(INTERNAL_ERR)
+                                    .to_big_endian(v.as_mut_slice());
+                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                            },
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        #[allow(clippy::ptr_arg)]
+        pub fn encode_output(value: &Vec<structs::Asset>) -> Vec<u8> {
+            ethabi::encode(
+                &[
+                    {
+                        let v = value
+                            .iter()
+                            .map(|inner| ethabi::Token::Tuple(
+                                vec![
+                                    ethabi::Token::Address(ethabi::Address::from_slice(& inner
+                                    .token)),
+                                    ethabi::Token::Uint(ethabi::Uint::from_big_endian(match
+                                    inner.amount.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported") }, }
+                                    .as_slice(),),)
+                                ],
+                            ))
+                            .collect();
+                        ethabi::Token::Array(v)
+                    },
+                ],
+            )
+        }
+        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+            match call.input.get(0..4) {
+                Some(signature) => Self::METHOD_ID == signature,
+                None => false,
+            }
+        }
+        pub fn call(&self, address: Vec<u8>) -> Option<Vec<structs::Asset>> {
+            use substreams_ethereum::pb::eth::rpc;
+            let rpc_calls = rpc::RpcCalls {
+                calls: vec![rpc::RpcCall { to_addr : address, data : self.encode(), }],
+            };
+            let responses = substreams_ethereum::rpc::eth_call(&rpc_calls).responses;
+            let response = responses.get(0).expect("one response should have existed");
+            if response.failed {
+                return None;
+            }
+            match Self::output(response.raw.as_ref()) {
+                Ok(data) => Some(data),
+                Err(err) => {
+                    use substreams_ethereum::Function;
+                    substreams::log::info!(
+                        "Call output for function `{}` failed to decode with error: {}",
+                        Self::NAME, err
+                    );
+                    None
+                }
+            }
+        }
+    }
+    impl substreams_ethereum::Function for FunStructNested {
+        const NAME: &'static str = "funStructNested";
+        const SIGNATURE: &'static str = "funStructNested((address,(address,uint256)[]))";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(256usize)])))])
+            ]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Array(Box::new(ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(256usize)])))
+            ]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    impl substreams_ethereum::rpc::RPCDecodable<Vec<structs::Asset>>
+    for FunStructNested {
+        fn output(
+            data: &[u8],
+        ) -> Result<Vec<structs::Asset>, substreams_ethereum::DecodeError> {
+            Self::output(data)
+        }
+    }
+    /// Function `funTupleAddress((address))`, selector `0xa369a3c9`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunTupleAddress {
         pub param0: (Vec<u8>,),
     }
     impl FunTupleAddress {
         const METHOD_ID: [u8; 4] = [163u8, 105u8, 163u8, 201u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::NonPayable;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funTupleAddress".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address])],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funTupleAddress".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -1559,36 +2622,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunTupleAddress {
         const NAME: &'static str = "funTupleAddress";
+        const SIGNATURE: &'static str = "funTupleAddress((address))";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address])]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funUint256(uint256)`, selector `0x2b15216f`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunUint256 {
         pub param0: substreams::scalar::BigInt,
     }
     impl FunUint256 {
         const METHOD_ID: [u8; 4] = [43u8, 21u8, 33u8, 111u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funUint256".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Uint(256usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funUint256".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -1634,36 +2719,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunUint256 {
         const NAME: &'static str = "funUint256";
+        const SIGNATURE: &'static str = "funUint256(uint256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funWithOverloads(int128)`, selector `0x71a0b96f`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunWithOverloads1 {
         pub arg0: substreams::scalar::BigInt,
     }
     impl FunWithOverloads1 {
         const METHOD_ID: [u8; 4] = [113u8, 160u8, 185u8, 111u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funWithOverloads".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(128usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funWithOverloads".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 arg0: {
@@ -1716,36 +2823,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunWithOverloads1 {
         const NAME: &'static str = "funWithOverloads";
+        const SIGNATURE: &'static str = "funWithOverloads(int128)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(128usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funWithOverloads(uint256)`, selector `0x2cb29c51`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunWithOverloads2 {
         pub arg0: substreams::scalar::BigInt,
     }
     impl FunWithOverloads2 {
         const METHOD_ID: [u8; 4] = [44u8, 178u8, 156u8, 81u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funWithOverloads".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Uint(256usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funWithOverloads".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 arg0: {
@@ -1791,36 +2920,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunWithOverloads2 {
         const NAME: &'static str = "funWithOverloads";
+        const SIGNATURE: &'static str = "funWithOverloads(uint256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `FunWithOverloadsCasing(uint256)`, selector `0xcf70ae87`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunWithOverloadsCasing1 {
         pub arg0: substreams::scalar::BigInt,
     }
     impl FunWithOverloadsCasing1 {
         const METHOD_ID: [u8; 4] = [207u8, 112u8, 174u8, 135u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "FunWithOverloadsCasing".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Uint(256usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "FunWithOverloadsCasing".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 arg0: {
@@ -1866,36 +3017,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunWithOverloadsCasing1 {
         const NAME: &'static str = "FunWithOverloadsCasing";
+        const SIGNATURE: &'static str = "FunWithOverloadsCasing(uint256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funWithOverloadsCasing(int128)`, selector `0x2ead378e`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunWithOverloadsCasing2 {
         pub arg0: substreams::scalar::BigInt,
     }
     impl FunWithOverloadsCasing2 {
         const METHOD_ID: [u8; 4] = [46u8, 173u8, 55u8, 142u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funWithOverloadsCasing".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(128usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funWithOverloadsCasing".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 arg0: {
@@ -1948,36 +3121,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunWithOverloadsCasing2 {
         const NAME: &'static str = "funWithOverloadsCasing";
+        const SIGNATURE: &'static str = "funWithOverloadsCasing(int128)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(128usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `_funWithOverloadsLeadingUnderscore(uint256)`, selector `0x8ca27489`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunWithOverloadsLeadingUnderscore1 {
         pub arg0: substreams::scalar::BigInt,
     }
     impl FunWithOverloadsLeadingUnderscore1 {
         const METHOD_ID: [u8; 4] = [140u8, 162u8, 116u8, 137u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "_funWithOverloadsLeadingUnderscore".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Uint(256usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "_funWithOverloadsLeadingUnderscore".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 arg0: {
@@ -2023,36 +3218,58 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunWithOverloadsLeadingUnderscore1 {
         const NAME: &'static str = "_funWithOverloadsLeadingUnderscore";
+        const SIGNATURE: &'static str = "_funWithOverloadsLeadingUnderscore(uint256)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// Function `funWithOverloadsLeadingUnderscore(int128)`, selector `0x6a37ebaa`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct FunWithOverloadsLeadingUnderscore2 {
         pub arg0: substreams::scalar::BigInt,
     }
     impl FunWithOverloadsLeadingUnderscore2 {
         const METHOD_ID: [u8; 4] = [106u8, 55u8, 235u8, 170u8];
+        pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::View;
+        /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+        pub fn is_payable() -> bool {
+            Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+        }
         pub fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let maybe_data = call.input.get(4..);
             if maybe_data.is_none() {
-                return Err("no data to decode".to_string());
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "funWithOverloadsLeadingUnderscore".into(),
+                    expected: 4,
+                    actual: call.input.len(),
+                });
             }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(128usize)],
                     maybe_data.unwrap(),
                 )
-                .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "funWithOverloadsLeadingUnderscore".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 arg0: {
@@ -2105,23 +3322,184 @@ pub mod functions {
     }
     impl substreams_ethereum::Function for FunWithOverloadsLeadingUnderscore2 {
         const NAME: &'static str = "funWithOverloadsLeadingUnderscore";
+        const SIGNATURE: &'static str = "funWithOverloadsLeadingUnderscore(int128)";
+        const SELECTOR: [u8; 4] = Self::METHOD_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(128usize)]
+        }
+        fn output_types() -> Vec<ethabi::ParamType> {
+            vec![]
+        }
         fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
             Self::match_call(call)
         }
         fn decode(
             call: &substreams_ethereum::pb::eth::v2::Call,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(call)
         }
         fn encode(&self) -> Vec<u8> {
             self.encode()
         }
     }
+    /// All functions of the contract, use [Functions::decode_any] to decode any call
+    /// into the matching function.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Functions {
+        ExactInputSingle(ExactInputSingle),
+        FixedArrayAddressArrayAddressReturnsUint256String(
+            FixedArrayAddressArrayAddressReturnsUint256String,
+        ),
+        FixedArrayAddressArrayUint256ReturnsUint256String(
+            FixedArrayAddressArrayUint256ReturnsUint256String,
+        ),
+        FunAll(FunAll),
+        FunDynamicBoolArray(FunDynamicBoolArray),
+        FunInt128(FunInt128),
+        FunInt256(FunInt256),
+        FunInt32(FunInt32),
+        FunInt8(FunInt8),
+        FunInt8Int32Int64Int256(FunInt8Int32Int64Int256),
+        FunReturnsString1(FunReturnsString1),
+        FunReturnsString2(FunReturnsString2),
+        FunReturnsStringString(FunReturnsStringString),
+        FunString(FunString),
+        FunStringString(FunStringString),
+        FunStructNested(FunStructNested),
+        FunTupleAddress(FunTupleAddress),
+        FunUint256(FunUint256),
+        FunWithOverloads1(FunWithOverloads1),
+        FunWithOverloads2(FunWithOverloads2),
+        FunWithOverloadsCasing1(FunWithOverloadsCasing1),
+        FunWithOverloadsCasing2(FunWithOverloadsCasing2),
+        FunWithOverloadsLeadingUnderscore1(FunWithOverloadsLeadingUnderscore1),
+        FunWithOverloadsLeadingUnderscore2(FunWithOverloadsLeadingUnderscore2),
+    }
+    impl Functions {
+        /// Decodes the `call` into the function whose selector matches the first 4 bytes
+        /// of the call's input. Returns `None` if no function of the contract matches.
+        pub fn decode_any(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = call
+                .input
+                .get(0..4)?
+                .try_into()
+                .expect(INTERNAL_ERR);
+            match selector {
+                ExactInputSingle::METHOD_ID => {
+                    Some(ExactInputSingle::decode(call).map(Functions::ExactInputSingle))
+                }
+                FixedArrayAddressArrayAddressReturnsUint256String::METHOD_ID => {
+                    Some(
+                        FixedArrayAddressArrayAddressReturnsUint256String::decode(call)
+                            .map(
+                                Functions::FixedArrayAddressArrayAddressReturnsUint256String,
+                            ),
+                    )
+                }
+                FixedArrayAddressArrayUint256ReturnsUint256String::METHOD_ID => {
+                    Some(
+                        FixedArrayAddressArrayUint256ReturnsUint256String::decode(call)
+                            .map(
+                                Functions::FixedArrayAddressArrayUint256ReturnsUint256String,
+                            ),
+                    )
+                }
+                FunAll::METHOD_ID => Some(FunAll::decode(call).map(Functions::FunAll)),
+                FunDynamicBoolArray::METHOD_ID => {
+                    Some(
+                        FunDynamicBoolArray::decode(call)
+                            .map(Functions::FunDynamicBoolArray),
+                    )
+                }
+                FunInt128::METHOD_ID => {
+                    Some(FunInt128::decode(call).map(Functions::FunInt128))
+                }
+                FunInt256::METHOD_ID => {
+                    Some(FunInt256::decode(call).map(Functions::FunInt256))
+                }
+                FunInt32::METHOD_ID => {
+                    Some(FunInt32::decode(call).map(Functions::FunInt32))
+                }
+                FunInt8::METHOD_ID => Some(FunInt8::decode(call).map(Functions::FunInt8)),
+                FunInt8Int32Int64Int256::METHOD_ID => {
+                    Some(
+                        FunInt8Int32Int64Int256::decode(call)
+                            .map(Functions::FunInt8Int32Int64Int256),
+                    )
+                }
+                FunReturnsString1::METHOD_ID => {
+                    Some(
+                        FunReturnsString1::decode(call).map(Functions::FunReturnsString1),
+                    )
+                }
+                FunReturnsStringString::METHOD_ID => {
+                    Some(
+                        FunReturnsStringString::decode(call)
+                            .map(Functions::FunReturnsStringString),
+                    )
+                }
+                FunString::METHOD_ID => {
+                    Some(FunString::decode(call).map(Functions::FunString))
+                }
+                FunStringString::METHOD_ID => {
+                    Some(FunStringString::decode(call).map(Functions::FunStringString))
+                }
+                FunStructNested::METHOD_ID => {
+                    Some(FunStructNested::decode(call).map(Functions::FunStructNested))
+                }
+                FunTupleAddress::METHOD_ID => {
+                    Some(FunTupleAddress::decode(call).map(Functions::FunTupleAddress))
+                }
+                FunUint256::METHOD_ID => {
+                    Some(FunUint256::decode(call).map(Functions::FunUint256))
+                }
+                FunWithOverloads1::METHOD_ID => {
+                    Some(
+                        FunWithOverloads1::decode(call).map(Functions::FunWithOverloads1),
+                    )
+                }
+                FunWithOverloads2::METHOD_ID => {
+                    Some(
+                        FunWithOverloads2::decode(call).map(Functions::FunWithOverloads2),
+                    )
+                }
+                FunWithOverloadsCasing1::METHOD_ID => {
+                    Some(
+                        FunWithOverloadsCasing1::decode(call)
+                            .map(Functions::FunWithOverloadsCasing1),
+                    )
+                }
+                FunWithOverloadsCasing2::METHOD_ID => {
+                    Some(
+                        FunWithOverloadsCasing2::decode(call)
+                            .map(Functions::FunWithOverloadsCasing2),
+                    )
+                }
+                FunWithOverloadsLeadingUnderscore1::METHOD_ID => {
+                    Some(
+                        FunWithOverloadsLeadingUnderscore1::decode(call)
+                            .map(Functions::FunWithOverloadsLeadingUnderscore1),
+                    )
+                }
+                FunWithOverloadsLeadingUnderscore2::METHOD_ID => {
+                    Some(
+                        FunWithOverloadsLeadingUnderscore2::decode(call)
+                            .map(Functions::FunWithOverloadsLeadingUnderscore2),
+                    )
+                }
+                _ => None,
+            }
+        }
+    }
 }
 /// Contract's events.
 #[allow(dead_code, unused_imports, unused_variables)]
 pub mod events {
     use super::INTERNAL_ERR;
+    use super::structs;
+    /// Event `EventAddressIdxString(address,string)`, topic 0 `0x3cdb310171efa4c08617535044016fb81ec0a9db468c06b008d2f446ee9346a8`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventAddressIdxString {
         pub first: Vec<u8>,
@@ -2174,23 +3552,33 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventAddressIdxString".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::String],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventAddressIdxString".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 first: ethabi::decode(
                         &[ethabi::ParamType::Address],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'first' from topic of type 'address': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventAddressIdxString".into(),
+                        param: Some("first".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -2205,16 +3593,43 @@ pub mod events {
                     .expect(INTERNAL_ERR),
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.first))])
+                ],
+                data: ethabi::encode(&[ethabi::Token::String(self.second.clone())]),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventAddressIdxString {
         const NAME: &'static str = "EventAddressIdxString";
+        const SIGNATURE: &'static str = "EventAddressIdxString(address,string)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Address, ethabi::ParamType::String]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventAddressIdxStringUint256IdxBytes(address,string,uint256,bytes)`, topic 0 `0x13c827c8aff69c8c51a406825a22313c37b01da4b8e8cc1ab95ff9e5abd433a9`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventAddressIdxStringUint256IdxBytes {
         pub first: Vec<u8>,
@@ -2269,23 +3684,33 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 3usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventAddressIdxStringUint256IdxBytes".into(),
+                    expected: 3usize,
+                    actual: log.topics.len(),
+                });
+            }
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::String, ethabi::ParamType::Bytes],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventAddressIdxStringUint256IdxBytes".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 first: ethabi::decode(
                         &[ethabi::ParamType::Address],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'first' from topic of type 'address': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventAddressIdxStringUint256IdxBytes".into(),
+                        param: Some("first".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -2299,11 +3724,10 @@ pub mod events {
                             &[ethabi::ParamType::Uint(256usize)],
                             log.topics[2usize].as_ref(),
                         )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'third' from topic of type 'uint256': {:?}",
-                                e
-                            )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "EventAddressIdxStringUint256IdxBytes".into(),
+                            param: Some("third".into()),
+                            reason: e.to_string(),
                         })?
                         .pop()
                         .expect(INTERNAL_ERR)
@@ -2324,16 +3748,57 @@ pub mod events {
                     .expect(INTERNAL_ERR),
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.first))]),
+                    ethabi::encode(&
+                    [ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self.third
+                    .clone().to_bytes_be() { (num_bigint::Sign::Plus, bytes) => bytes,
+                    (num_bigint::Sign::NoSign, bytes) => bytes, (num_bigint::Sign::Minus,
+                    _) => { panic!("negative numbers are not supported") }, }
+                    .as_slice(),),)])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::String(self.second.clone()),
+                        ethabi::Token::Bytes(self.fourth.clone()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventAddressIdxStringUint256IdxBytes {
         const NAME: &'static str = "EventAddressIdxStringUint256IdxBytes";
+        const SIGNATURE: &'static str = "EventAddressIdxStringUint256IdxBytes(address,string,uint256,bytes)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::String,
+                ethabi::ParamType::Uint(256usize), ethabi::ParamType::Bytes
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventAddressIdxUint256Uint256AddressIdx(address,uint256,uint256,address)`, topic 0 `0xbad15ff417f376311ddc6f3dcc484cb8b893ca791bd927de626adc9bd8f7d97d`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventAddressIdxUint256Uint256AddressIdx {
         pub first: Vec<u8>,
@@ -2388,7 +3853,14 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 3usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventAddressIdxUint256Uint256AddressIdx".into(),
+                    expected: 3usize,
+                    actual: log.topics.len(),
+                });
+            }
             let mut values = ethabi::decode(
                     &[
                         ethabi::ParamType::Uint(256usize),
@@ -2396,18 +3868,21 @@ pub mod events {
                     ],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventAddressIdxUint256Uint256AddressIdx".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 first: ethabi::decode(
                         &[ethabi::ParamType::Address],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'first' from topic of type 'address': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventAddressIdxUint256Uint256AddressIdx".into(),
+                        param: Some("first".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -2419,11 +3894,10 @@ pub mod events {
                         &[ethabi::ParamType::Address],
                         log.topics[2usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'fourth' from topic of type 'address': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventAddressIdxUint256Uint256AddressIdx".into(),
+                        param: Some("fourth".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -2453,16 +3927,75 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.first))]),
+                    ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.fourth))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.second.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.third.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventAddressIdxUint256Uint256AddressIdx {
         const NAME: &'static str = "EventAddressIdxUint256Uint256AddressIdx";
+        const SIGNATURE: &'static str = "EventAddressIdxUint256Uint256AddressIdx(address,uint256,uint256,address)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize),
+                ethabi::ParamType::Uint(256usize), ethabi::ParamType::Address
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventBytes20UintAddressIdx(bytes20,uint256,address)`, topic 0 `0x82fc641f1b59e5aa1d72b56a795b6a37b67c4c4a709c94808b8e12c83cbc93e1`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventBytes20UintAddressIdx {
         pub first: [u8; 20usize],
@@ -2516,7 +4049,14 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventBytes20UintAddressIdx".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             let mut values = ethabi::decode(
                     &[
                         ethabi::ParamType::FixedBytes(20usize),
@@ -2524,18 +4064,21 @@ pub mod events {
                     ],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventBytes20UintAddressIdx".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 third: ethabi::decode(
                         &[ethabi::ParamType::Address],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'third' from topic of type 'address': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventBytes20UintAddressIdx".into(),
+                        param: Some("third".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -2565,16 +4108,62 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.third))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::FixedBytes(self.first.as_ref().to_vec()),
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.second.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventBytes20UintAddressIdx {
         const NAME: &'static str = "EventBytes20UintAddressIdx";
+        const SIGNATURE: &'static str = "EventBytes20UintAddressIdx(bytes20,uint256,address)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedBytes(20usize),
+                ethabi::ParamType::Uint(256usize), ethabi::ParamType::Address
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventBytes32UintAddressIdx(bytes32,uint256,address)`, topic 0 `0xa862be12a1b17a697b5344433e3cbc744c7f9e2b0bc39baf4dc409a5a8c6b0b3`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventBytes32UintAddressIdx {
         pub first: [u8; 32usize],
@@ -2628,7 +4217,14 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventBytes32UintAddressIdx".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             let mut values = ethabi::decode(
                     &[
                         ethabi::ParamType::FixedBytes(32usize),
@@ -2636,18 +4232,21 @@ pub mod events {
                     ],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventBytes32UintAddressIdx".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 third: ethabi::decode(
                         &[ethabi::ParamType::Address],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'third' from topic of type 'address': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventBytes32UintAddressIdx".into(),
+                        param: Some("third".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -2677,16 +4276,62 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.third))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::FixedBytes(self.first.as_ref().to_vec()),
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.second.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventBytes32UintAddressIdx {
         const NAME: &'static str = "EventBytes32UintAddressIdx";
+        const SIGNATURE: &'static str = "EventBytes32UintAddressIdx(bytes32,uint256,address)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedBytes(32usize),
+                ethabi::ParamType::Uint(256usize), ethabi::ParamType::Address
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventInt256(int256)`, topic 0 `0xa0bc7a55329cc29f990b7c48d9f4624e4c0c35eb955aee358f7b16441db9ed45`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventInt256 {
         pub param0: substreams::scalar::BigInt,
@@ -2738,12 +4383,16 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Int(256usize)],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventInt256".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -2758,16 +4407,63 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let non_full_signed_bytes = self.param0.to_signed_bytes_be();
+                            let full_signed_bytes_init = if non_full_signed_bytes[0]
+                                & 0x80 == 0x80
+                            {
+                                0xff
+                            } else {
+                                0x00
+                            };
+                            let mut full_signed_bytes = [full_signed_bytes_init
+                                as u8; 32];
+                            non_full_signed_bytes
+                                .into_iter()
+                                .rev()
+                                .enumerate()
+                                .for_each(|(i, byte)| full_signed_bytes[31 - i] = byte);
+                            ethabi::Token::Int(
+                                ethabi::Int::from_big_endian(full_signed_bytes.as_ref()),
+                            )
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventInt256 {
         const NAME: &'static str = "EventInt256";
+        const SIGNATURE: &'static str = "EventInt256(int256)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(256usize)]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventInt256Idx(int256)`, topic 0 `0x084d6aa2a24841fba4be2c27f3be03e19c312265fd3e6a73e92ce58c202a4727`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventInt256Idx {
         pub param0: substreams::scalar::BigInt,
@@ -2819,23 +4515,64 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventInt256Idx".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             Ok(Self {
                 param0: substreams::scalar::BigInt::from_signed_bytes_be(
                     log.topics[1usize].as_ref(),
                 ),
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(& [{ let
+                    non_full_signed_bytes = self.param0.to_signed_bytes_be(); let
+                    full_signed_bytes_init = if non_full_signed_bytes[0] & 0x80 == 0x80 {
+                    0xff } else { 0x00 }; let mut full_signed_bytes =
+                    [full_signed_bytes_init as u8; 32]; non_full_signed_bytes.into_iter()
+                    .rev().enumerate().for_each(| (i, byte) | full_signed_bytes[31 - i] =
+                    byte);
+                    ethabi::Token::Int(ethabi::Int::from_big_endian(full_signed_bytes
+                    .as_ref())) }])
+                ],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventInt256Idx {
         const NAME: &'static str = "EventInt256Idx";
+        const SIGNATURE: &'static str = "EventInt256Idx(int256)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Int(256usize)]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventStringIdx(string)`, topic 0 `0xb6e8616369603c14126f2f830d422b55910c71d2bda5145db145e33db8cb51dd`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventStringIdx {
         pub param0: substreams_ethereum::IndexedDynamicValue<String>,
@@ -2887,17 +4624,23 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventStringIdx".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             Ok(Self {
                 param0: ethabi::decode(
                         &[ethabi::ParamType::FixedBytes(32)],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'param0' from topic of type 'string': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventStringIdx".into(),
+                        param: Some("param0".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -2906,16 +4649,40 @@ pub mod events {
                     .into(),
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec(), self.param0.hash.clone()],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventStringIdx {
         const NAME: &'static str = "EventStringIdx";
+        const SIGNATURE: &'static str = "EventStringIdx(string)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventUArrayBool(bool[])`, topic 0 `0xee0cd0e55d575e4e32db712d239532b1104938ed2971f10d8b63e4aa4c17afb6`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUArrayBool {
         pub param0: Vec<bool>,
@@ -2967,12 +4734,16 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bool))],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventUArrayBool".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: values
@@ -2985,16 +4756,51 @@ pub mod events {
                     .collect(),
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::Bool(inner.clone()))
+                                .collect();
+                            ethabi::Token::Array(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventUArrayBool {
         const NAME: &'static str = "EventUArrayBool";
+        const SIGNATURE: &'static str = "EventUArrayBool(bool[])";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bool))]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventUBytes8UBytes16UBytes24UBytes32(bytes8,bytes16,bytes24,bytes32)`, topic 0 `0x75a3b769a551ac226656df901c963ae3f172066c6f8733eed8b96e0710b9b0c4`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUBytes8UBytes16UBytes24UBytes32 {
         pub param0: [u8; 8usize],
@@ -3049,7 +4855,7 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[
                         ethabi::ParamType::FixedBytes(8usize),
@@ -3059,7 +4865,11 @@ pub mod events {
                     ],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventUBytes8UBytes16UBytes24UBytes32".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -3104,16 +4914,52 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::FixedBytes(self.param0.as_ref().to_vec()),
+                        ethabi::Token::FixedBytes(self.param1.as_ref().to_vec()),
+                        ethabi::Token::FixedBytes(self.param2.as_ref().to_vec()),
+                        ethabi::Token::FixedBytes(self.param3.as_ref().to_vec()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventUBytes8UBytes16UBytes24UBytes32 {
         const NAME: &'static str = "EventUBytes8UBytes16UBytes24UBytes32";
+        const SIGNATURE: &'static str = "EventUBytes8UBytes16UBytes24UBytes32(bytes8,bytes16,bytes24,bytes32)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedBytes(8usize),
+                ethabi::ParamType::FixedBytes(16usize),
+                ethabi::ParamType::FixedBytes(24usize),
+                ethabi::ParamType::FixedBytes(32usize)
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventUFixedArrayString(string[2])`, topic 0 `0x2f66d1a00558d55ced0f61b550ca490f9718523b5181b89c06b24ed7752e137c`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUFixedArrayString {
         pub param0: [String; 2usize],
@@ -3165,7 +5011,7 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[
                         ethabi::ParamType::FixedArray(
@@ -3175,7 +5021,11 @@ pub mod events {
                     ],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventUFixedArrayString".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -3190,16 +5040,54 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::String(inner.clone()))
+                                .collect();
+                            ethabi::Token::FixedArray(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventUFixedArrayString {
         const NAME: &'static str = "EventUFixedArrayString";
+        const SIGNATURE: &'static str = "EventUFixedArrayString(string[2])";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::String),
+                2usize)
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventUFixedArraySubDynamic(bytes[2])`, topic 0 `0xd63d45e6cdf5e412e1c4057eba6cb5f766618ae7306d0caf6dab7e3761b68cd8`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUFixedArraySubDynamic {
         pub param0: [Vec<u8>; 2usize],
@@ -3251,7 +5139,7 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[
                         ethabi::ParamType::FixedArray(
@@ -3261,7 +5149,11 @@ pub mod events {
                     ],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventUFixedArraySubDynamic".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -3276,16 +5168,53 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::Bytes(inner.clone()))
+                                .collect();
+                            ethabi::Token::FixedArray(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventUFixedArraySubDynamic {
         const NAME: &'static str = "EventUFixedArraySubDynamic";
+        const SIGNATURE: &'static str = "EventUFixedArraySubDynamic(bytes[2])";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Bytes), 2usize)
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventUFixedArraySubFixed(address[2])`, topic 0 `0x165e34a726badd6985b545a30401873cbd28f8a48f784983ef9ebaee28e1abb2`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUFixedArraySubFixed {
         pub param0: [Vec<u8>; 2usize],
@@ -3337,7 +5266,7 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[
                         ethabi::ParamType::FixedArray(
@@ -3347,7 +5276,11 @@ pub mod events {
                     ],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventUFixedArraySubFixed".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -3364,16 +5297,56 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        {
+                            let v = self
+                                .param0
+                                .iter()
+                                .map(|inner| ethabi::Token::Address(
+                                    ethabi::Address::from_slice(&inner),
+                                ))
+                                .collect();
+                            ethabi::Token::FixedArray(v)
+                        },
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventUFixedArraySubFixed {
         const NAME: &'static str = "EventUFixedArraySubFixed";
+        const SIGNATURE: &'static str = "EventUFixedArraySubFixed(address[2])";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address),
+                2usize)
+            ]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventUTupleAddress((address))`, topic 0 `0xadb25b4ab5d8f04dc5e8073124d207a0974cb9aecac69a6197dbd5cf8dce87d3`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUTupleAddress {
         pub param0: (Vec<u8>,),
@@ -3425,12 +5398,16 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address])],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventUTupleAddress".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -3450,16 +5427,49 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Tuple(
+                            vec![
+                                ethabi::Token::Address(ethabi::Address::from_slice(& self
+                                .param0.0))
+                            ],
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventUTupleAddress {
         const NAME: &'static str = "EventUTupleAddress";
+        const SIGNATURE: &'static str = "EventUTupleAddress((address))";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address])]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventUTupleBool((bool))`, topic 0 `0xe46e0615228a85d593cefeae9bb5f9d1b6698858b635d549b40492afb258ff23`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventUTupleBool {
         pub param0: (bool,),
@@ -3511,12 +5521,16 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             let mut values = ethabi::decode(
                     &[ethabi::ParamType::Tuple(vec![ethabi::ParamType::Bool])],
                     log.data.as_ref(),
                 )
-                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "EventUTupleBool".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
             values.reverse();
             Ok(Self {
                 param0: {
@@ -3529,16 +5543,46 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec()],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Tuple(
+                            vec![ethabi::Token::Bool(self.param0.0.clone())],
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventUTupleBool {
         const NAME: &'static str = "EventUTupleBool";
+        const SIGNATURE: &'static str = "EventUTupleBool((bool))";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Tuple(vec![ethabi::ParamType::Bool])]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventWithOverloads(address)`, topic 0 `0xa0e8866973065650237243af1a7e95fc066a1a49dff3878ca8efca558d271c15`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventWithOverloads1 {
         pub first: Vec<u8>,
@@ -3590,17 +5634,23 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventWithOverloads".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             Ok(Self {
                 first: ethabi::decode(
                         &[ethabi::ParamType::Address],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'first' from topic of type 'address': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventWithOverloads".into(),
+                        param: Some("first".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -3610,16 +5660,43 @@ pub mod events {
                     .to_vec(),
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.first))])
+                ],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventWithOverloads1 {
         const NAME: &'static str = "EventWithOverloads";
+        const SIGNATURE: &'static str = "EventWithOverloads(address)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Address]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventWithOverloads(string)`, topic 0 `0x91762ecf7336dd483cc4a387607c66c8175ccc8a1aca4a4e9012844b0b932396`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventWithOverloads2 {
         pub second: substreams_ethereum::IndexedDynamicValue<String>,
@@ -3671,17 +5748,23 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventWithOverloads".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             Ok(Self {
                 second: ethabi::decode(
                         &[ethabi::ParamType::FixedBytes(32)],
                         log.topics[1usize].as_ref(),
                     )
-                    .map_err(|e| {
-                        format!(
-                            "unable to decode param 'second' from topic of type 'string': {:?}",
-                            e
-                        )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "EventWithOverloads".into(),
+                        param: Some("second".into()),
+                        reason: e.to_string(),
                     })?
                     .pop()
                     .expect(INTERNAL_ERR)
@@ -3690,16 +5773,40 @@ pub mod events {
                     .into(),
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![Self::TOPIC_ID.to_vec(), self.second.hash.clone()],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventWithOverloads2 {
         const NAME: &'static str = "EventWithOverloads";
+        const SIGNATURE: &'static str = "EventWithOverloads(string)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::String]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
     }
+    /// Event `EventWithOverloads(uint256)`, topic 0 `0x02e3bc646e484042ad2adc335b4e77a2f083b21eb36e09456e75e8e37b604976`.
     #[derive(Debug, Clone, PartialEq)]
     pub struct EventWithOverloads3 {
         pub third: substreams::scalar::BigInt,
@@ -3751,7 +5858,14 @@ pub mod events {
         }
         pub fn decode(
             log: &substreams_ethereum::pb::eth::v2::Log,
-        ) -> Result<Self, String> {
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "EventWithOverloads".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
             Ok(Self {
                 third: {
                     let mut v = [0 as u8; 32];
@@ -3759,11 +5873,10 @@ pub mod events {
                             &[ethabi::ParamType::Uint(256usize)],
                             log.topics[1usize].as_ref(),
                         )
-                        .map_err(|e| {
-                            format!(
-                                "unable to decode param 'third' from topic of type 'uint256': {:?}",
-                                e
-                            )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "EventWithOverloads".into(),
+                            param: Some("third".into()),
+                            reason: e.to_string(),
                         })?
                         .pop()
                         .expect(INTERNAL_ERR)
@@ -3774,14 +5887,862 @@ pub mod events {
                 },
             })
         }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self.third
+                    .clone().to_bytes_be() { (num_bigint::Sign::Plus, bytes) => bytes,
+                    (num_bigint::Sign::NoSign, bytes) => bytes, (num_bigint::Sign::Minus,
+                    _) => { panic!("negative numbers are not supported") }, }
+                    .as_slice(),),)])
+                ],
+                data: ethabi::encode(&[]),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
     }
     impl substreams_ethereum::Event for EventWithOverloads3 {
         const NAME: &'static str = "EventWithOverloads";
+        const SIGNATURE: &'static str = "EventWithOverloads(uint256)";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![ethabi::ParamType::Uint(256usize)]
+        }
         fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
             Self::match_log(log)
         }
-        fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
             Self::decode(log)
         }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// Anonymous event `LogNote(bytes4,address,bytes32,bytes32,uint256,bytes)`, logged without signature topic.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LogNote {
+        pub sig: [u8; 4usize],
+        pub guy: Vec<u8>,
+        pub foo: [u8; 32usize],
+        pub bar: [u8; 32usize],
+        pub wad: substreams::scalar::BigInt,
+        pub fax: Vec<u8>,
+    }
+    impl LogNote {
+        /// Anonymous event, there is no signature topic to check so the log is matched
+        /// solely on its shape (topics count and data size). Use [Self::match_log_at]
+        /// to also restrict the match to logs emitted by a given contract address.
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 4usize {
+                return false;
+            }
+            if log.data.len() < 96usize {
+                return false;
+            }
+            true
+        }
+        /// Matches the log if it was emitted by `address` and has the shape of this
+        /// anonymous event.
+        pub fn match_log_at(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+            address: &[u8],
+        ) -> bool {
+            log.address == address && Self::match_log(log)
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 4usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "LogNote".into(),
+                    expected: 4usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Uint(256usize), ethabi::ParamType::Bytes],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "LogNote".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                sig: {
+                    let mut result = [0u8; 4];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(4usize)],
+                            log.topics[0usize].as_ref(),
+                        )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "LogNote".into(),
+                            param: Some("sig".into()),
+                            reason: e.to_string(),
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                guy: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "LogNote".into(),
+                        param: Some("guy".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                foo: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[2usize].as_ref(),
+                        )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "LogNote".into(),
+                            param: Some("foo".into()),
+                            reason: e.to_string(),
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                bar: {
+                    let mut result = [0u8; 32];
+                    let v = ethabi::decode(
+                            &[ethabi::ParamType::FixedBytes(32usize)],
+                            log.topics[3usize].as_ref(),
+                        )
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "LogNote".into(),
+                            param: Some("bar".into()),
+                            reason: e.to_string(),
+                        })?
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_fixed_bytes()
+                        .expect(INTERNAL_ERR);
+                    result.copy_from_slice(&v);
+                    result
+                },
+                wad: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                fax: values.pop().expect(INTERNAL_ERR).into_bytes().expect(INTERNAL_ERR),
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    ethabi::encode(& [ethabi::Token::FixedBytes(self.sig.as_ref()
+                    .to_vec())]), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.guy))]),
+                    ethabi::encode(& [ethabi::Token::FixedBytes(self.foo.as_ref()
+                    .to_vec())]), ethabi::encode(& [ethabi::Token::FixedBytes(self.bar
+                    .as_ref().to_vec())])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Uint(
+                            ethabi::Uint::from_big_endian(
+                                match self.wad.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    }
+                                }
+                                    .as_slice(),
+                            ),
+                        ),
+                        ethabi::Token::Bytes(self.fax.clone()),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for LogNote {
+        const NAME: &'static str = "LogNote";
+        const SIGNATURE: &'static str = "LogNote(bytes4,address,bytes32,bytes32,uint256,bytes)";
+        const TOPIC0: [u8; 32] = [
+            100u8,
+            72u8,
+            67u8,
+            243u8,
+            81u8,
+            211u8,
+            251u8,
+            164u8,
+            171u8,
+            205u8,
+            96u8,
+            16u8,
+            158u8,
+            175u8,
+            249u8,
+            245u8,
+            75u8,
+            172u8,
+            143u8,
+            184u8,
+            204u8,
+            240u8,
+            186u8,
+            185u8,
+            65u8,
+            0u8,
+            156u8,
+            33u8,
+            223u8,
+            33u8,
+            207u8,
+            49u8,
+        ];
+        const ANONYMOUS: bool = true;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::FixedBytes(4usize), ethabi::ParamType::Address,
+                ethabi::ParamType::FixedBytes(32usize),
+                ethabi::ParamType::FixedBytes(32usize),
+                ethabi::ParamType::Uint(256usize), ethabi::ParamType::Bytes
+            ]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// Event `SwappedExactInputSingle(address,(address,address,uint24,address,uint256))`, topic 0 `0x4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SwappedExactInputSingle {
+        pub sender: Vec<u8>,
+        pub params: structs::ExactInputSingleParams,
+    }
+    impl SwappedExactInputSingle {
+        const TOPIC_ID: [u8; 32] = [
+            64u8,
+            114u8,
+            177u8,
+            63u8,
+            191u8,
+            49u8,
+            167u8,
+            127u8,
+            154u8,
+            165u8,
+            180u8,
+            169u8,
+            86u8,
+            197u8,
+            242u8,
+            6u8,
+            117u8,
+            219u8,
+            93u8,
+            179u8,
+            93u8,
+            42u8,
+            143u8,
+            216u8,
+            234u8,
+            52u8,
+            197u8,
+            24u8,
+            50u8,
+            240u8,
+            249u8,
+            64u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() != 160usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            if log.topics.len() < 2usize {
+                return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                    event: "SwappedExactInputSingle".into(),
+                    expected: 2usize,
+                    actual: log.topics.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Tuple(
+                            vec![
+                                ethabi::ParamType::Address, ethabi::ParamType::Address,
+                                ethabi::ParamType::Uint(24usize),
+                                ethabi::ParamType::Address,
+                                ethabi::ParamType::Uint(256usize)
+                            ],
+                        ),
+                    ],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "SwappedExactInputSingle".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                sender: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                        item: "SwappedExactInputSingle".into(),
+                        param: Some("sender".into()),
+                        reason: e.to_string(),
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                params: {
+                    let tuple_elements = values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_tuple()
+                        .expect(INTERNAL_ERR);
+                    structs::ExactInputSingleParams {
+                        token_in: tuple_elements[0usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        token_out: tuple_elements[1usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        fee: {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[2usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                        recipient: tuple_elements[3usize]
+                            .clone()
+                            .into_address()
+                            .expect(INTERNAL_ERR)
+                            .as_bytes()
+                            .to_vec(),
+                        amount_in: {
+                            let mut v = [0 as u8; 32];
+                            tuple_elements[4usize]
+                                .clone()
+                                .into_uint()
+                                .expect(INTERNAL_ERR)
+                                .to_big_endian(v.as_mut_slice());
+                            substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                        },
+                    }
+                },
+            })
+        }
+        pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                topics: vec![
+                    Self::TOPIC_ID.to_vec(), ethabi::encode(&
+                    [ethabi::Token::Address(ethabi::Address::from_slice(& self.sender))])
+                ],
+                data: ethabi::encode(
+                    &[
+                        ethabi::Token::Tuple(
+                            vec![
+                                ethabi::Token::Address(ethabi::Address::from_slice(& self
+                                .params.token_in)),
+                                ethabi::Token::Address(ethabi::Address::from_slice(& self
+                                .params.token_out)),
+                                ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self
+                                .params.fee.clone().to_bytes_be() { (num_bigint::Sign::Plus,
+                                bytes) => bytes, (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                panic!("negative numbers are not supported") }, }
+                                .as_slice(),),),
+                                ethabi::Token::Address(ethabi::Address::from_slice(& self
+                                .params.recipient)),
+                                ethabi::Token::Uint(ethabi::Uint::from_big_endian(match self
+                                .params.amount_in.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                panic!("negative numbers are not supported") }, }
+                                .as_slice(),),)
+                            ],
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            }
+        }
+        pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+            substreams_ethereum::pb::eth::v2::Log {
+                address: address.to_vec(),
+                ..self.encode()
+            }
+        }
+    }
+    impl substreams_ethereum::Event for SwappedExactInputSingle {
+        const NAME: &'static str = "SwappedExactInputSingle";
+        const SIGNATURE: &'static str = "SwappedExactInputSingle(address,(address,address,uint24,address,uint256))";
+        const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+        fn input_types() -> Vec<ethabi::ParamType> {
+            vec![
+                ethabi::ParamType::Address,
+                ethabi::ParamType::Tuple(vec![ethabi::ParamType::Address,
+                ethabi::ParamType::Address, ethabi::ParamType::Uint(24usize),
+                ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)])
+            ]
+        }
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, substreams_ethereum::DecodeError> {
+            Self::decode(log)
+        }
+        fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+            self.encode()
+        }
+    }
+    /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+    /// log into the matching event.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Events {
+        EventAddressIdxString(EventAddressIdxString),
+        EventAddressIdxStringUint256IdxBytes(EventAddressIdxStringUint256IdxBytes),
+        EventAddressIdxUint256Uint256AddressIdx(EventAddressIdxUint256Uint256AddressIdx),
+        EventBytes20UintAddressIdx(EventBytes20UintAddressIdx),
+        EventBytes32UintAddressIdx(EventBytes32UintAddressIdx),
+        EventInt256(EventInt256),
+        EventInt256Idx(EventInt256Idx),
+        EventStringIdx(EventStringIdx),
+        EventUArrayBool(EventUArrayBool),
+        EventUBytes8UBytes16UBytes24UBytes32(EventUBytes8UBytes16UBytes24UBytes32),
+        EventUFixedArrayString(EventUFixedArrayString),
+        EventUFixedArraySubDynamic(EventUFixedArraySubDynamic),
+        EventUFixedArraySubFixed(EventUFixedArraySubFixed),
+        EventUTupleAddress(EventUTupleAddress),
+        EventUTupleBool(EventUTupleBool),
+        EventWithOverloads1(EventWithOverloads1),
+        EventWithOverloads2(EventWithOverloads2),
+        EventWithOverloads3(EventWithOverloads3),
+        SwappedExactInputSingle(SwappedExactInputSingle),
+    }
+    impl Events {
+        /// Decodes the `log` into the event whose signature matches the log's first topic.
+        /// Returns `None` if no event of the contract matches the log, anonymous events
+        /// are never matched.
+        pub fn decode_any(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+            match topic_id {
+                EventAddressIdxString::TOPIC_ID if EventAddressIdxString::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventAddressIdxString::decode(log)
+                            .map(Events::EventAddressIdxString),
+                    )
+                }
+                EventAddressIdxStringUint256IdxBytes::TOPIC_ID if EventAddressIdxStringUint256IdxBytes::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventAddressIdxStringUint256IdxBytes::decode(log)
+                            .map(Events::EventAddressIdxStringUint256IdxBytes),
+                    )
+                }
+                EventAddressIdxUint256Uint256AddressIdx::TOPIC_ID if EventAddressIdxUint256Uint256AddressIdx::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventAddressIdxUint256Uint256AddressIdx::decode(log)
+                            .map(Events::EventAddressIdxUint256Uint256AddressIdx),
+                    )
+                }
+                EventBytes20UintAddressIdx::TOPIC_ID if EventBytes20UintAddressIdx::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventBytes20UintAddressIdx::decode(log)
+                            .map(Events::EventBytes20UintAddressIdx),
+                    )
+                }
+                EventBytes32UintAddressIdx::TOPIC_ID if EventBytes32UintAddressIdx::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventBytes32UintAddressIdx::decode(log)
+                            .map(Events::EventBytes32UintAddressIdx),
+                    )
+                }
+                EventInt256::TOPIC_ID if EventInt256::match_log(log) => {
+                    Some(EventInt256::decode(log).map(Events::EventInt256))
+                }
+                EventInt256Idx::TOPIC_ID if EventInt256Idx::match_log(log) => {
+                    Some(EventInt256Idx::decode(log).map(Events::EventInt256Idx))
+                }
+                EventStringIdx::TOPIC_ID if EventStringIdx::match_log(log) => {
+                    Some(EventStringIdx::decode(log).map(Events::EventStringIdx))
+                }
+                EventUArrayBool::TOPIC_ID if EventUArrayBool::match_log(log) => {
+                    Some(EventUArrayBool::decode(log).map(Events::EventUArrayBool))
+                }
+                EventUBytes8UBytes16UBytes24UBytes32::TOPIC_ID if EventUBytes8UBytes16UBytes24UBytes32::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventUBytes8UBytes16UBytes24UBytes32::decode(log)
+                            .map(Events::EventUBytes8UBytes16UBytes24UBytes32),
+                    )
+                }
+                EventUFixedArrayString::TOPIC_ID if EventUFixedArrayString::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventUFixedArrayString::decode(log)
+                            .map(Events::EventUFixedArrayString),
+                    )
+                }
+                EventUFixedArraySubDynamic::TOPIC_ID if EventUFixedArraySubDynamic::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventUFixedArraySubDynamic::decode(log)
+                            .map(Events::EventUFixedArraySubDynamic),
+                    )
+                }
+                EventUFixedArraySubFixed::TOPIC_ID if EventUFixedArraySubFixed::match_log(
+                    log,
+                ) => {
+                    Some(
+                        EventUFixedArraySubFixed::decode(log)
+                            .map(Events::EventUFixedArraySubFixed),
+                    )
+                }
+                EventUTupleAddress::TOPIC_ID if EventUTupleAddress::match_log(log) => {
+                    Some(EventUTupleAddress::decode(log).map(Events::EventUTupleAddress))
+                }
+                EventUTupleBool::TOPIC_ID if EventUTupleBool::match_log(log) => {
+                    Some(EventUTupleBool::decode(log).map(Events::EventUTupleBool))
+                }
+                EventWithOverloads1::TOPIC_ID if EventWithOverloads1::match_log(log) => {
+                    Some(
+                        EventWithOverloads1::decode(log).map(Events::EventWithOverloads1),
+                    )
+                }
+                EventWithOverloads2::TOPIC_ID if EventWithOverloads2::match_log(log) => {
+                    Some(
+                        EventWithOverloads2::decode(log).map(Events::EventWithOverloads2),
+                    )
+                }
+                EventWithOverloads3::TOPIC_ID if EventWithOverloads3::match_log(log) => {
+                    Some(
+                        EventWithOverloads3::decode(log).map(Events::EventWithOverloads3),
+                    )
+                }
+                SwappedExactInputSingle::TOPIC_ID if SwappedExactInputSingle::match_log(
+                    log,
+                ) => {
+                    Some(
+                        SwappedExactInputSingle::decode(log)
+                            .map(Events::SwappedExactInputSingle),
+                    )
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// Contract's custom errors.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod errors {
+    use super::INTERNAL_ERR;
+    use super::structs;
+    /// Error `InsufficientBalance(uint256,uint256)`, selector `0xcf479181`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InsufficientBalance {
+        pub available: substreams::scalar::BigInt,
+        pub required: substreams::scalar::BigInt,
+    }
+    impl InsufficientBalance {
+        pub const NAME: &'static str = "InsufficientBalance";
+        pub const SELECTOR: [u8; 4] = [207u8, 71u8, 145u8, 129u8];
+        /// Returns `true` if the revert `data` starts with this error's selector.
+        pub fn match_revert(data: &[u8]) -> bool {
+            match data.get(0..4) {
+                Some(selector) => Self::SELECTOR == selector,
+                None => false,
+            }
+        }
+        pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = data.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "InsufficientBalance".into(),
+                    expected: 4,
+                    actual: data.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[
+                        ethabi::ParamType::Uint(256usize),
+                        ethabi::ParamType::Uint(256usize),
+                    ],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "InsufficientBalance".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                available: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+                required: {
+                    let mut v = [0 as u8; 32];
+                    values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_uint()
+                        .expect(INTERNAL_ERR)
+                        .to_big_endian(v.as_mut_slice());
+                    substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                },
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match self.available.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                    ethabi::Token::Uint(
+                        ethabi::Uint::from_big_endian(
+                            match self.required.clone().to_bytes_be() {
+                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                (num_bigint::Sign::Minus, _) => {
+                                    panic!("negative numbers are not supported")
+                                }
+                            }
+                                .as_slice(),
+                        ),
+                    ),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::SELECTOR);
+            encoded.extend(data);
+            encoded
+        }
+    }
+    /// Error `Paused()`, selector `0x9e87fac8`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Paused {}
+    impl Paused {
+        pub const NAME: &'static str = "Paused";
+        pub const SELECTOR: [u8; 4] = [158u8, 135u8, 250u8, 200u8];
+        /// Returns `true` if the revert `data` starts with this error's selector.
+        pub fn match_revert(data: &[u8]) -> bool {
+            match data.get(0..4) {
+                Some(selector) => Self::SELECTOR == selector,
+                None => false,
+            }
+        }
+        pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+            Ok(Self {})
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(&[]);
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::SELECTOR);
+            encoded.extend(data);
+            encoded
+        }
+    }
+    /// Error `Unauthorized(address,string)`, selector `0xa35b150b`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Unauthorized {
+        pub caller: Vec<u8>,
+        pub reason: String,
+    }
+    impl Unauthorized {
+        pub const NAME: &'static str = "Unauthorized";
+        pub const SELECTOR: [u8; 4] = [163u8, 91u8, 21u8, 11u8];
+        /// Returns `true` if the revert `data` starts with this error's selector.
+        pub fn match_revert(data: &[u8]) -> bool {
+            match data.get(0..4) {
+                Some(selector) => Self::SELECTOR == selector,
+                None => false,
+            }
+        }
+        pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+            let maybe_data = data.get(4..);
+            if maybe_data.is_none() {
+                return Err(substreams_ethereum::DecodeError::DataSize {
+                    item: "Unauthorized".into(),
+                    expected: 4,
+                    actual: data.len(),
+                });
+            }
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Address, ethabi::ParamType::String],
+                    maybe_data.unwrap(),
+                )
+                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                    item: "Unauthorized".into(),
+                    param: None,
+                    reason: e.to_string(),
+                })?;
+            values.reverse();
+            Ok(Self {
+                caller: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                reason: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_string()
+                    .expect(INTERNAL_ERR),
+            })
+        }
+        pub fn encode(&self) -> Vec<u8> {
+            let data = ethabi::encode(
+                &[
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.caller)),
+                    ethabi::Token::String(self.reason.clone()),
+                ],
+            );
+            let mut encoded = Vec::with_capacity(4 + data.len());
+            encoded.extend(Self::SELECTOR);
+            encoded.extend(data);
+            encoded
+        }
+    }
+    /// All custom errors of the contract, use [Errors::decode] to decode any revert
+    /// payload into the matching error.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Errors {
+        InsufficientBalance(InsufficientBalance),
+        Paused(Paused),
+        Unauthorized(Unauthorized),
+    }
+    impl Errors {
+        /// Decodes the revert `data` into the custom error whose selector matches the first
+        /// 4 bytes. Returns `None` if no custom error of the contract matches.
+        pub fn decode(
+            data: &[u8],
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            let selector: [u8; 4] = data.get(0..4)?.try_into().expect(INTERNAL_ERR);
+            match selector {
+                InsufficientBalance::SELECTOR => {
+                    Some(
+                        InsufficientBalance::decode(data)
+                            .map(Errors::InsufficientBalance),
+                    )
+                }
+                Paused::SELECTOR => Some(Paused::decode(data).map(Errors::Paused)),
+                Unauthorized::SELECTOR => {
+                    Some(Unauthorized::decode(data).map(Errors::Unauthorized))
+                }
+                _ => None,
+            }
+        }
+        /// Decodes the `return_data` of a reverted call, returns `None` if the call
+        /// did not revert or if no custom error of the contract matches.
+        pub fn decode_call(
+            call: &substreams_ethereum::pb::eth::v2::Call,
+        ) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+            if !call.status_reverted {
+                return None;
+            }
+            Self::decode(call.return_data.as_ref())
+        }
     }
 }
\ No newline at end of file
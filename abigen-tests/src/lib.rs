@@ -1,14 +1,58 @@
 mod abi;
+mod pb;
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::abi::tests;
+    use crate::abi::{
+        address_types, derives, foundry_artifact, native_ints, overloads, proto, signatures, tests,
+    };
     use pretty_assertions::assert_eq;
     use substreams::scalar::BigInt;
     use substreams::{hex, Hex};
-    use substreams_ethereum::{pb, Event, Function, IndexedDynamicValue};
+    use substreams_ethereum::{
+        pb, Address, DecodeError, Event, Function, IndexedDynamicValue, H256,
+    };
+
+    substreams_ethereum::use_contract!(
+        erc20,
+        [
+            "event Approval(address indexed owner, address indexed spender, uint256 value)",
+            "function allowance(address owner, address spender) view returns (uint256)",
+        ]
+    );
+
+    substreams_ethereum::use_contract!(
+        vault,
+        [
+            "function deposit() payable",
+            "receive() external payable",
+            "fallback() external payable",
+        ]
+    );
+
+    substreams_ethereum::use_contract!(proxy, ["fallback() external"]);
+
+    substreams_ethereum::use_contract!(
+        token,
+        [
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Approval(address indexed owner, address indexed spender, uint256 value)",
+            "function balanceOf(address owner) view returns (uint256)",
+            "function totalSupply() view returns (uint256)",
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "fallback() external",
+        ],
+        include_functions = ["balanceOf", "transfer(address,uint256)"],
+        exclude_events = ["Approval"],
+    );
+
+    substreams_ethereum::use_contract!(
+        token_rpc,
+        ["function transfer(address to, uint256 amount) returns (bool)"],
+        rpc_all_functions = true,
+    );
 
     #[macro_export]
     macro_rules! assert_bytes {
@@ -916,4 +960,1366 @@ mod tests {
         assert!(Fun1::NAME == "FunWithOverloadsCasing");
         assert!(Fun2::NAME == "funWithOverloadsCasing");
     }
+
+    #[test]
+    fn it_decode_error_insufficient_balance() {
+        use tests::errors::InsufficientBalance as Error;
+
+        let data = hex!("cf47918100000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000fa0");
+
+        assert_eq!(Error::match_revert(&data), true);
+
+        let error = Error::decode(&data);
+        assert_eq!(
+            error,
+            Ok(Error {
+                available: BigInt::from(100u64),
+                required: BigInt::from(4000u64),
+            }),
+        );
+    }
+
+    #[test]
+    fn it_encode_error_insufficient_balance() {
+        use tests::errors::InsufficientBalance as Error;
+
+        let error = Error {
+            available: BigInt::from(100u64),
+            required: BigInt::from(4000u64),
+        };
+
+        assert_bytes!(
+            error.encode(),
+            hex!("cf47918100000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000fa0").to_vec()
+        );
+    }
+
+    #[test]
+    fn it_decode_errors_enum_dispatch() {
+        use tests::errors::{Errors, InsufficientBalance, Paused, Unauthorized};
+
+        let unauthorized = Unauthorized {
+            caller: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            reason: "not owner".to_string(),
+        };
+
+        assert_eq!(
+            Errors::decode(&unauthorized.encode()),
+            Some(Ok(Errors::Unauthorized(unauthorized.clone())))
+        );
+        assert_eq!(
+            Errors::decode(&Paused::SELECTOR),
+            Some(Ok(Errors::Paused(Paused {})))
+        );
+        assert_eq!(
+            Errors::decode(&InsufficientBalance::SELECTOR).map(|result| result.is_err()),
+            Some(true)
+        );
+        assert_eq!(Errors::decode(&hex!("08c379a0")), None);
+        assert_eq!(Errors::decode(&hex!("cf47")), None);
+    }
+
+    #[test]
+    fn it_decode_errors_from_reverted_call() {
+        use tests::errors::{Errors, Paused};
+
+        let mut call = pb::eth::v2::Call {
+            status_failed: true,
+            status_reverted: true,
+            return_data: Paused::SELECTOR.to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Errors::decode_call(&call),
+            Some(Ok(Errors::Paused(Paused {})))
+        );
+
+        call.status_failed = false;
+        call.status_reverted = false;
+        assert_eq!(Errors::decode_call(&call), None);
+    }
+
+    #[test]
+    fn it_decode_constructor_with_bytecode() {
+        use tests::Constructor;
+
+        let bytecode = hex!("6080604052348015600f57600080fd5b50");
+        let constructor = Constructor {
+            name: "Token".to_string(),
+            initial_supply: BigInt::from(1_000_000u64),
+            owner: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+        };
+
+        let call = pb::eth::v2::Call {
+            call_type: pb::eth::v2::CallType::Create as i32,
+            input: constructor.encode(&bytecode),
+            ..Default::default()
+        };
+
+        assert_eq!(Constructor::match_call(&call), true);
+        assert_eq!(
+            Constructor::decode_with_bytecode(&call, &bytecode),
+            Ok(constructor.clone())
+        );
+        assert_eq!(
+            Constructor::decode_with_code_len(&call, bytecode.len()),
+            Ok(constructor)
+        );
+        assert_eq!(
+            Constructor::decode_with_bytecode(&call, &hex!("deadbeef")),
+            Err(DecodeError::BytecodeMismatch {
+                item: "constructor".into()
+            })
+        );
+        assert_eq!(
+            Constructor::decode_with_code_len(&call, call.input.len() + 1).is_err(),
+            true
+        );
+    }
+
+    #[test]
+    fn it_decode_constructor_data() {
+        use tests::Constructor;
+
+        let data = hex!("0000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec0000000000000000000000000000000000000000000000000000000000000005546f6b656e000000000000000000000000000000000000000000000000000000");
+
+        assert_eq!(
+            Constructor::decode_data(&data),
+            Ok(Constructor {
+                name: "Token".to_string(),
+                initial_supply: BigInt::from(10u64),
+                owner: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            }),
+        );
+    }
+
+    #[test]
+    fn it_match_constructor_call_only_on_create() {
+        use tests::Constructor;
+
+        let call = pb::eth::v2::Call {
+            call_type: pb::eth::v2::CallType::Call as i32,
+            ..Default::default()
+        };
+
+        assert_eq!(Constructor::match_call(&call), false);
+    }
+
+    #[test]
+    fn it_decode_event_anonymous() {
+        use tests::events::LogNote as Event;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("9759a6ac90977b93b58547b4a71c78317f391a28").to_vec(),
+            topics: vec![
+                hex!("1cff79cd00000000000000000000000000000000000000000000000000000000").to_vec(),
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000002").to_vec(),
+            ],
+            data: hex!("000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000041cff79cd00000000000000000000000000000000000000000000000000000000").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(Event::match_log(&log), true);
+        assert_eq!(
+            Event::match_log_at(&log, &hex!("9759a6ac90977b93b58547b4a71c78317f391a28")),
+            true
+        );
+        assert_eq!(
+            Event::match_log_at(&log, &hex!("0000000000000000000000000000000000000000")),
+            false
+        );
+
+        let event = Event::decode(&log);
+        assert_eq!(
+            event,
+            Ok(Event {
+                sig: hex!("1cff79cd"),
+                guy: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                foo: hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                bar: hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+                wad: BigInt::from(10u64),
+                fax: hex!("1cff79cd").to_vec(),
+            }),
+        );
+    }
+
+    #[test]
+    fn it_match_event_anonymous_on_shape() {
+        use tests::events::LogNote as Event;
+
+        let log = pb::eth::v2::Log {
+            topics: vec![
+                hex!("1cff79cd00000000000000000000000000000000000000000000000000000000").to_vec(),
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            ],
+            data: hex!("000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(Event::match_log(&log), false);
+        assert_eq!(<Event as substreams_ethereum::Event>::ANONYMOUS, true);
+        assert_eq!(
+            <erc20::events::Approval as substreams_ethereum::Event>::ANONYMOUS,
+            false
+        );
+    }
+
+    #[test]
+    fn it_encode_fun_struct() {
+        use tests::functions::ExactInputSingle as Function;
+        use tests::structs::ExactInputSingleParams;
+
+        let fun = Function {
+            params: ExactInputSingleParams {
+                token_in: hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").to_vec(),
+                token_out: hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").to_vec(),
+                fee: BigInt::from(3000u64),
+                recipient: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                amount_in: BigInt::from(10u64),
+            },
+        };
+
+        let input = fun.encode();
+        assert_bytes!(
+            input[4..],
+            hex!("000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000000000000000000000000000000000000000000bb8000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec000000000000000000000000000000000000000000000000000000000000000a"),
+        );
+
+        let call = pb::eth::v2::Call {
+            input,
+            ..Default::default()
+        };
+
+        assert_eq!(Function::match_call(&call), true);
+        assert_eq!(Function::decode(&call), Ok(fun));
+    }
+
+    #[test]
+    fn it_decode_event_struct() {
+        use tests::events::SwappedExactInputSingle as Event;
+        use tests::structs::ExactInputSingleParams;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940").to_vec(),
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            ],
+            data: hex!("000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000000000000000000000000000000000000000000bb8000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec000000000000000000000000000000000000000000000000000000000000000a").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(Event::match_log(&log), true);
+        assert_eq!(
+            Event::decode(&log),
+            Ok(Event {
+                sender: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                params: ExactInputSingleParams {
+                    token_in: hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").to_vec(),
+                    token_out: hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").to_vec(),
+                    fee: BigInt::from(3000u64),
+                    recipient: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                    amount_in: BigInt::from(10u64),
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn it_encode_decode_fun_nested_struct() {
+        use tests::functions::FunStructNested as Function;
+        use tests::structs::{Asset, Order};
+
+        let fun = Function {
+            order: Order {
+                maker: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                assets: vec![
+                    Asset {
+                        token: hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").to_vec(),
+                        amount: BigInt::from(1u64),
+                    },
+                    Asset {
+                        token: hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").to_vec(),
+                        amount: BigInt::from(2u64),
+                    },
+                ],
+            },
+        };
+
+        let call = pb::eth::v2::Call {
+            input: fun.encode(),
+            ..Default::default()
+        };
+
+        assert_eq!(Function::decode(&call), Ok(fun));
+    }
+
+    #[test]
+    fn it_decode_fun_struct_array_output() {
+        use tests::functions::FunStructNested as Function;
+        use tests::structs::Asset;
+
+        let output = Function::output(&hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000001000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000000000000000000000000000000000000000000001"));
+
+        assert_eq!(
+            output,
+            Ok(vec![Asset {
+                token: hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").to_vec(),
+                amount: BigInt::from(1u64),
+            }]),
+        );
+    }
+
+    #[test]
+    fn it_decode_event_address_types() {
+        use address_types::events::Deployed as Event;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("c503c7bd1701df8b8e079a6e87a5b46b1f6856c45dcf55f573a7d65ca9a048f1").to_vec(),
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            ],
+            data: hex!("000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc24072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940").to_vec(),
+            ..Default::default()
+        };
+
+        let event = Event::decode(&log).unwrap();
+        assert_eq!(
+            event,
+            Event {
+                from: Address(hex!("fffdb7377345371817f2b4dd490319755f5899ec")),
+                salt: H256(hex!(
+                    "0000000000000000000000000000000000000000000000000000000000000001"
+                )),
+                to: Address(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")),
+                hash: H256(hex!(
+                    "4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940"
+                )),
+            },
+        );
+        assert_eq!(
+            event.to.to_string(),
+            "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"
+        );
+    }
+
+    #[test]
+    fn it_encode_decode_fun_address_types() {
+        use address_types::functions::FunAddresses as Function;
+        use address_types::structs::Entry;
+
+        let fun = Function {
+            accounts: vec![
+                Address(hex!("fffdb7377345371817f2b4dd490319755f5899ec")),
+                Address(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")),
+            ],
+            entry: Entry {
+                owner: Address(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")),
+                node: H256(hex!(
+                    "4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940"
+                )),
+            },
+        };
+
+        let call = pb::eth::v2::Call {
+            input: fun.encode(),
+            ..Default::default()
+        };
+
+        assert_eq!(Function::decode(&call), Ok(fun));
+        assert_eq!(
+            Function::output(&hex!(
+                "4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940"
+            )),
+            Ok(H256(hex!(
+                "4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940"
+            ))),
+        );
+    }
+
+    #[test]
+    fn it_decode_event_native_ints() {
+        use native_ints::events::Tick as Event;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("1705104a4f8d5b8ea492175e2501044a3f5c28c74daafbd3a3108e7a36245be9").to_vec(),
+                hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc4").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000012").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000003e8000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000070000000000000000000000000000000000000000000000000000000000000002ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000000005").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Event::decode(&log),
+            Ok(Event {
+                tick: -60i32,
+                decimals: 18u8,
+                liquidity: 1000u128,
+                deltas: vec![-1i8, 5i8],
+                amount: BigInt::from(7u64),
+            }),
+        );
+    }
+
+    #[test]
+    fn it_encode_decode_fun_native_ints() {
+        use native_ints::functions::FunNativeInts as Function;
+        use native_ints::structs::Fee;
+
+        let fun = Function {
+            small: 255u8,
+            pair: [i16::MIN, i16::MAX],
+            fee: Fee {
+                tier: 3000u32,
+                offset: -5i128,
+            },
+        };
+
+        let input = fun.encode();
+        assert_bytes!(
+            input,
+            hex!("4a43dd8100000000000000000000000000000000000000000000000000000000000000ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80000000000000000000000000000000000000000000000000000000000000007fff0000000000000000000000000000000000000000000000000000000000000bb8fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffb"),
+        );
+
+        let call = pb::eth::v2::Call {
+            input,
+            ..Default::default()
+        };
+
+        assert_eq!(Function::decode(&call), Ok(fun));
+        assert_eq!(
+            Function::output(&hex!(
+                "000000000000000000000000000000000000000000000000000000000000002a"
+            )),
+            Ok(42u64)
+        );
+    }
+
+    #[test]
+    fn it_decode_fun_native_ints_overflow() {
+        use native_ints::functions::FunNativeInts as Function;
+
+        let call = pb::eth::v2::Call {
+            input: hex!("4a43dd8100000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000001fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe0000000000000000000000000000000000000000000000000000000000000bb8fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffb").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Function::decode(&call),
+            Err(DecodeError::Overflow {
+                item: "funNativeInts".into(),
+                param: "small".into(),
+                kind: "uint8",
+                value: "0x100".to_string(),
+            }),
+        );
+
+        let call = pb::eth::v2::Call {
+            input: hex!("4a43dd8100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7fff0000000000000000000000000000000000000000000000000000000000000bb8fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffb").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Function::decode(&call),
+            Err(DecodeError::Overflow {
+                item: "funNativeInts".into(),
+                param: "pair".into(),
+                kind: "int16",
+                value: "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7fff"
+                    .to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn it_decode_any_event() {
+        use tests::events::{EventInt256, Events, SwappedExactInputSingle};
+
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("a0bc7a55329cc29f990b7c48d9f4624e4c0c35eb955aee358f7b16441db9ed45").to_vec(),
+            ],
+            data: hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffff713f526b11d").to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Events::decode_any(&log),
+            Some(Ok(Events::EventInt256(EventInt256 {
+                param0: BigInt::from(-9809887317731i64),
+            }))),
+        );
+
+        let log = pb::eth::v2::Log {
+            address: hex!("0000000000000000000000000000000000000000").to_vec(),
+            topics: vec![
+                hex!("4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940").to_vec(),
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            ],
+            data: hex!("000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000000000000000000000000000000000000000000bb8000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec000000000000000000000000000000000000000000000000000000000000000a").to_vec(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            Events::decode_any(&log),
+            Some(Ok(Events::SwappedExactInputSingle(
+                SwappedExactInputSingle { .. }
+            )))
+        ));
+    }
+
+    #[test]
+    fn it_decode_any_event_no_match() {
+        use tests::events::Events;
+
+        // Unknown signature topic
+        let log = pb::eth::v2::Log {
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(Events::decode_any(&log), None);
+
+        // Known signature topic but the log does not have the event's shape
+        let log = pb::eth::v2::Log {
+            topics: vec![
+                hex!("a0bc7a55329cc29f990b7c48d9f4624e4c0c35eb955aee358f7b16441db9ed45").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            ],
+            data: hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffff713f526b11d").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(Events::decode_any(&log), None);
+
+        // No topics at all, like a log matching the anonymous `LogNote` would not have
+        let log = pb::eth::v2::Log::default();
+        assert_eq!(Events::decode_any(&log), None);
+    }
+
+    #[test]
+    fn it_decode_any_function() {
+        use tests::functions::{FunInt128, Functions};
+
+        let call = pb::eth::v2::Call {
+            input: hex!("5b3357ff0000000000000000000000000000000000000000000000000000000000000000")
+                .to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Functions::decode_any(&call),
+            Some(Ok(Functions::FunInt128(FunInt128 {
+                arg0: BigInt::zero(),
+            }))),
+        );
+    }
+
+    #[test]
+    fn it_decode_any_function_no_match() {
+        use tests::functions::Functions;
+
+        // Unknown selector (ERC20 `transfer(address,uint256)`)
+        let call = pb::eth::v2::Call {
+            input: hex!("a9059cbb").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(Functions::decode_any(&call), None);
+
+        // Input shorter than a selector
+        let call = pb::eth::v2::Call {
+            input: hex!("5b33").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(Functions::decode_any(&call), None);
+    }
+
+    #[test]
+    fn it_decode_any_function_invalid_input() {
+        use tests::functions::Functions;
+
+        let call = pb::eth::v2::Call {
+            input: hex!("5b3357ff").to_vec(),
+            ..Default::default()
+        };
+
+        assert!(matches!(Functions::decode_any(&call), Some(Err(_))));
+    }
+
+    #[test]
+    fn it_decode_constructor_from_artifact_bytecode() {
+        use foundry_artifact::{Constructor, BYTECODE, DEPLOYED_BYTECODE};
+
+        assert_bytes!(
+            BYTECODE,
+            hex!("6080604052348015600f57600080fd5b50603f80601d6000396000f3fe")
+        );
+        assert_bytes!(
+            DEPLOYED_BYTECODE,
+            hex!("6080604052600080fdfea164736f6c6343000814000a")
+        );
+
+        let constructor = Constructor {
+            owner: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+            supply: BigInt::from(1_000_000),
+        };
+
+        let call = pb::eth::v2::Call {
+            call_type: pb::eth::v2::CallType::Create as i32,
+            input: constructor.encode(BYTECODE),
+            ..Default::default()
+        };
+
+        assert_eq!(Constructor::decode(&call), Ok(constructor));
+    }
+
+    #[test]
+    fn it_decode_event_from_signatures() {
+        use signatures::events::Transfer;
+
+        let log = pb::eth::v2::Log {
+            address: hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").to_vec(),
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                hex!("0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000f4240").to_vec(),
+            ..Default::default()
+        };
+
+        assert!(Transfer::match_log(&log));
+        assert_eq!(
+            Transfer::decode(&log),
+            Ok(Transfer {
+                from: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                to: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+                value: BigInt::from(1_000_000),
+            }),
+        );
+    }
+
+    #[test]
+    fn it_encode_decode_fun_from_signatures() {
+        use signatures::functions::TransferFrom;
+
+        let function = TransferFrom {
+            from: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            to: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+            amount: BigInt::from(1_000_000),
+        };
+
+        let call = pb::eth::v2::Call {
+            input: function.encode(),
+            ..Default::default()
+        };
+
+        assert_bytes!(call.input[0..4], hex!("23b872dd"));
+        assert_eq!(TransferFrom::decode(&call), Ok(function));
+        assert_eq!(
+            TransferFrom::output(&hex!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            )),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn it_use_contract_from_signatures() {
+        use erc20::{events::Approval, functions::Allowance};
+
+        let log = pb::eth::v2::Log {
+            topics: vec![
+                hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925").to_vec(),
+                hex!("000000000000000000000000fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                hex!("0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            ..Default::default()
+        };
+
+        assert!(Approval::match_log(&log));
+        assert_eq!(Approval::decode(&log).unwrap().value, BigInt::from(1));
+
+        let call = pb::eth::v2::Call {
+            input: hex!("dd62ed3e").to_vec(),
+            ..Default::default()
+        };
+        assert!(Allowance::match_call(&call));
+    }
+
+    #[test]
+    fn it_generate_extra_derives_and_attributes() {
+        use derives::events::OwnershipTransferred;
+        use derives::functions::SetName;
+        use std::collections::HashSet;
+
+        let event = OwnershipTransferred {
+            previous_owner: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            new_owner: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+        };
+
+        let events: HashSet<_> = vec![
+            event.clone(),
+            event.clone(),
+            OwnershipTransferred::default(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(events.len(), 2);
+
+        let function = SetName {
+            new_name: "pool".to_string(),
+            is_enabled: true,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&function).unwrap(),
+            r#"{"newName":"pool","isEnabled":true}"#
+        );
+    }
+
+    #[test]
+    fn it_convert_event_into_proto() {
+        use crate::pb::contract::v1 as contract;
+        use proto::events::{Registered, Transfer};
+
+        let transfer = Transfer {
+            from: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            to: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+            value: BigInt::from(1_000_000),
+        };
+
+        assert_eq!(
+            contract::Transfer::from(transfer),
+            contract::Transfer {
+                from: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+                to: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+                value: "1000000".to_string(),
+            }
+        );
+
+        let registered = Registered {
+            label: IndexedDynamicValue::new(
+                hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").to_vec(),
+            ),
+            ticks: vec![BigInt::from(-887272), BigInt::from(887272)],
+            flags: (BigInt::from(3), true),
+        };
+
+        assert_eq!(
+            contract::Registered::from(registered),
+            contract::Registered {
+                label: hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8")
+                    .to_vec(),
+                ticks: vec!["-887272".to_string(), "887272".to_string()],
+                flags: hex!("00000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_convert_function_into_proto() {
+        use crate::pb::contract::v1 as contract;
+        use proto::{functions::Register, structs::Entry};
+
+        let entry = Entry {
+            owner: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+            weight: BigInt::from(10),
+        };
+        let proto_entry = contract::Entry {
+            owner: hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec(),
+            weight: "10".to_string(),
+        };
+
+        let register = Register {
+            entry: entry.clone(),
+            entries: vec![entry.clone(), entry],
+            salt: [1u8; 32],
+            operators: vec![hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec()],
+            enabled: true,
+            grid: vec![vec![BigInt::from(1)]],
+        };
+
+        assert_eq!(
+            contract::RegisterCall::from(register),
+            contract::RegisterCall {
+                entry: Some(proto_entry.clone()),
+                entries: vec![proto_entry.clone(), proto_entry],
+                salt: [1u8; 32].to_vec(),
+                operators: vec![hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec()],
+                enabled: true,
+                grid: hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_encode_event_to_log() {
+        use num_traits::Num;
+        use tests::events::EventAddressIdxStringUint256IdxBytes as Event;
+
+        let event = Event {
+            first: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            second: "second string".to_string(),
+            third: BigInt::from(num_bigint::BigInt::from_str_radix("1000000000", 16).unwrap()),
+            fourth: hex!("abdeff90").to_vec(),
+        };
+
+        // Same log as `it_decode_event_address_idx_string_uint256_idx_bytes`
+        let log = event.to_log(&hex!("0000000000000000000000000000000000000000"));
+        assert_eq!(
+            log,
+            pb::eth::v2::Log {
+                address: hex!("0000000000000000000000000000000000000000").to_vec(),
+                topics: vec![
+                    hex!("13c827c8aff69c8c51a406825a22313c37b01da4b8e8cc1ab95ff9e5abd433a9").to_vec(),
+                    hex!("000000000000000000000000ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                    hex!("0000000000000000000000000000000000000000000000000000001000000000").to_vec(),
+                ],
+                data: hex!("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000d7365636f6e6420737472696e67000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004abdeff9000000000000000000000000000000000000000000000000000000000").to_vec(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(Event::decode(&log), Ok(event));
+    }
+
+    #[test]
+    fn it_encode_event_anonymous_to_log() {
+        use tests::events::LogNote as Event;
+
+        let event = Event {
+            sig: hex!("1cff79cd"),
+            guy: hex!("fffdb7377345371817f2b4dd490319755f5899ec").to_vec(),
+            foo: hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+            bar: hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            wad: BigInt::from(10u64),
+            fax: hex!("1cff79cd").to_vec(),
+        };
+
+        let log = event.to_log(&hex!("9759a6ac90977b93b58547b4a71c78317f391a28"));
+        assert_eq!(log.topics.len(), 4);
+        assert_bytes!(
+            log.topics[0],
+            hex!("1cff79cd00000000000000000000000000000000000000000000000000000000")
+        );
+        assert_eq!(
+            Event::match_log_at(&log, &hex!("9759a6ac90977b93b58547b4a71c78317f391a28")),
+            true
+        );
+        assert_eq!(Event::decode(&log), Ok(event));
+    }
+
+    #[test]
+    fn it_encode_event_indexed_dynamic_value() {
+        use tests::events::EventStringIdx;
+
+        let event = EventStringIdx {
+            param0: IndexedDynamicValue::<String>::from_value("hello"),
+        };
+
+        let log = Event::encode(&event);
+        assert_eq!(log.address, Vec::<u8>::new());
+        assert_bytes!(
+            log.topics[1],
+            hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8")
+        );
+        assert_eq!(log.data, Vec::<u8>::new());
+        assert_eq!(EventStringIdx::match_and_decode(&log), Some(event));
+    }
+
+    #[test]
+    fn it_encode_fun_output_string_string() {
+        use tests::functions::FunReturnsStringString as Function;
+
+        // Same return data as `it_decode_fun_output_string_string`
+        let return_data = Function::encode_output(&("test1".to_string(), "test2".to_string()));
+        assert_bytes!(
+            return_data,
+            hex!("000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000005746573743100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000057465737432000000000000000000000000000000000000000000000000000000"),
+        );
+
+        let call = pb::eth::v2::Call {
+            input: Function {}.encode(),
+            return_data,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Function::output_call(&call),
+            Ok(("test1".to_string(), "test2".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_encode_fun_output_struct_array() {
+        use tests::functions::FunStructNested as Function;
+        use tests::structs::Asset;
+
+        let assets = vec![Asset {
+            token: hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").to_vec(),
+            amount: BigInt::from(1u64),
+        }];
+
+        // Same return data as `it_decode_fun_struct_array_output`
+        assert_bytes!(
+            Function::encode_output(&assets),
+            hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000001000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000000000000000000000000000000000000000000001"),
+        );
+    }
+
+    #[test]
+    fn it_decode_rpc_response_from_encoded_output() {
+        use native_ints::functions::FunNativeInts as Function;
+        use substreams_ethereum::pb::eth::rpc::RpcResponse;
+        use substreams_ethereum::rpc::RpcBatch;
+
+        let response = RpcResponse {
+            raw: Function::encode_output(&42u64),
+            failed: false,
+        };
+
+        assert_eq!(RpcBatch::decode::<_, Function>(&response), Some(42u64));
+    }
+
+    #[test]
+    fn it_expose_event_signature_metadata() {
+        use signatures::events::Transfer;
+
+        fn topic0<E: Event>() -> [u8; 32] {
+            E::TOPIC0
+        }
+
+        assert_eq!(Transfer::SIGNATURE, "Transfer(address,address,uint256)");
+        assert_eq!(
+            topic0::<Transfer>(),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+        assert_eq!(
+            Transfer::input_types(),
+            vec![
+                ethabi::ParamType::Address,
+                ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(256),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_expose_function_signature_metadata() {
+        use signatures::functions::TransferFrom;
+        use tests::functions::FunStructNested;
+
+        assert_eq!(
+            TransferFrom::SIGNATURE,
+            "transferFrom(address,address,uint256)"
+        );
+        assert_eq!(TransferFrom::SELECTOR, hex!("23b872dd"));
+        assert_eq!(TransferFrom::output_types(), vec![ethabi::ParamType::Bool]);
+
+        assert_eq!(
+            FunStructNested::SIGNATURE,
+            "funStructNested((address,(address,uint256)[]))"
+        );
+        assert_eq!(
+            FunStructNested::output_types(),
+            vec![ethabi::ParamType::Array(Box::new(
+                ethabi::ParamType::Tuple(vec![
+                    ethabi::ParamType::Address,
+                    ethabi::ParamType::Uint(256)
+                ])
+            ))]
+        );
+    }
+
+    #[test]
+    fn it_match_receive_and_fallback_calls() {
+        let transfer = pb::eth::v2::Call {
+            caller: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+            value: Some(pb::eth::v2::BigInt {
+                bytes: hex!("0de0b6b3a7640000").to_vec(),
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            vault::functions::Receive::match_and_decode(&transfer),
+            Some(vault::functions::Receive {
+                caller: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                value: BigInt::from(1_000_000_000_000_000_000u64),
+                input: vec![],
+            })
+        );
+        assert_eq!(vault::functions::Fallback::match_call(&transfer), false);
+        assert_eq!(proxy::functions::Fallback::match_call(&transfer), true);
+
+        let deposit = pb::eth::v2::Call {
+            input: vault::functions::Deposit {}.encode(),
+            ..transfer.clone()
+        };
+
+        assert_eq!(vault::functions::Receive::match_call(&deposit), false);
+        assert_eq!(vault::functions::Fallback::match_call(&deposit), false);
+
+        // `receive` is only matched for calls carrying a value
+        for value in [
+            None,
+            Some(pb::eth::v2::BigInt { bytes: vec![] }),
+            Some(pb::eth::v2::BigInt { bytes: vec![0] }),
+        ] {
+            let empty = pb::eth::v2::Call {
+                value,
+                ..transfer.clone()
+            };
+
+            assert_eq!(vault::functions::Receive::match_call(&empty), false);
+            assert_eq!(vault::functions::Fallback::match_call(&empty), false);
+        }
+
+        let unknown = pb::eth::v2::Call {
+            input: hex!("deadbeef01").to_vec(),
+            value: None,
+            ..transfer
+        };
+
+        assert_eq!(vault::functions::Receive::match_call(&unknown), false);
+        assert_eq!(
+            vault::functions::Fallback::match_and_decode(&unknown),
+            Some(vault::functions::Fallback {
+                caller: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                value: BigInt::zero(),
+                input: hex!("deadbeef01").to_vec(),
+            })
+        );
+
+        let creation = pb::eth::v2::Call {
+            call_type: pb::eth::v2::CallType::Create as i32,
+            input: hex!("6080604052348015600f57600080fd5b50").to_vec(),
+            ..unknown
+        };
+
+        assert_eq!(vault::functions::Fallback::match_call(&creation), false);
+        assert_eq!(proxy::functions::Fallback::match_call(&creation), false);
+        assert_eq!(
+            vault::functions::Receive::match_call(&pb::eth::v2::Call {
+                input: vec![],
+                value: Some(pb::eth::v2::BigInt { bytes: vec![1] }),
+                ..creation
+            }),
+            false
+        );
+    }
+
+    #[test]
+    fn it_collect_contract_handle_calls_into_batch() {
+        use erc20::functions::Allowance;
+        use signatures::functions::BalanceOf;
+
+        let owner = hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec();
+        let spender = hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec();
+
+        let token = erc20::Contract::at(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+        assert_eq!(
+            token.address(),
+            hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+        );
+
+        let other = signatures::Contract::at(hex!("dac17f958d2ee523a2206206994597c13d831ec7"));
+        let batch = other
+            .batch_with(
+                token
+                    .batch()
+                    .allowance(owner.clone(), spender.clone())
+                    .into_rpc_batch(),
+            )
+            .balance_of(owner.clone())
+            .into_rpc_batch();
+
+        let calls = batch.calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(
+            calls[0].to_addr,
+            hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+        );
+        assert_eq!(
+            calls[0].data,
+            Allowance {
+                owner: owner.clone(),
+                spender,
+            }
+            .encode()
+        );
+        assert_eq!(
+            calls[1].to_addr,
+            hex!("dac17f958d2ee523a2206206994597c13d831ec7")
+        );
+        assert_eq!(calls[1].data, BalanceOf { owner }.encode());
+    }
+
+    #[test]
+    fn it_name_overloads_after_params_types() {
+        use overloads::events::{DepositAddressUint256, DepositAddressUint256Bytes};
+        use overloads::functions::{
+            SafeTransferFromAddressAddressUint256, SafeTransferFromWithData,
+        };
+
+        assert_eq!(
+            SafeTransferFromAddressAddressUint256::SIGNATURE,
+            "safeTransferFrom(address,address,uint256)"
+        );
+        assert_eq!(
+            SafeTransferFromWithData::SIGNATURE,
+            "safeTransferFrom(address,address,uint256,bytes)"
+        );
+        assert_eq!(DepositAddressUint256::SIGNATURE, "Deposit(address,uint256)");
+        assert_eq!(
+            DepositAddressUint256Bytes::SIGNATURE,
+            "Deposit(address,uint256,bytes)"
+        );
+    }
+
+    #[test]
+    fn it_fails_renaming_unknown_signature() {
+        let result = substreams_ethereum::Abigen::from_signatures(&[
+            "function safeTransferFrom(address from, address to, uint256 tokenId)",
+        ])
+        .unwrap()
+        .rename_function(
+            "safeTransferFrom(address,address,uint256,bytes)",
+            "WithData",
+        )
+        .generate();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_generate_only_selected_events_and_functions() {
+        assert_eq!(
+            token::events::Transfer::SIGNATURE,
+            "Transfer(address,address,uint256)"
+        );
+        assert_eq!(token::functions::BalanceOf::SIGNATURE, "balanceOf(address)");
+        assert_eq!(
+            token::functions::Transfer::SIGNATURE,
+            "transfer(address,uint256)"
+        );
+    }
+
+    #[test]
+    fn it_match_fallback_calls_with_filtered_functions() {
+        use token::functions::{BalanceOf, Fallback};
+
+        let call = |input: Vec<u8>| pb::eth::v2::Call {
+            input,
+            ..Default::default()
+        };
+
+        // `totalSupply()` is a real function of the contract, left out by the filters
+        assert_eq!(
+            Fallback::match_call(&call(hex!("18160ddd").to_vec())),
+            false
+        );
+        assert_eq!(
+            Fallback::match_call(&call(
+                BalanceOf {
+                    owner: hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec(),
+                }
+                .encode()
+            )),
+            false
+        );
+        assert_eq!(Fallback::match_call(&call(hex!("deadbeef").to_vec())), true);
+    }
+
+    #[test]
+    fn it_fails_filtering_unknown_function() {
+        let result = substreams_ethereum::Abigen::from_signatures(&[
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "function balanceOf(address owner) view returns (uint256)",
+        ])
+        .unwrap()
+        .include_events(&["Transfer"])
+        .exclude_functions(&["totalSupply"])
+        .generate();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_expose_function_state_mutability() {
+        use ethabi::StateMutability;
+
+        assert_eq!(
+            erc20::functions::Allowance::STATE_MUTABILITY,
+            StateMutability::View
+        );
+        assert_eq!(
+            token::functions::Transfer::STATE_MUTABILITY,
+            StateMutability::NonPayable
+        );
+        assert_eq!(
+            vault::functions::Deposit::STATE_MUTABILITY,
+            StateMutability::Payable
+        );
+        assert!(vault::functions::Deposit::is_payable());
+        assert!(!token::functions::Transfer::is_payable());
+    }
+
+    #[test]
+    fn it_generate_rpc_call_for_non_view_functions_when_requested() {
+        let call: fn(&token_rpc::functions::Transfer, Vec<u8>) -> Option<bool> =
+            token_rpc::functions::Transfer::call;
+        let _ = call;
+
+        let call: fn(&erc20::functions::Allowance, Vec<u8>) -> Option<BigInt> =
+            erc20::functions::Allowance::call;
+        let _ = call;
+    }
+
+    #[test]
+    fn it_document_bindings_with_natspec() {
+        let generated = include_str!("abi/foundry_artifact.rs");
+
+        assert!(generated.contains(
+            "    /// Mints `amount` tokens to `to`
+    ///
+    /// Only callable by the owner.
+    ///
+    /// Function `mint(address,uint256)`, selector `0x40c10f19`.
+"
+        ));
+        assert!(generated.contains("        /// Receiver of the tokens\n        pub to: Vec<u8>,"));
+        assert!(generated.contains("    /// Emitted when tokens are minted\n    ///\n    /// Event `Minted(address,uint256)`"));
+        assert!(generated.contains(
+            "        /// Amount of tokens minted\n        pub amount: substreams::scalar::BigInt,"
+        ));
+
+        // Plain ABIs have no NatSpec, the signature is documented nonetheless
+        let generated = include_str!("abi/signatures.rs");
+        assert!(
+            generated.contains("    /// Function `balanceOf(address)`, selector `0x70a08231`.\n")
+        );
+    }
+
+    #[test]
+    fn it_report_typed_decode_errors() {
+        use tests::events::EventAddressIdxString;
+
+        let log = pb::eth::v2::Log {
+            topics: vec![EventAddressIdxString::TOPIC0.to_vec()],
+            ..Default::default()
+        };
+        assert_eq!(
+            EventAddressIdxString::decode(&log),
+            Err(DecodeError::TopicMismatch {
+                event: "EventAddressIdxString".into(),
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        let call = pb::eth::v2::Call {
+            input: hex!("a9059c").to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(
+            signatures::functions::BalanceOf::decode(&call),
+            Err(DecodeError::DataSize {
+                item: "balanceOf".into(),
+                expected: 4,
+                actual: 3,
+            })
+        );
+
+        let error = signatures::functions::BalanceOf::output(&hex!("2a")).unwrap_err();
+        assert!(matches!(
+            error,
+            DecodeError::Abi {
+                ref item,
+                param: None,
+                ..
+            } if item == "balanceOf"
+        ));
+
+        // Code still dealing with `String` errors keeps working through `?`
+        fn decode(call: &pb::eth::v2::Call) -> Result<Vec<u8>, String> {
+            Ok(signatures::functions::BalanceOf::decode(call)?.owner)
+        }
+        assert_eq!(
+            decode(&call),
+            Err("`balanceOf` expects at least 4 bytes of data, got 3".to_string())
+        );
+    }
+
+    #[test]
+    fn it_call_function_through_mock_rpc_provider() {
+        use erc20::functions::Allowance;
+        use substreams::scalar::BigInt;
+        use substreams_ethereum::rpc::{self, MockProvider};
+
+        let token = hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").to_vec();
+        let other_token = hex!("dac17f958d2ee523a2206206994597c13d831ec7").to_vec();
+        let owner = hex!("ab07a50ad459b41fe065f7bbab866d5390e9f705").to_vec();
+        let spender = hex!("cd91a50ad459b41fe065f7bbab866d5390e945fa").to_vec();
+
+        let allowance = Allowance {
+            owner: owner.clone(),
+            spender: spender.clone(),
+        };
+
+        rpc::set_provider(
+            MockProvider::new()
+                .with_response(
+                    token.clone(),
+                    allowance.encode(),
+                    Allowance::encode_output(&BigInt::from(1000)),
+                )
+                .with_failure(other_token.clone(), allowance.encode())
+                .with_handler(other_token.clone(), Allowance::SELECTOR, |data| {
+                    let call = Allowance::decode(&pb::eth::v2::Call {
+                        input: data.to_vec(),
+                        ..Default::default()
+                    })
+                    .ok()?;
+
+                    Some(Allowance::encode_output(&BigInt::from(
+                        call.owner[19] as u64,
+                    )))
+                }),
+        );
+
+        assert_eq!(allowance.call(token.clone()), Some(BigInt::from(1000)));
+        assert_eq!(allowance.call(other_token.clone()), None);
+        assert_eq!(
+            Allowance {
+                owner: spender.clone(),
+                spender: owner.clone(),
+            }
+            .call(other_token.clone()),
+            Some(BigInt::from(0xfa))
+        );
+
+        let responses = erc20::Contract::at(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"))
+            .batch()
+            .allowance(owner, spender)
+            .execute()
+            .unwrap();
+        assert_eq!(
+            substreams_ethereum::rpc::RpcBatch::decode::<_, Allowance>(&responses.responses[0]),
+            Some(BigInt::from(1000))
+        );
+
+        rpc::reset_provider();
+    }
 }
@@ -0,0 +1,46 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Entry {
+    #[prost(bytes="vec", tag="1")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub weight: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Registered {
+    #[prost(bytes="vec", tag="1")]
+    pub label: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="2")]
+    pub ticks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bytes="vec", tag="3")]
+    pub flags: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfer {
+    #[prost(bytes="vec", tag="1")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="3")]
+    pub value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterCall {
+    #[prost(message, optional, tag="1")]
+    pub entry: ::core::option::Option<Entry>,
+    #[prost(message, repeated, tag="2")]
+    pub entries: ::prost::alloc::vec::Vec<Entry>,
+    #[prost(bytes="vec", tag="3")]
+    pub salt: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="4")]
+    pub operators: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bool, tag="5")]
+    pub enabled: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub grid: ::prost::alloc::vec::Vec<u8>,
+}
+// @@protoc_insertion_point(module)
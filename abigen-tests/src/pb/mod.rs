@@ -0,0 +1,8 @@
+//! Messages generated by prost from `proto/contract.proto`, itself generated by abigen
+//! from `abi/proto.json`.
+
+pub mod contract {
+    pub mod v1 {
+        include!("contract.v1.rs");
+    }
+}
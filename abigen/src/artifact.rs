@@ -0,0 +1,286 @@
+use anyhow::format_err;
+use serde_json::Value;
+
+/// Contract's ABI loaded either from a plain ABI JSON array, from a compilation artifact
+/// produced by Hardhat, Foundry or Truffle, which all store the ABI under an `"abi"` key, or
+/// from the output of the solc compiler's standard JSON interface.
+///
+/// When the artifact provides them, the bytecode and the NatSpec documentation are captured
+/// too. Plain ABI files never have them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Artifact {
+    /// The ABI JSON array of the contract.
+    pub abi: Value,
+    /// The init bytecode used to deploy the contract, `None` if absent or if it contains
+    /// unresolved library link placeholders.
+    pub bytecode: Option<Vec<u8>>,
+    /// The runtime bytecode of the deployed contract, `None` if absent or if it contains
+    /// unresolved library link placeholders.
+    pub deployed_bytecode: Option<Vec<u8>>,
+    /// The developer NatSpec documentation (`@dev`, `@param`, `@return` and `@title` tags).
+    pub devdoc: Option<Value>,
+    /// The user NatSpec documentation (`@notice` tags).
+    pub userdoc: Option<Value>,
+}
+
+impl Artifact {
+    /// Loads the artifact from its JSON `bytes`, auto-detecting the layout.
+    ///
+    /// A solc standard JSON output (`contracts.<file>.<name>`) must hold a single contract,
+    /// otherwise the entry of the wanted contract has to be extracted from it first.
+    pub fn load(bytes: &[u8]) -> Result<Artifact, anyhow::Error> {
+        let value: Value = serde_json::from_slice(bytes)?;
+
+        match value {
+            Value::Array(_) => Ok(Artifact {
+                abi: value,
+                ..Default::default()
+            }),
+            Value::Object(_) if value["abi"].is_array() => Ok(Artifact::from_contract(&value)),
+            Value::Object(_) if value["contracts"].is_object() => {
+                Artifact::from_standard_json(&value)
+            }
+            _ => Err(format_err!(
+                "unsupported ABI format, expected a JSON array, a Hardhat, Foundry or Truffle artifact with an `abi` key or a solc standard JSON output"
+            )),
+        }
+    }
+
+    /// Artifacts of Hardhat, Foundry and Truffle as well as a single contract entry of solc's
+    /// standard JSON output, which nests the bytecode under `evm`.
+    fn from_contract(contract: &Value) -> Artifact {
+        let evm = &contract["evm"];
+
+        Artifact {
+            abi: contract["abi"].clone(),
+            bytecode: bytecode(&contract["bytecode"]).or_else(|| bytecode(&evm["bytecode"])),
+            deployed_bytecode: bytecode(&contract["deployedBytecode"])
+                .or_else(|| bytecode(&evm["deployedBytecode"])),
+            devdoc: natspec(contract, "devdoc"),
+            userdoc: natspec(contract, "userdoc"),
+        }
+    }
+
+    fn from_standard_json(output: &Value) -> Result<Artifact, anyhow::Error> {
+        let contracts: Vec<_> = output["contracts"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .flat_map(|(file, contracts)| {
+                contracts
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .filter(|(_, contract)| contract["abi"].is_array())
+                    .map(move |(name, contract)| (format!("{}:{}", file, name), contract))
+            })
+            .collect();
+
+        match contracts.as_slice() {
+            [(_, contract)] => Ok(Artifact::from_contract(contract)),
+            [] => Err(format_err!(
+                "solc standard JSON output has no contract with an ABI, make sure `abi` is part of the `outputSelection`"
+            )),
+            _ => Err(format_err!(
+                "solc standard JSON output holds {} contracts ({}), extract the `contracts.<file>.<name>` entry of the one to generate bindings for",
+                contracts.len(),
+                contracts.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>().join(", ")
+            )),
+        }
+    }
+}
+
+/// Hardhat and Truffle store the bytecode as an hexadecimal string while Foundry stores it
+/// in the `object` field of an object also containing the source map and link references.
+fn bytecode(value: &Value) -> Option<Vec<u8>> {
+    let encoded = match value {
+        Value::String(encoded) => encoded.as_str(),
+        Value::Object(_) => value["object"].as_str()?,
+        _ => return None,
+    };
+
+    let encoded = encoded.strip_prefix("0x").unwrap_or(encoded);
+    if encoded.is_empty() {
+        return None;
+    }
+
+    hex::decode(encoded).ok()
+}
+
+/// Truffle (and Foundry with `extra_output`) store the NatSpec at the root of the artifact,
+/// otherwise it's found in the compiler's metadata which Foundry stores as an object and
+/// Truffle as a JSON encoded string.
+fn natspec(artifact: &Value, key: &str) -> Option<Value> {
+    if artifact[key].is_object() {
+        return Some(artifact[key].clone());
+    }
+
+    let metadata = match &artifact["metadata"] {
+        Value::String(encoded) => serde_json::from_str(encoded).ok()?,
+        metadata => metadata.clone(),
+    };
+
+    match &metadata["output"][key] {
+        Value::Object(_) => Some(metadata["output"][key].clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Artifact;
+
+    #[test]
+    fn test_load_abi_array() {
+        let abi = json!([{ "type": "event", "name": "Paused", "inputs": [], "anonymous": false }]);
+
+        let artifact = Artifact::load(abi.to_string().as_bytes()).unwrap();
+
+        assert_eq!(
+            artifact,
+            Artifact {
+                abi,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_hardhat_artifact() {
+        let artifact = json!({
+            "_format": "hh-sol-artifact-1",
+            "contractName": "Pool",
+            "sourceName": "contracts/Pool.sol",
+            "abi": [],
+            "bytecode": "0x6080",
+            "deployedBytecode": "0x",
+            "linkReferences": {},
+            "deployedLinkReferences": {}
+        });
+
+        let artifact = Artifact::load(artifact.to_string().as_bytes()).unwrap();
+
+        assert_eq!(
+            artifact,
+            Artifact {
+                abi: json!([]),
+                bytecode: Some(vec![0x60, 0x80]),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_foundry_artifact() {
+        let artifact = json!({
+            "abi": [],
+            "bytecode": { "object": "0x6080", "sourceMap": "", "linkReferences": {} },
+            "deployedBytecode": { "object": "0x6001", "sourceMap": "", "linkReferences": {} },
+            "methodIdentifiers": {},
+            "metadata": {
+                "output": {
+                    "abi": [],
+                    "devdoc": { "kind": "dev", "methods": {}, "title": "Pool" },
+                    "userdoc": { "kind": "user", "methods": {}, "notice": "A pool" }
+                }
+            }
+        });
+
+        let artifact = Artifact::load(artifact.to_string().as_bytes()).unwrap();
+
+        assert_eq!(
+            artifact,
+            Artifact {
+                abi: json!([]),
+                bytecode: Some(vec![0x60, 0x80]),
+                deployed_bytecode: Some(vec![0x60, 0x01]),
+                devdoc: Some(json!({ "kind": "dev", "methods": {}, "title": "Pool" })),
+                userdoc: Some(json!({ "kind": "user", "methods": {}, "notice": "A pool" })),
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_truffle_artifact() {
+        let metadata = json!({
+            "output": { "userdoc": { "kind": "user", "methods": {}, "notice": "A pool" } }
+        });
+
+        let artifact = json!({
+            "contractName": "Pool",
+            "abi": [],
+            "metadata": metadata.to_string(),
+            "bytecode": "0x6080__$d5ad6e4e17c3b8d9e9b6bc0d7a8e7a4c1d$__",
+            "deployedBytecode": "0x6001",
+            "devdoc": { "kind": "dev", "methods": {} }
+        });
+
+        let artifact = Artifact::load(artifact.to_string().as_bytes()).unwrap();
+
+        assert_eq!(
+            artifact,
+            Artifact {
+                abi: json!([]),
+                bytecode: None,
+                deployed_bytecode: Some(vec![0x60, 0x01]),
+                devdoc: Some(json!({ "kind": "dev", "methods": {} })),
+                userdoc: Some(json!({ "kind": "user", "methods": {}, "notice": "A pool" })),
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_standard_json_contract() {
+        let contract = json!({
+            "abi": [],
+            "devdoc": { "kind": "dev", "methods": {}, "title": "Pool" },
+            "userdoc": { "kind": "user", "methods": {} },
+            "evm": {
+                "bytecode": { "object": "6080", "linkReferences": {} },
+                "deployedBytecode": { "object": "6001", "linkReferences": {} },
+                "methodIdentifiers": {}
+            }
+        });
+        let expected = Artifact {
+            abi: json!([]),
+            bytecode: Some(vec![0x60, 0x80]),
+            deployed_bytecode: Some(vec![0x60, 0x01]),
+            devdoc: Some(json!({ "kind": "dev", "methods": {}, "title": "Pool" })),
+            userdoc: Some(json!({ "kind": "user", "methods": {} })),
+        };
+
+        let artifact = Artifact::load(contract.to_string().as_bytes()).unwrap();
+        assert_eq!(artifact, expected);
+
+        let output = json!({
+            "contracts": { "contracts/Pool.sol": { "Pool": contract } },
+            "sources": { "contracts/Pool.sol": { "id": 0 } }
+        });
+
+        let artifact = Artifact::load(output.to_string().as_bytes()).unwrap();
+        assert_eq!(artifact, expected);
+    }
+
+    #[test]
+    fn test_load_standard_json_many_contracts() {
+        let output = json!({
+            "contracts": {
+                "contracts/Pool.sol": { "Pool": { "abi": [] } },
+                "contracts/Token.sol": { "Token": { "abi": [] } }
+            }
+        });
+
+        let err = Artifact::load(output.to_string().as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "solc standard JSON output holds 2 contracts (contracts/Pool.sol:Pool, contracts/Token.sol:Token), extract the `contracts.<file>.<name>` entry of the one to generate bindings for"
+        );
+    }
+
+    #[test]
+    fn test_load_unsupported() {
+        assert!(Artifact::load(br#"{ "contractName": "Pool" }"#).is_err());
+    }
+}
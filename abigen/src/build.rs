@@ -1,8 +1,11 @@
 use std::path::{Path, PathBuf};
 use std::str;
 
-use crate::{generate_abi_code, generate_abi_code_from_bytes, normalize_path};
-use anyhow::Context;
+use crate::{
+    load_contract_from_bytes, load_contract_from_signatures, normalize_path, read_abi,
+    signatures::parse_signatures, Options, ProtoOptions,
+};
+use anyhow::{format_err, Context};
 
 #[derive(Debug, Clone)]
 pub struct Abigen<'a> {
@@ -12,17 +15,35 @@ pub struct Abigen<'a> {
 
     /// The bytes of the ABI for the contract whose bindings are being generated.
     bytes: Option<&'a [u8]>,
+
+    /// The human-readable signatures of the events, functions and errors of the contract whose
+    /// bindings are being generated.
+    signatures: Option<Vec<String>>,
+
+    /// The options tweaking the generated code.
+    options: Options,
+
+    /// Kept apart from `options.proto` until [Abigen::proto] enables the protobuf generation,
+    /// so the builder methods can be called in any order.
+    proto_hex_addresses: bool,
 }
 
 impl<'a> Abigen<'a> {
     /// Creates a new builder for the given contract name and where the ABI JSON file can be found
     /// at `path`, which is relative to the your crate's root directory (where `Cargo.toml` file is located).
+    ///
+    /// The file can either be a plain ABI JSON array, a Hardhat, Foundry or Truffle compilation
+    /// artifact or a solc standard JSON output holding a single contract, the layout is detected
+    /// automatically.
     pub fn new<S: AsRef<str>>(_contract_name: S, path: S) -> Result<Self, anyhow::Error> {
         let path = normalize_path(path.as_ref()).context("normalize path")?;
 
         Ok(Self {
             abi_path: path,
             bytes: None,
+            signatures: None,
+            options: Options::default(),
+            proto_hex_addresses: false,
         })
     }
 
@@ -35,15 +56,233 @@ impl<'a> Abigen<'a> {
         Ok(Self {
             abi_path: "".parse()?,
             bytes: Some(abi_bytes),
+            signatures: None,
+            options: Options::default(),
+            proto_hex_addresses: false,
+        })
+    }
+
+    /// Creates a new builder from human-readable (ethers style) signatures like
+    /// `event Transfer(address indexed from, address indexed to, uint256 value)` or
+    /// `function balanceOf(address owner) view returns (uint256)`, no ABI JSON file needed.
+    ///
+    /// Tuples are generated as Rust tuples since signatures don't carry Solidity struct names.
+    pub fn from_signatures<S: AsRef<str>>(signatures: &[S]) -> Result<Self, anyhow::Error> {
+        parse_signatures(signatures)?;
+
+        Ok(Self {
+            abi_path: "".parse()?,
+            bytes: None,
+            signatures: Some(signatures.iter().map(|s| s.as_ref().to_string()).collect()),
+            options: Options::default(),
+            proto_hex_addresses: false,
         })
     }
 
+    /// Generates `substreams_ethereum::Address` for `address` params and `substreams_ethereum::H256`
+    /// for `bytes32` params instead of `Vec<u8>` and `[u8; 32]`.
+    pub fn address_types(mut self, enabled: bool) -> Self {
+        self.options.address_types = enabled;
+        self
+    }
+
+    /// Generates Rust primitive integers (`u8` to `u128` and `i8` to `i128`) for `uintN` and `intN`
+    /// params up to 128 bits instead of `substreams::scalar::BigInt`. Decoding a value that does
+    /// not fit in the param's declared size fails with an error.
+    pub fn native_ints(mut self, enabled: bool) -> Self {
+        self.options.native_ints = enabled;
+        self
+    }
+
+    /// Generates the RPC helpers (`call` and `RPCDecodable`) for every function with outputs, by
+    /// default only `view` and `pure` functions get them.
+    pub fn rpc_all_functions(mut self, enabled: bool) -> Self {
+        self.options.rpc_all_functions = enabled;
+        self
+    }
+
+    /// Names overloaded functions and events after their params types, e.g.
+    /// `SafeTransferFromAddressAddressUint256` and `SafeTransferFromAddressAddressUint256Bytes`,
+    /// instead of suffixing them with an index which depends on the ordering of the ABI.
+    pub fn typed_overload_names(mut self, enabled: bool) -> Self {
+        self.options.typed_overload_names = enabled;
+        self
+    }
+
+    /// Names the struct of the function with the canonical `signature` (e.g.
+    /// `safeTransferFrom(address,address,uint256,bytes)`) `name` (e.g. `SafeTransferFromWithData`),
+    /// taking precedence over the naming of overloads. Generation fails if no function of the
+    /// contract has this signature.
+    pub fn rename_function<S: AsRef<str>>(mut self, signature: S, name: S) -> Self {
+        self.options
+            .function_renames
+            .insert(signature.as_ref().to_string(), name.as_ref().to_string());
+        self
+    }
+
+    /// Names the struct of the event with the canonical `signature` (e.g.
+    /// `Transfer(address,address,uint256)`) `name`, taking precedence over the naming of
+    /// overloads. Generation fails if no event of the contract has this signature.
+    pub fn rename_event<S: AsRef<str>>(mut self, signature: S, name: S) -> Self {
+        self.options
+            .event_renames
+            .insert(signature.as_ref().to_string(), name.as_ref().to_string());
+        self
+    }
+
+    /// Only generates the `events` given by ABI name (e.g. `Transfer`, all overloads) or by
+    /// canonical signature (e.g. `Transfer(address,address,uint256)`). The Solidity structs
+    /// used only by the events that are left out are not generated either.
+    pub fn include_events<S: AsRef<str>>(mut self, events: &[S]) -> Self {
+        self.options
+            .events
+            .include
+            .extend(events.iter().map(|event| event.as_ref().to_string()));
+        self
+    }
+
+    /// Doesn't generate the `events` given by ABI name or by canonical signature.
+    pub fn exclude_events<S: AsRef<str>>(mut self, events: &[S]) -> Self {
+        self.options
+            .events
+            .exclude
+            .extend(events.iter().map(|event| event.as_ref().to_string()));
+        self
+    }
+
+    /// Only generates the `functions` given by ABI name (e.g. `balanceOf`, all overloads) or by
+    /// canonical signature (e.g. `balanceOf(address)`). The Solidity structs used only by the
+    /// functions that are left out are not generated either.
+    pub fn include_functions<S: AsRef<str>>(mut self, functions: &[S]) -> Self {
+        self.options.functions.include.extend(
+            functions
+                .iter()
+                .map(|function| function.as_ref().to_string()),
+        );
+        self
+    }
+
+    /// Doesn't generate the `functions` given by ABI name or by canonical signature.
+    pub fn exclude_functions<S: AsRef<str>>(mut self, functions: &[S]) -> Self {
+        self.options.functions.exclude.extend(
+            functions
+                .iter()
+                .map(|function| function.as_ref().to_string()),
+        );
+        self
+    }
+
+    /// Adds `derive` (e.g. `Eq`, `Hash` or `serde::Serialize`) to every generated struct, on top
+    /// of the default `Debug`, `Clone` and `PartialEq`. The enums dispatching on events,
+    /// functions and errors are left untouched.
+    pub fn add_derive<S: AsRef<str>>(mut self, derive: S) -> Self {
+        self.options
+            .attributes
+            .derives
+            .push(derive.as_ref().to_string());
+        self
+    }
+
+    /// Adds the outer `attribute` (e.g. `serde(rename_all = "camelCase")`, with or without the
+    /// surrounding `#[...]`) to every generated struct.
+    pub fn add_attribute<S: AsRef<str>>(mut self, attribute: S) -> Self {
+        self.options
+            .attributes
+            .attributes
+            .push(attribute.as_ref().to_string());
+        self
+    }
+
+    /// Adds `derive` to the struct of the `event`, which is the event's name in the ABI. All
+    /// overloads of the event are affected.
+    pub fn add_event_derive<S: AsRef<str>>(mut self, event: S, derive: S) -> Self {
+        self.options
+            .event_attributes
+            .entry(event.as_ref().to_string())
+            .or_default()
+            .derives
+            .push(derive.as_ref().to_string());
+        self
+    }
+
+    /// Adds the outer `attribute` to the struct of the `event`, which is the event's name in
+    /// the ABI. All overloads of the event are affected.
+    pub fn add_event_attribute<S: AsRef<str>>(mut self, event: S, attribute: S) -> Self {
+        self.options
+            .event_attributes
+            .entry(event.as_ref().to_string())
+            .or_default()
+            .attributes
+            .push(attribute.as_ref().to_string());
+        self
+    }
+
+    /// Adds `derive` to the struct of the `function`, which is the function's name in the ABI.
+    /// All overloads of the function are affected.
+    pub fn add_function_derive<S: AsRef<str>>(mut self, function: S, derive: S) -> Self {
+        self.options
+            .function_attributes
+            .entry(function.as_ref().to_string())
+            .or_default()
+            .derives
+            .push(derive.as_ref().to_string());
+        self
+    }
+
+    /// Adds the outer `attribute` to the struct of the `function`, which is the function's name
+    /// in the ABI. All overloads of the function are affected.
+    pub fn add_function_attribute<S: AsRef<str>>(mut self, function: S, attribute: S) -> Self {
+        self.options
+            .function_attributes
+            .entry(function.as_ref().to_string())
+            .or_default()
+            .attributes
+            .push(attribute.as_ref().to_string());
+        self
+    }
+
+    /// Imports `path` (e.g. `serde::{Deserialize, Serialize}`) in every generated module, for
+    /// derives and attributes that are not written with their full path.
+    pub fn add_import<S: AsRef<str>>(mut self, path: S) -> Self {
+        self.options.imports.push(path.as_ref().to_string());
+        self
+    }
+
+    /// Generates a `.proto` file in the protobuf `package` (e.g. `contract.v1`) with one message
+    /// per event, function (suffixed by `Call`) and Solidity struct, to be written with
+    /// [GeneratedBindings::write_proto_to_file]. The generated structs get a `From` conversion
+    /// into the messages that prost generates from it in `rust_module` (e.g. `crate::pb::contract::v1`).
+    ///
+    /// Addresses are `bytes` and integers decimal strings, types protobuf cannot represent like
+    /// tuples that are not Solidity structs or arrays of arrays are kept ABI encoded in `bytes`.
+    pub fn proto<S: AsRef<str>>(mut self, package: S, rust_module: S) -> Self {
+        self.options.proto = Some(ProtoOptions {
+            package: package.as_ref().to_string(),
+            rust_module: rust_module.as_ref().to_string(),
+            hex_addresses: self.proto_hex_addresses,
+        });
+        self
+    }
+
+    /// Represents addresses as `0x` prefixed hexadecimal strings instead of bytes in the
+    /// messages generated by [Abigen::proto], has no effect without it.
+    pub fn proto_hex_addresses(mut self, enabled: bool) -> Self {
+        self.proto_hex_addresses = enabled;
+        if let Some(proto) = self.options.proto.as_mut() {
+            proto.hex_addresses = enabled;
+        }
+        self
+    }
+
     pub fn generate(&self) -> Result<GeneratedBindings, anyhow::Error> {
-        let tokens = match &self.bytes {
-            None => generate_abi_code(self.abi_path.to_string_lossy()),
-            Some(bytes) => generate_abi_code_from_bytes(bytes),
+        let contract = match (&self.bytes, &self.signatures) {
+            (_, Some(signatures)) => load_contract_from_signatures(signatures, &self.options),
+            (Some(bytes), None) => load_contract_from_bytes(bytes, &self.options),
+            (None, None) => read_abi(&self.abi_path.to_string_lossy())
+                .and_then(|bytes| load_contract_from_bytes(&bytes, &self.options)),
         }
         .context("generating abi code")?;
+        let tokens = contract.generate();
 
         let file = syn::parse_file(&tokens.to_string()).context("parsing generated code")?;
 
@@ -52,12 +291,16 @@ impl<'a> Abigen<'a> {
             .collect::<Vec<_>>()
             .join("\n");
 
-        Ok(GeneratedBindings { code })
+        Ok(GeneratedBindings {
+            code,
+            proto: contract.generate_proto(),
+        })
     }
 }
 
 pub struct GeneratedBindings {
     code: String,
+    proto: Option<String>,
 }
 
 impl GeneratedBindings {
@@ -72,4 +315,88 @@ impl GeneratedBindings {
         std::fs::write(path, &self.code)
             .with_context(|| format!("writing file {}", p.as_ref().to_string_lossy()))
     }
+
+    /// Writes the `.proto` file generated when [Abigen::proto] is enabled.
+    pub fn write_proto_to_file<P: AsRef<Path>>(&self, p: P) -> Result<(), anyhow::Error> {
+        let proto = self.proto.as_ref().ok_or_else(|| {
+            format_err!("protobuf generation is not enabled, see `Abigen::proto`")
+        })?;
+        let path = normalize_path(p.as_ref()).context("normalize path")?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("creating directories for {}", parent.to_string_lossy()))?
+        }
+
+        std::fs::write(path, proto)
+            .with_context(|| format!("writing file {}", p.as_ref().to_string_lossy()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Abigen;
+
+    #[test]
+    fn test_proto_hex_addresses_in_any_order() {
+        let signatures = ["event Set(address who)"];
+
+        let bindings = Abigen::from_signatures(&signatures)
+            .unwrap()
+            .proto_hex_addresses(true)
+            .generate()
+            .unwrap();
+        assert_eq!(bindings.proto, None);
+
+        for abigen in [
+            Abigen::from_signatures(&signatures)
+                .unwrap()
+                .proto_hex_addresses(true)
+                .proto("contract.v1", "crate::pb::contract::v1"),
+            Abigen::from_signatures(&signatures)
+                .unwrap()
+                .proto("contract.v1", "crate::pb::contract::v1")
+                .proto_hex_addresses(true),
+        ] {
+            let proto = abigen.generate().unwrap().proto.unwrap();
+            assert!(proto.contains("string who = 1;"), "{}", proto);
+        }
+    }
+
+    #[test]
+    fn test_proto_event_named_like_struct() {
+        let abi = br#"[{
+            "type": "event",
+            "name": "Order",
+            "anonymous": false,
+            "inputs": [{
+                "name": "order",
+                "type": "tuple",
+                "internalType": "struct Order",
+                "indexed": false,
+                "components": [
+                    { "name": "maker", "type": "address", "internalType": "address" },
+                    { "name": "amount", "type": "uint256", "internalType": "uint256" }
+                ]
+            }]
+        }]"#;
+
+        let bindings = Abigen::from_bytes("Contract", abi)
+            .unwrap()
+            .proto("contract.v1", "crate::pb::contract::v1")
+            .generate()
+            .unwrap();
+
+        let proto = bindings.proto.unwrap();
+        assert!(proto.contains("message Order {"), "{}", proto);
+        assert!(
+            proto.contains("message OrderEvent {\n  Order order = 1;\n}"),
+            "{}",
+            proto
+        );
+        assert!(bindings
+            .code
+            .replace(' ', "")
+            .contains("crate::pb::contract::v1::OrderEvent"));
+    }
 }
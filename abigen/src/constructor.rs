@@ -9,126 +9,303 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::{
-	from_template_param, get_template_names, input_names, rust_type, template_param_type, to_ethabi_param_vec, to_token,
+use crate::{
+    natspec::{Docs, NatSpec},
+    structs::Structs,
+    to_syntax_string, DecodeContext, Options,
 };
 
+use super::{from_token, param_names, rust_type, to_token};
+
 /// Structure used to generate contract's constructor interface.
 pub struct Constructor {
-	inputs_declarations: Vec<TokenStream>,
-	inputs_definitions: Vec<TokenStream>,
-	tokenize: Vec<TokenStream>,
-	recreate_inputs: TokenStream,
+    fields: Vec<TokenStream>,
+    tokenize: Vec<TokenStream>,
+    decode_data: TokenStream,
+    decode_fields: Vec<TokenStream>,
+    attributes: TokenStream,
+    param_names: Vec<String>,
+    docs: Docs,
 }
 
 impl<'a> From<&'a ethabi::Constructor> for Constructor {
-	fn from(c: &'a ethabi::Constructor) -> Self {
-		// [param0, hello_world, param2]
-		let input_names = input_names(&c.inputs);
-
-		// [T0: Into<Uint>, T1: Into<Bytes>, T2: IntoIterator<Item = U2>, U2 = Into<Uint>]
-		let inputs_declarations =
-			c.inputs.iter().enumerate().map(|(index, param)| template_param_type(&param.kind, index)).collect();
-
-		// [Uint, Bytes, Vec<Uint>]
-		let kinds: Vec<_> = c.inputs.iter().map(|param| rust_type(&param.kind)).collect();
-
-		// [T0, T1, T2]
-		let template_names: Vec<_> = get_template_names(&kinds);
-
-		// [param0: T0, hello_world: T1, param2: T2]
-		let inputs_definitions = input_names
-			.iter()
-			.zip(template_names.iter())
-			.map(|(param_name, template_name)| quote! { #param_name: #template_name });
-
-		let inputs_definitions = Some(quote! { code: ethabi::Bytes }).into_iter().chain(inputs_definitions).collect();
-
-		// [Token::Uint(param0.into()), Token::Bytes(hello_world.into()), Token::Array(param2.into_iter().map(Into::into).collect())]
-		let tokenize: Vec<_> = input_names
-			.iter()
-			.zip(c.inputs.iter())
-			.map(|(param_name, param)| to_token(&from_template_param(&param.kind, param_name), &param.kind))
-			.collect();
-
-		Constructor {
-			inputs_declarations,
-			inputs_definitions,
-			tokenize,
-			recreate_inputs: to_ethabi_param_vec(&c.inputs),
-		}
-	}
+    fn from(c: &'a ethabi::Constructor) -> Self {
+        (c, &Structs::default(), &Options::default()).into()
+    }
+}
+
+impl<'a> From<(&'a ethabi::Constructor, &'a Structs, &'a Options)> for Constructor {
+    fn from((c, structs, options): (&'a ethabi::Constructor, &'a Structs, &'a Options)) -> Self {
+        let components = structs.constructor_inputs(c);
+
+        // [param0, hello_world, param2]
+        let input_names = param_names(&c.inputs);
+
+        let fields = input_names
+            .iter()
+            .zip(c.inputs.iter())
+            .zip(components.iter())
+            .map(|((param_name, param), components)| {
+                let kind = rust_type(&param.kind, components, options);
+                quote! { pub #param_name: #kind }
+            })
+            .collect();
+
+        let tokenize = input_names
+            .iter()
+            .zip(c.inputs.iter())
+            .zip(components.iter())
+            .map(|((param_name, param), components)| {
+                to_token(
+                    &quote! { self.#param_name },
+                    &param.kind,
+                    components,
+                    options,
+                )
+            })
+            .collect();
+
+        let decode_data = if !c.inputs.is_empty() {
+            let params: Vec<_> = c
+                .inputs
+                .iter()
+                .map(|input| to_syntax_string(&input.kind))
+                .collect();
+
+            quote! {
+                let mut values = ethabi::decode(&[#(#params),*], data)
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: "constructor".into(),
+                            param: None,
+                            reason: e.to_string(),
+                        })?;
+                values.reverse();
+            }
+        } else {
+            quote! {}
+        };
+
+        // We go reverse in the iteration because we use a series of `.pop()` to correctly
+        // extract elements and put them in the good fields.
+        let decode_fields = c
+            .inputs
+            .iter()
+            .zip(input_names.iter())
+            .zip(components.iter())
+            .map(|((param, name), components)| {
+                let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
+                let decode_input = from_token(
+                    &param.kind,
+                    &data_access,
+                    components,
+                    options,
+                    DecodeContext::new("constructor", &name.to_string()),
+                );
+                quote! {
+                   #name: #decode_input
+                }
+            })
+            .collect();
+
+        Constructor {
+            fields,
+            tokenize,
+            decode_data,
+            decode_fields,
+            attributes: options.struct_attributes(None),
+            param_names: c.inputs.iter().map(|param| param.name.clone()).collect(),
+            docs: Docs::default(),
+        }
+    }
 }
 
 impl Constructor {
-	/// Generates contract constructor interface.
-	pub fn generate(&self) -> TokenStream {
-		let declarations = &self.inputs_declarations;
-		let definitions = &self.inputs_definitions;
-		let tokenize = &self.tokenize;
-		let recreate_inputs = &self.recreate_inputs;
-
-		quote! {
-			/// Encodes a call to contract's constructor.
-			pub fn constructor<#(#declarations),*>(#(#definitions),*) -> ethabi::Bytes {
-				let c = ethabi::Constructor {
-					inputs: #recreate_inputs,
-				};
-				let tokens = vec![#(#tokenize),*];
-				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
-			}
-		}
-	}
+    /// Attaches the NatSpec documentation of the constructor found in the artifact.
+    pub(crate) fn document(&mut self, natspec: &NatSpec) {
+        self.docs = natspec.constructor();
+    }
+
+    /// Generates contract constructor interface.
+    pub fn generate(&self) -> TokenStream {
+        let fields = &self.fields;
+        let tokenize = &self.tokenize;
+        let decode_data = &self.decode_data;
+        let decode_fields = &self.decode_fields;
+        let attributes = &self.attributes;
+
+        let docs = self.docs.item(
+            "Contract's constructor arguments, decoded from the input of the contract\n\
+             creation call which is the init bytecode followed by the ABI encoded arguments.",
+        );
+        let field_docs: Vec<_> = self
+            .param_names
+            .iter()
+            .map(|name| self.docs.param(name))
+            .collect();
+
+        quote! {
+            #docs
+            #attributes
+            pub struct Constructor {
+                #(#field_docs #fields),*
+            }
+
+            impl Constructor {
+                /// Returns `true` if the call is a contract creation call.
+                pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                    call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+                }
+
+                /// Decodes the constructor arguments of the contract creation `call` by removing
+                /// the known init `bytecode` prefix from the call's input.
+                pub fn decode_with_bytecode(
+                    call: &substreams_ethereum::pb::eth::v2::Call,
+                    bytecode: &[u8],
+                ) -> Result<Self, substreams_ethereum::DecodeError> {
+                    match call.input.strip_prefix(bytecode) {
+                        Some(data) => Self::decode_data(data),
+                        None => Err(substreams_ethereum::DecodeError::BytecodeMismatch {
+                            item: "constructor".into(),
+                        }),
+                    }
+                }
+
+                /// Decodes the constructor arguments of the contract creation `call` by reading
+                /// the ABI encoded tail found after the first `code_len` bytes of creation code.
+                pub fn decode_with_code_len(
+                    call: &substreams_ethereum::pb::eth::v2::Call,
+                    code_len: usize,
+                ) -> Result<Self, substreams_ethereum::DecodeError> {
+                    match call.input.get(code_len..) {
+                        Some(data) => Self::decode_data(data),
+                        None => Err(substreams_ethereum::DecodeError::DataSize {
+                            item: "constructor".into(),
+                            expected: code_len,
+                            actual: call.input.len(),
+                        }),
+                    }
+                }
+
+                /// Decodes the ABI encoded constructor arguments, `data` must not contain
+                /// the init bytecode.
+                pub fn decode_data(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                    #decode_data
+
+                    Ok(Self {
+                        #(#decode_fields),*
+                    })
+                }
+
+                /// Encodes the contract creation input, which is the init `bytecode` followed
+                /// by the ABI encoded constructor arguments.
+                pub fn encode(&self, bytecode: &[u8]) -> Vec<u8> {
+                    let data = ethabi::encode(&[#(#tokenize),*]);
+
+                    let mut encoded = Vec::with_capacity(bytecode.len() + data.len());
+                    encoded.extend_from_slice(bytecode);
+                    encoded.extend(data);
+
+                    encoded
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-	use super::Constructor;
-	use quote::quote;
-
-	#[test]
-	fn test_no_params() {
-		let ethabi_constructor = ethabi::Constructor { inputs: vec![] };
-
-		let c = Constructor::from(&ethabi_constructor);
-
-		let expected = quote! {
-			/// Encodes a call to contract's constructor.
-			pub fn constructor<>(code: ethabi::Bytes) -> ethabi::Bytes {
-				let c = ethabi::Constructor {
-					inputs: vec![],
-				};
-				let tokens = vec![];
-				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
-			}
-		};
-
-		assert_eq!(expected.to_string(), c.generate().to_string());
-	}
-
-	#[test]
-	fn test_one_param() {
-		let ethabi_constructor = ethabi::Constructor {
-			inputs: vec![ethabi::Param { name: "foo".into(), kind: ethabi::ParamType::Uint(256), internal_type: None }],
-		};
-
-		let c = Constructor::from(&ethabi_constructor);
-
-		let expected = quote! {
-			/// Encodes a call to contract's constructor.
-			pub fn constructor<T0: Into<ethabi::Uint> >(code: ethabi::Bytes, foo: T0) -> ethabi::Bytes {
-				let c = ethabi::Constructor {
-					inputs: vec![ethabi::Param {
-						name: "foo".to_owned(),
-						kind: ethabi::ParamType::Uint(256usize),
-						internal_type: None
-					}],
-				};
-				let tokens = vec![ethabi::Token::Uint(foo.into())];
-				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
-			}
-		};
-
-		assert_eq!(expected.to_string(), c.generate().to_string());
-	}
+    use crate::assertions::assert_ast_eq;
+
+    use super::Constructor;
+    use quote::quote;
+
+    #[test]
+    fn test_one_param() {
+        let ethabi_constructor = ethabi::Constructor {
+            inputs: vec![ethabi::Param {
+                name: "owner".into(),
+                kind: ethabi::ParamType::Address,
+                internal_type: None,
+            }],
+        };
+
+        let c = Constructor::from(&ethabi_constructor);
+
+        assert_ast_eq(
+            c.generate(),
+            quote! {
+                #[doc = " Contract's constructor arguments, decoded from the input of the contract"]
+                #[doc = " creation call which is the init bytecode followed by the ABI encoded arguments."]
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct Constructor {
+                    pub owner: Vec<u8>
+                }
+                impl Constructor {
+                    /// Returns `true` if the call is a contract creation call.
+                    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                        call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32
+                    }
+                    /// Decodes the constructor arguments of the contract creation `call` by removing
+                    /// the known init `bytecode` prefix from the call's input.
+                    pub fn decode_with_bytecode(
+                        call: &substreams_ethereum::pb::eth::v2::Call,
+                        bytecode: &[u8],
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        match call.input.strip_prefix(bytecode) {
+                            Some(data) => Self::decode_data(data),
+                            None => Err(substreams_ethereum::DecodeError::BytecodeMismatch {
+                                item: "constructor".into(),
+                            }),
+                        }
+                    }
+                    /// Decodes the constructor arguments of the contract creation `call` by reading
+                    /// the ABI encoded tail found after the first `code_len` bytes of creation code.
+                    pub fn decode_with_code_len(
+                        call: &substreams_ethereum::pb::eth::v2::Call,
+                        code_len: usize,
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        match call.input.get(code_len..) {
+                            Some(data) => Self::decode_data(data),
+                            None => Err(substreams_ethereum::DecodeError::DataSize {
+                                item: "constructor".into(),
+                                expected: code_len,
+                                actual: call.input.len(),
+                            }),
+                        }
+                    }
+                    /// Decodes the ABI encoded constructor arguments, `data` must not contain
+                    /// the init bytecode.
+                    pub fn decode_data(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                        let mut values = ethabi::decode(&[ethabi::ParamType::Address], data)
+                            .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                item: "constructor".into(),
+                                param: None,
+                                reason: e.to_string(),
+                            })?;
+                        values.reverse();
+                        Ok(Self {
+                            owner: values
+                                .pop()
+                                .expect(INTERNAL_ERR)
+                                .into_address()
+                                .expect(INTERNAL_ERR)
+                                .as_bytes()
+                                .to_vec()
+                        })
+                    }
+                    /// Encodes the contract creation input, which is the init `bytecode` followed
+                    /// by the ABI encoded constructor arguments.
+                    pub fn encode(&self, bytecode: &[u8]) -> Vec<u8> {
+                        let data = ethabi::encode(&[ethabi::Token::Address(
+                            ethabi::Address::from_slice(&self.owner)
+                        )]);
+                        let mut encoded = Vec::with_capacity(bytecode.len() + data.len());
+                        encoded.extend_from_slice(bytecode);
+                        encoded.extend(data);
+                        encoded
+                    }
+                }
+            },
+        );
+    }
 }
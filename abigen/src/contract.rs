@@ -8,34 +8,60 @@
 
 use std::collections::BTreeMap;
 
+use ethabi::ParamType;
 use heck::ToUpperCamelCase;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
-// use crate::{constructor::Constructor,};
-use crate::{event::Event, function::Function};
+use crate::{
+    artifact::Artifact, canonical_signature, constructor::Constructor, error::Error, event::Event,
+    fallback::Fallback, function::Function, natspec::NatSpec, proto, structs::Structs, Options,
+};
 
 /// Structure used to generate rust interface for solidity contract.
 pub struct Contract {
-    // constructor: Option<Constructor>,
+    constructor: Option<Constructor>,
     functions: Vec<Function>,
+    fallback: Fallback,
     events: Vec<Event>,
+    errors: Vec<Error>,
+    structs: TokenStream,
+    bytecode: Option<Vec<u8>>,
+    deployed_bytecode: Option<Vec<u8>>,
+    imports: Vec<TokenStream>,
+    /// Package and struct messages of the `.proto` file, if enabled.
+    proto: Option<(String, Vec<String>)>,
 }
 
 impl<'a> From<&'a ethabi::Contract> for Contract {
     fn from(c: &'a ethabi::Contract) -> Self {
+        (c, &Structs::default(), &Options::default()).into()
+    }
+}
+
+impl<'a> From<(&'a ethabi::Contract, &'a Structs, &'a Options)> for Contract {
+    fn from((c, structs, options): (&'a ethabi::Contract, &'a Structs, &'a Options)) -> Self {
+        // The fallback is built from all the functions, calls to the ones left out by the
+        // filters don't reach it
+        let fallback = (c, options).into();
+        let c = &options.filter_contract(c);
+
         let mut events: Vec<_> = c
             .events
             .values()
             .flat_map(|events| {
-                let count = events.len();
+                let overloads: Vec<Vec<_>> = events
+                    .iter()
+                    .map(|event| event.inputs.iter().map(|param| &param.kind).collect())
+                    .collect();
+                let names = overload_names(&events[0].name, &overloads, options);
 
-                events.iter().enumerate().map(move |(index, event)| {
-                    if count <= 1 {
-                        (&event.name, event).into()
-                    } else {
-                        (&format!("{}{}", event.name, index + 1), event).into()
-                    }
+                events.iter().zip(names).map(move |(event, name)| {
+                    let signature =
+                        canonical_signature(&event.name, event.inputs.iter().map(|p| &p.kind));
+                    let name = options.event_rename(&signature).cloned().unwrap_or(name);
+
+                    (&name, event, structs, options).into()
                 })
             })
             .collect();
@@ -59,33 +85,189 @@ impl<'a> From<&'a ethabi::Contract> for Contract {
         let mut functions: Vec<_> = function_by_rust_struct_name
             .iter()
             .flat_map(|(sanitized_name, functions)| {
-                let count = functions.len();
+                let overloads: Vec<Vec<_>> = functions
+                    .iter()
+                    .map(|function| function.inputs.iter().map(|param| &param.kind).collect())
+                    .collect();
+                let names = overload_names(sanitized_name, &overloads, options);
+
+                functions.iter().zip(names).map(move |(function, name)| {
+                    let signature = canonical_signature(
+                        &function.name,
+                        function.inputs.iter().map(|p| &p.kind),
+                    );
+                    let name = options.function_rename(&signature).cloned().unwrap_or(name);
+
+                    (name, *function, structs, options).into()
+                })
+            })
+            .collect();
+
+        // Since some people will actually commit this code, we use a "stable" generation order
+        functions.sort_by(|left: &Function, right: &Function| left.name.cmp(&right.name));
 
-                functions.iter().enumerate().map(move |(index, function)| {
+        let mut errors: Vec<_> = c
+            .errors
+            .values()
+            .flat_map(|errors| {
+                let count = errors.len();
+
+                errors.iter().enumerate().map(move |(index, error)| {
                     if count <= 1 {
-                        (sanitized_name.clone(), *function).into()
+                        (&error.name, error, structs, options).into()
                     } else {
-                        (format!("{}{}", sanitized_name, index + 1), *function).into()
+                        (
+                            &format!("{}{}", error.name, index + 1),
+                            error,
+                            structs,
+                            options,
+                        )
+                            .into()
                     }
                 })
             })
             .collect();
 
         // Since some people will actually commit this code, we use a "stable" generation order
-        functions.sort_by(|left: &Function, right: &Function| left.name.cmp(&right.name));
+        errors.sort_by(|left: &Error, right: &Error| left.name.cmp(&right.name));
 
         Contract {
-            // constructor: c.constructor.as_ref().map(Into::into),
+            constructor: c
+                .constructor
+                .as_ref()
+                .map(|constructor| (constructor, structs, options).into()),
             functions,
+            fallback,
             events,
+            errors,
+            structs: match structs.is_empty() {
+                true => quote! {},
+                false => structs.generate(options),
+            },
+            bytecode: None,
+            deployed_bytecode: None,
+            imports: options.imports(),
+            proto: options.proto.as_ref().map(|proto| {
+                let messages = structs.proto_messages(options);
+
+                (
+                    proto.package.clone(),
+                    messages
+                        .into_iter()
+                        .map(|message| message.definition)
+                        .collect(),
+                )
+            }),
+        }
+    }
+}
+
+/// Returns the Rust names of the `overloads` of `name` given their params types, suffixed
+/// by the params types when [Options::typed_overload_names] is enabled or by their index.
+fn overload_names(name: &str, overloads: &[Vec<&ParamType>], options: &Options) -> Vec<String> {
+    if overloads.len() <= 1 {
+        return vec![name.to_string()];
+    }
+
+    if !options.typed_overload_names {
+        return (1..=overloads.len())
+            .map(|index| format!("{}{}", name, index))
+            .collect();
+    }
+
+    let names: Vec<_> = overloads
+        .iter()
+        .map(|kinds| {
+            let suffix: String = kinds.iter().map(|kind| param_type_name(kind)).collect();
+            format!("{}{}", name, suffix)
+        })
+        .collect();
+
+    // Functions whose names only differ by their casing can have the same params types
+    names
+        .iter()
+        .enumerate()
+        .map(
+            |(index, candidate)| match names.iter().filter(|other| *other == candidate).count() {
+                1 => candidate.clone(),
+                _ => format!("{}{}", candidate, index + 1),
+            },
+        )
+        .collect()
+}
+
+/// Returns the name of a param type used in overload names, e.g. `Uint256` or `AddressArray`.
+fn param_type_name(kind: &ParamType) -> String {
+    match kind {
+        ParamType::Address => "Address".to_string(),
+        ParamType::Bytes => "Bytes".to_string(),
+        ParamType::Int(size) => format!("Int{}", size),
+        ParamType::Uint(size) => format!("Uint{}", size),
+        ParamType::Bool => "Bool".to_string(),
+        ParamType::String => "String".to_string(),
+        ParamType::FixedBytes(size) => format!("Bytes{}", size),
+        ParamType::Array(inner) => format!("{}Array", param_type_name(inner)),
+        ParamType::FixedArray(inner, size) => format!("{}Array{}", param_type_name(inner), size),
+        ParamType::Tuple(inner) => {
+            let inner: String = inner.iter().map(param_type_name).collect();
+            format!("Tuple{}", inner)
         }
     }
 }
 
 impl Contract {
+    /// Attaches the bytecode and the NatSpec documentation found in the compilation `artifact`
+    /// the contract was loaded from.
+    pub fn with_artifact(mut self, artifact: &Artifact) -> Self {
+        self.bytecode = artifact.bytecode.clone();
+        self.deployed_bytecode = artifact.deployed_bytecode.clone();
+
+        let natspec = NatSpec::from(artifact);
+        if let Some(constructor) = self.constructor.as_mut() {
+            constructor.document(&natspec);
+        }
+        self.events
+            .iter_mut()
+            .for_each(|event| event.document(&natspec));
+        self.functions
+            .iter_mut()
+            .for_each(|function| function.document(&natspec));
+        self.errors
+            .iter_mut()
+            .for_each(|error| error.document(&natspec));
+        self
+    }
+
+    /// Generates the `.proto` file with the messages of the contract's structs, events and
+    /// functions, `None` if protobuf generation is disabled.
+    pub fn generate_proto(&self) -> Option<String> {
+        let (package, struct_messages) = self.proto.as_ref()?;
+
+        let messages: Vec<&str> = struct_messages
+            .iter()
+            .map(String::as_str)
+            .chain(self.events.iter().filter_map(Event::proto_definition))
+            .chain(self.functions.iter().filter_map(Function::proto_definition))
+            .collect();
+
+        Some(proto::generate_file(package, &messages))
+    }
+
     /// Generates rust interface for a contract.
     pub fn generate(&self) -> TokenStream {
-        // let constructor = self.constructor.as_ref().map(Constructor::generate);
+        // The constructor lives at the root of the contract's module, which isn't covered by
+        // the `unused_imports` allowance of the other modules.
+        let constructor = self.constructor.as_ref().map(|constructor| {
+            let imports = &self.imports;
+            let constructor = constructor.generate();
+
+            quote! {
+                #(#[allow(unused_imports)] use #imports;)*
+                #constructor
+            }
+        });
+        let imports = self.imports.iter().map(|import| quote! { use #import; });
+        let imports = quote! { #(#imports)* };
         let functions: Vec<_> = self.functions.iter().map(Function::generate).collect();
         let events: Vec<_> = self
             .events
@@ -93,35 +275,184 @@ impl Contract {
             .map(|event| event.generate_event())
             .collect();
         // let logs: Vec<_> = self.events.iter().map(Event::generate_log).collect();
+        let fallback = self.fallback.generate();
+        let functions_enum = Function::generate_enum(&self.functions);
+        let contract_handle = Function::generate_contract_handle(&self.functions);
+        let events_enum = Event::generate_enum(&self.events);
+
+        // Like `errors` below, the `structs` module is only rendered when the ABI declares
+        // Solidity structs, the other modules then refer to them through `structs::Name`.
+        let (structs, use_structs) = match self.structs.is_empty() {
+            true => (quote! {}, quote! {}),
+            false => {
+                let structs = &self.structs;
+
+                (
+                    quote! {
+                        /// Contract's structs.
+                        #[allow(dead_code, unused_imports, unused_variables)]
+                        pub mod structs {
+                            use super::structs;
+                            #imports
+                            #structs
+                        }
+                    },
+                    quote! { use super::structs; },
+                )
+            }
+        };
+
+        // The `errors` module is only rendered when the ABI declares custom errors so that
+        // bindings of contracts without any stay the same.
+        let errors = match self.errors.is_empty() {
+            true => quote! {},
+            false => {
+                let errors: Vec<_> = self.errors.iter().map(Error::generate).collect();
+                let errors_enum = Error::generate_enum(&self.errors);
+
+                quote! {
+                    /// Contract's custom errors.
+                    #[allow(dead_code, unused_imports, unused_variables)]
+                    pub mod errors {
+                        use super::INTERNAL_ERR;
+                        #use_structs
+                        #imports
+                        #(#errors)*
+
+                        #errors_enum
+                    }
+                }
+            }
+        };
+
+        // Bytecode constants are only rendered when the ABI was loaded from a compilation
+        // artifact providing them, the constructor can then be decoded without passing it.
+        let bytecode = match &self.bytecode {
+            None => quote! {},
+            Some(bytecode) => {
+                let bytecode = Literal::byte_string(bytecode);
+                let decode_constructor = match self.constructor {
+                    None => quote! {},
+                    Some(_) => quote! {
+                        impl Constructor {
+                            /// Decodes the constructor arguments of the contract creation `call`
+                            /// by removing the contract's [BYTECODE] prefix from the call's input.
+                            pub fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, substreams_ethereum::DecodeError> {
+                                Self::decode_with_bytecode(call, BYTECODE)
+                            }
+                        }
+                    },
+                };
+
+                quote! {
+                    /// Contract's init bytecode, as found in the compilation artifact.
+                    pub const BYTECODE: &[u8] = #bytecode;
+
+                    #decode_constructor
+                }
+            }
+        };
+
+        let deployed_bytecode = match &self.deployed_bytecode {
+            None => quote! {},
+            Some(deployed_bytecode) => {
+                let deployed_bytecode = Literal::byte_string(deployed_bytecode);
+
+                quote! {
+                    /// Contract's runtime bytecode, as found in the compilation artifact.
+                    pub const DEPLOYED_BYTECODE: &[u8] = #deployed_bytecode;
+                }
+            }
+        };
+
         quote! {
             const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
 
-            // #constructor
+            #structs
+
+            #constructor
+
+            #bytecode
+
+            #deployed_bytecode
+
+            #contract_handle
 
             /// Contract's functions.
             #[allow(dead_code, unused_imports, unused_variables)]
             pub mod functions {
                 use super::INTERNAL_ERR;
+                #use_structs
+                #imports
                 #(#functions)*
+
+                #fallback
+
+                #functions_enum
             }
 
             /// Contract's events.
             #[allow(dead_code, unused_imports, unused_variables)]
             pub mod events {
                 use super::INTERNAL_ERR;
+                #use_structs
+                #imports
                 #(#events)*
+
+                #events_enum
             }
+
+            #errors
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use ethabi::ParamType;
     use quote::quote;
 
-    use crate::assertions::assert_ast_eq;
+    use crate::{assertions::assert_ast_eq, Options};
+
+    use super::{overload_names, Contract};
+
+    #[test]
+    fn test_overload_names() {
+        let tuple = ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]);
+        let array = ParamType::FixedArray(Box::new(tuple), 2);
+        let overloads = vec![
+            vec![&ParamType::Address, &ParamType::Uint(256)],
+            vec![&ParamType::Bytes, &array],
+        ];
+        let typed = Options {
+            typed_overload_names: true,
+            ..Default::default()
+        };
 
-    use super::Contract;
+        assert_eq!(
+            overload_names("Transfer", &overloads[..1], &typed),
+            vec!["Transfer"]
+        );
+        assert_eq!(
+            overload_names("Transfer", &overloads, &Options::default()),
+            vec!["Transfer1", "Transfer2"]
+        );
+        assert_eq!(
+            overload_names("Transfer", &overloads, &typed),
+            vec![
+                "TransferAddressUint256",
+                "TransferBytesTupleAddressUint256Array2"
+            ]
+        );
+        assert_eq!(
+            overload_names(
+                "Transfer",
+                &[overloads[0].clone(), overloads[0].clone()],
+                &typed
+            ),
+            vec!["TransferAddressUint2561", "TransferAddressUint2562"]
+        );
+    }
 
     #[test]
     fn test_no_body() {
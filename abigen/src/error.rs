@@ -0,0 +1,450 @@
+use std::collections::HashSet;
+
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::{
+    canonical_signature, from_token,
+    natspec::{Docs, NatSpec},
+    param_names, rust_type,
+    structs::Structs,
+    to_syntax_string, to_token, DecodeContext, Options,
+};
+
+/// Structure used to generate contract's custom error interface.
+pub struct Error {
+    /// Name of the error, de-duped and sanitized for Rust
+    pub(crate) name: String,
+    /// Original name of the error as defined in the ABI
+    original_name: String,
+    /// Canonical signature, e.g. `InsufficientBalance(uint256,uint256)`
+    signature: String,
+    selector: [u8; 4],
+    fields: Vec<TokenStream>,
+    tokenize: Vec<TokenStream>,
+    decode_data: TokenStream,
+    decode_fields: Vec<TokenStream>,
+    attributes: TokenStream,
+    /// Names of the params as defined in the ABI, used to look up their documentation
+    param_names: Vec<String>,
+    /// NatSpec documentation, only available when loaded from a compilation artifact
+    docs: Docs,
+}
+
+impl<'a> From<(&'a String, &'a ethabi::AbiError)> for Error {
+    fn from((name, e): (&'a String, &'a ethabi::AbiError)) -> Self {
+        (name, e, &Structs::default(), &Options::default()).into()
+    }
+}
+
+impl<'a> From<(&'a String, &'a ethabi::AbiError, &'a Structs, &'a Options)> for Error {
+    fn from(
+        (name, e, structs, options): (&'a String, &'a ethabi::AbiError, &'a Structs, &'a Options),
+    ) -> Self {
+        let components = structs.error_inputs(e);
+        let names = param_names(&e.inputs);
+
+        let fields = names
+            .iter()
+            .zip(e.inputs.iter())
+            .zip(components.iter())
+            .map(|((param_name, param), components)| {
+                let kind = rust_type(&param.kind, components, options);
+                quote! { pub #param_name: #kind }
+            })
+            .collect();
+
+        let tokenize = names
+            .iter()
+            .zip(e.inputs.iter())
+            .zip(components.iter())
+            .map(|((param_name, param), components)| {
+                to_token(
+                    &quote! { self.#param_name },
+                    &param.kind,
+                    components,
+                    options,
+                )
+            })
+            .collect();
+
+        let error_name = &e.name;
+        let decode_data = if !e.inputs.is_empty() {
+            let params: Vec<_> = e
+                .inputs
+                .iter()
+                .map(|input| to_syntax_string(&input.kind))
+                .collect();
+
+            quote! {
+                let maybe_data = data.get(4..);
+                if maybe_data.is_none() {
+                    return Err(substreams_ethereum::DecodeError::DataSize {
+                        item: #error_name.into(),
+                        expected: 4,
+                        actual: data.len(),
+                    });
+                }
+
+                let mut values = ethabi::decode(&[#(#params),*], maybe_data.unwrap())
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: #error_name.into(),
+                            param: None,
+                            reason: e.to_string(),
+                        })?;
+                values.reverse();
+            }
+        } else {
+            quote! {}
+        };
+
+        // We go reverse in the iteration because we use a series of `.pop()` to correctly
+        // extract elements and put them in the good fields.
+        let decode_fields = e
+            .inputs
+            .iter()
+            .zip(names.iter())
+            .zip(components.iter())
+            .map(|((param, name), components)| {
+                let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
+                let decode_input = from_token(
+                    &param.kind,
+                    &data_access,
+                    components,
+                    options,
+                    DecodeContext::new(&e.name, &name.to_string()),
+                );
+                quote! {
+                   #name: #decode_input
+                }
+            })
+            .collect();
+
+        let param_types: Vec<_> = e.inputs.iter().map(|param| param.kind.clone()).collect();
+
+        Error {
+            name: name.clone(),
+            original_name: e.name.clone(),
+            signature: canonical_signature(&e.name, &param_types),
+            selector: ethabi::short_signature(&e.name, &param_types),
+            fields,
+            tokenize,
+            decode_data,
+            decode_fields,
+            attributes: options.struct_attributes(None),
+            param_names: e.inputs.iter().map(|param| param.name.clone()).collect(),
+            docs: Docs::default(),
+        }
+    }
+}
+
+impl Error {
+    /// Attaches the error's documentation found in the `natspec` of the compilation artifact.
+    pub(crate) fn document(&mut self, natspec: &NatSpec) {
+        self.docs = natspec.error(&self.signature);
+    }
+
+    /// Generates rust interface for contract's custom error.
+    pub fn generate(&self) -> TokenStream {
+        let original_name = &self.original_name;
+        let camel_name = self.camel_name();
+        let selector_bytes: Vec<_> = self
+            .selector
+            .iter()
+            .map(|value| quote! { #value })
+            .collect();
+
+        let fields = &self.fields;
+        let tokenize = &self.tokenize;
+        let decode_data = &self.decode_data;
+        let decode_fields = &self.decode_fields;
+        let attributes = &self.attributes;
+
+        let docs = self.docs.item(&format!(
+            "Error `{}`, selector `0x{}`.",
+            self.signature,
+            hex::encode(self.selector)
+        ));
+        let field_docs: Vec<_> = self
+            .param_names
+            .iter()
+            .map(|name| self.docs.param(name))
+            .collect();
+
+        quote! {
+            #docs
+            #attributes
+            pub struct #camel_name {
+                #(#field_docs #fields),*
+            }
+
+            impl #camel_name {
+                pub const NAME: &'static str = #original_name;
+                pub const SELECTOR: [u8; 4] = [#(#selector_bytes),*];
+
+                /// Returns `true` if the revert `data` starts with this error's selector.
+                pub fn match_revert(data: &[u8]) -> bool {
+                    match data.get(0..4) {
+                        Some(selector) => Self::SELECTOR == selector,
+                        None => false
+                    }
+                }
+
+                pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                    #decode_data
+
+                    Ok(Self {
+                        #(#decode_fields),*
+                    })
+                }
+
+                pub fn encode(&self) -> Vec<u8> {
+                    let data = ethabi::encode(&[#(#tokenize),*]);
+
+                    let mut encoded = Vec::with_capacity(4 + data.len());
+                    encoded.extend(Self::SELECTOR);
+                    encoded.extend(data);
+
+                    encoded
+                }
+            }
+        }
+    }
+
+    /// Generates the contract-level `Errors` enum dispatching on the selector of
+    /// a revert payload.
+    pub fn generate_enum(errors: &[Error]) -> TokenStream {
+        let variants: Vec<_> = errors
+            .iter()
+            .map(|error| {
+                let camel_name = error.camel_name();
+                quote! { #camel_name(#camel_name) }
+            })
+            .collect();
+
+        // ABIs can list the same error more than once, only the first one is decoded
+        let mut seen_selectors = HashSet::new();
+        let decode_arms: Vec<_> = errors
+            .iter()
+            .filter(|error| seen_selectors.insert(error.selector))
+            .map(|error| {
+                let camel_name = error.camel_name();
+                quote! {
+                    #camel_name::SELECTOR => Some(#camel_name::decode(data).map(Errors::#camel_name))
+                }
+            })
+            .collect();
+
+        quote! {
+            /// All custom errors of the contract, use [Errors::decode] to decode any revert
+            /// payload into the matching error.
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum Errors {
+                #(#variants),*
+            }
+
+            impl Errors {
+                /// Decodes the revert `data` into the custom error whose selector matches the first
+                /// 4 bytes. Returns `None` if no custom error of the contract matches.
+                pub fn decode(data: &[u8]) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+                    let selector: [u8; 4] = data.get(0..4)?.try_into().expect(INTERNAL_ERR);
+
+                    match selector {
+                        #(#decode_arms,)*
+                        _ => None,
+                    }
+                }
+
+                /// Decodes the `return_data` of a reverted call, returns `None` if the call
+                /// did not revert or if no custom error of the contract matches.
+                pub fn decode_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+                    if !call.status_reverted {
+                        return None;
+                    }
+
+                    Self::decode(call.return_data.as_ref())
+                }
+            }
+        }
+    }
+
+    fn camel_name(&self) -> syn::Ident {
+        syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::assert_ast_eq;
+
+    use super::Error;
+    use quote::quote;
+
+    #[test]
+    fn test_error_insufficient_balance() {
+        let ethabi_error = ethabi::AbiError {
+            name: "InsufficientBalance".into(),
+            inputs: vec![
+                ethabi::Param {
+                    name: "available".into(),
+                    kind: ethabi::ParamType::Uint(256),
+                    internal_type: None,
+                },
+                ethabi::Param {
+                    name: "required".into(),
+                    kind: ethabi::ParamType::Uint(256),
+                    internal_type: None,
+                },
+            ],
+        };
+
+        let e = Error::from((&ethabi_error.name, &ethabi_error));
+
+        assert_ast_eq(
+            e.generate(),
+            quote! {
+                #[doc = " Error `InsufficientBalance(uint256,uint256)`, selector `0xcf479181`."]
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct InsufficientBalance {
+                    pub available: substreams::scalar::BigInt,
+                    pub required: substreams::scalar::BigInt
+                }
+                impl InsufficientBalance {
+                    pub const NAME: &'static str = "InsufficientBalance";
+                    pub const SELECTOR: [u8; 4] = [207u8, 71u8, 145u8, 129u8];
+                    /// Returns `true` if the revert `data` starts with this error's selector.
+                    pub fn match_revert(data: &[u8]) -> bool {
+                        match data.get(0..4) {
+                            Some(selector) => Self::SELECTOR == selector,
+                            None => false
+                        }
+                    }
+                    pub fn decode(data: &[u8]) -> Result<Self, substreams_ethereum::DecodeError> {
+                        let maybe_data = data.get(4..);
+                        if maybe_data.is_none() {
+                            return Err(substreams_ethereum::DecodeError::DataSize {
+                                item: "InsufficientBalance".into(),
+                                expected: 4,
+                                actual: data.len(),
+                            });
+                        }
+                        let mut values = ethabi::decode(
+                                &[
+                                    ethabi::ParamType::Uint(256usize),
+                                    ethabi::ParamType::Uint(256usize)
+                                ],
+                                maybe_data.unwrap()
+                            )
+                            .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                item: "InsufficientBalance".into(),
+                                param: None,
+                                reason: e.to_string(),
+                            })?;
+                        values.reverse();
+                        Ok(Self {
+                            available: {
+                                let mut v = [0 as u8; 32];
+                                values
+                                    .pop()
+                                    .expect(INTERNAL_ERR)
+                                    .into_uint()
+                                    .expect(INTERNAL_ERR)
+                                    .to_big_endian(v.as_mut_slice());
+                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                            },
+                            required: {
+                                let mut v = [0 as u8; 32];
+                                values
+                                    .pop()
+                                    .expect(INTERNAL_ERR)
+                                    .into_uint()
+                                    .expect(INTERNAL_ERR)
+                                    .to_big_endian(v.as_mut_slice());
+                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                            }
+                        })
+                    }
+                    pub fn encode(&self) -> Vec<u8> {
+                        let data = ethabi::encode(&[
+                            ethabi::Token::Uint(ethabi::Uint::from_big_endian(
+                                match self.available.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    },
+                                }
+                                .as_slice(),
+                            ),),
+                            ethabi::Token::Uint(ethabi::Uint::from_big_endian(
+                                match self.required.clone().to_bytes_be() {
+                                    (num_bigint::Sign::Plus, bytes) => bytes,
+                                    (num_bigint::Sign::NoSign, bytes) => bytes,
+                                    (num_bigint::Sign::Minus, _) => {
+                                        panic!("negative numbers are not supported")
+                                    },
+                                }
+                                .as_slice(),
+                            ),)
+                        ]);
+                        let mut encoded = Vec::with_capacity(4 + data.len());
+                        encoded.extend(Self::SELECTOR);
+                        encoded.extend(data);
+                        encoded
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_error_enum_dedupes_selectors() {
+        let ethabi_error = ethabi::AbiError {
+            name: "Unauthorized".into(),
+            inputs: vec![],
+        };
+
+        // The same error listed twice by the ABI, named apart by the contract
+        let errors = vec![
+            Error::from((&"Unauthorized1".to_string(), &ethabi_error)),
+            Error::from((&"Unauthorized2".to_string(), &ethabi_error)),
+        ];
+
+        assert_ast_eq(
+            Error::generate_enum(&errors),
+            quote! {
+                /// All custom errors of the contract, use [Errors::decode] to decode any revert
+                /// payload into the matching error.
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum Errors {
+                    Unauthorized1(Unauthorized1),
+                    Unauthorized2(Unauthorized2)
+                }
+
+                impl Errors {
+                    /// Decodes the revert `data` into the custom error whose selector matches the first
+                    /// 4 bytes. Returns `None` if no custom error of the contract matches.
+                    pub fn decode(data: &[u8]) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+                        let selector: [u8; 4] = data.get(0..4)?.try_into().expect(INTERNAL_ERR);
+
+                        match selector {
+                            Unauthorized1::SELECTOR => Some(Unauthorized1::decode(data).map(Errors::Unauthorized1)),
+                            _ => None,
+                        }
+                    }
+
+                    /// Decodes the `return_data` of a reverted call, returns `None` if the call
+                    /// did not revert or if no custom error of the contract matches.
+                    pub fn decode_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+                        if !call.status_reverted {
+                            return None;
+                        }
+
+                        Self::decode(call.return_data.as_ref())
+                    }
+                }
+            },
+        );
+    }
+}
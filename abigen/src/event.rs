@@ -1,10 +1,18 @@
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use substreams_ethereum_core::abi::LogShape;
 
-use crate::{decode_topic, fixed_data_size, min_data_size, rust_type_indexed};
+use crate::{
+    canonical_signature, decode_topic,
+    natspec::{Docs, NatSpec},
+    proto::{Field, Message},
+    rust_type_indexed,
+    structs::Structs,
+    DecodeContext, Options,
+};
 
-use super::{from_token, rust_type, to_syntax_string};
+use super::{from_token, rust_type, to_syntax_string, to_token};
 
 /// Structure used to generate contract's event interface.
 pub struct Event {
@@ -12,7 +20,16 @@ pub struct Event {
     pub(crate) name: String,
     /// Original name of the event as defined in the ABI
     original_name: String,
+    /// Anonymous events have no signature topic, their indexed params start at topic 0
+    anonymous: bool,
+    /// Canonical signature, e.g. `Transfer(address,address,uint256)`
+    signature: String,
     topic_hash: [u8; 32],
+    input_types: Vec<TokenStream>,
+    /// Names of the params as defined in the ABI, used to look up their documentation
+    param_names: Vec<String>,
+    /// NatSpec documentation, only available when loaded from a compilation artifact
+    docs: Docs,
     topic_count: usize,
     min_data_size: usize,
     fixed_data_size: Option<usize>,
@@ -20,10 +37,24 @@ pub struct Event {
     decode_indexed_fields: Vec<TokenStream>,
     decode_unindexed_fields: Vec<TokenStream>,
     decode_data: TokenStream,
+    encode_topics: Vec<TokenStream>,
+    encode_data: Vec<TokenStream>,
+    attributes: TokenStream,
+    proto: Option<Message>,
 }
 
 impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
     fn from((name, e): (&'a String, &'a ethabi::Event)) -> Self {
+        (name, e, &Structs::default(), &Options::default()).into()
+    }
+}
+
+impl<'a> From<(&'a String, &'a ethabi::Event, &'a Structs, &'a Options)> for Event {
+    fn from(
+        (name, e, structs, options): (&'a String, &'a ethabi::Event, &'a Structs, &'a Options),
+    ) -> Self {
+        let components = structs.event_inputs(e);
+
         let names: Vec<_> = e
             .inputs
             .iter()
@@ -41,32 +72,21 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             })
             .collect();
 
-        let topic_count = e.inputs.iter().filter(|param| param.indexed).count() + 1;
-
-        let fixed_data_size = e.inputs.iter().filter(|param| !param.indexed).fold(
-            Some(0usize),
-            |size, param| -> Option<usize> {
-                match size {
-                    Some(count) => fixed_data_size(&param.kind)
-                        .map(|param_fixed_size| count + param_fixed_size),
-                    None => None,
-                }
-            },
-        );
-
-        let min_data_size = e
-            .inputs
-            .iter()
-            .filter(|param| !param.indexed)
-            .map(|param| min_data_size(&param.kind))
-            .sum();
+        // Anonymous events don't emit the event's signature as the first topic
+        let first_indexed_topic = if e.anonymous { 0 } else { 1 };
+        let LogShape {
+            topic_count,
+            fixed_data_size,
+            min_data_size,
+        } = LogShape::new(e);
 
         let kinds: Vec<_> = e
             .inputs
             .iter()
-            .map(|param| match param.indexed {
-                true => rust_type_indexed(&param.kind),
-                false => rust_type(&param.kind),
+            .zip(components.iter())
+            .map(|(param, components)| match param.indexed {
+                true => rust_type_indexed(&param.kind, components, options),
+                false => rust_type(&param.kind, components, options),
             })
             .collect();
 
@@ -80,12 +100,19 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             .inputs
             .iter()
             .zip(names.iter())
-            .filter(|(param, _)| param.indexed)
+            .zip(components.iter())
+            .filter(|((param, _), _)| param.indexed)
             .enumerate()
-            .map(|(index, (param, name))| {
-                let topic_index = index + 1;
+            .map(|(index, ((param, name), components))| {
+                let topic_index = index + first_indexed_topic;
                 let topic_access = quote! { log.topics[#topic_index].as_ref() };
-                let decode_topic = decode_topic(&name.to_string(), &param.kind, &topic_access);
+                let decode_topic = decode_topic(
+                    &param.kind,
+                    &topic_access,
+                    components,
+                    options,
+                    DecodeContext::new(&e.name, &name.to_string()),
+                );
 
                 quote! {
                     #name: #decode_topic
@@ -93,6 +120,21 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             })
             .collect();
 
+        // Reading the indexed params' topics would panic on a log with less topics
+        let event_name = &e.name;
+        let decode_topics = match e.inputs.iter().any(|input| input.indexed) {
+            true => quote! {
+                if log.topics.len() < #topic_count {
+                    return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                        event: #event_name.into(),
+                        expected: #topic_count,
+                        actual: log.topics.len(),
+                    });
+                }
+            },
+            false => TokenStream::new(),
+        };
+
         let decode_data = if e.inputs.iter().any(|input| !input.indexed) {
             let params: Vec<_> = e
                 .inputs
@@ -102,12 +144,18 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
                 .collect();
 
             quote! {
+                #decode_topics
+
                 let mut values = ethabi::decode(&[#(#params),*], log.data.as_ref())
-                        .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: #event_name.into(),
+                            param: None,
+                            reason: e.to_string(),
+                        })?;
                 values.reverse();
             }
         } else {
-            TokenStream::new()
+            decode_topics
         };
 
         // We go reverse in the iteration because we use a series of `.pop()` to correctly
@@ -116,10 +164,17 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             .inputs
             .iter()
             .zip(names.iter())
-            .filter(|(param, _)| !param.indexed)
-            .map(|(param, name)| {
+            .zip(components.iter())
+            .filter(|((param, _), _)| !param.indexed)
+            .map(|((param, name), components)| {
                 let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
-                let decode_topic = from_token(&param.kind, &data_access);
+                let decode_topic = from_token(
+                    &param.kind,
+                    &data_access,
+                    components,
+                    options,
+                    DecodeContext::new(&e.name, &name.to_string()),
+                );
 
                 quote! {
                    #name: #decode_topic
@@ -127,10 +182,75 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             })
             .collect();
 
+        // Indexed dynamic values are only known by their hash, which is the topic itself
+        let encode_topics = e
+            .inputs
+            .iter()
+            .zip(names.iter())
+            .zip(components.iter())
+            .filter(|((param, _), _)| param.indexed)
+            .map(
+                |((param, name), components)| match param.kind.is_dynamic() {
+                    true => quote! { self.#name.hash.clone() },
+                    false => {
+                        let token =
+                            to_token(&quote! { self.#name }, &param.kind, components, options);
+                        quote! { ethabi::encode(&[#token]) }
+                    }
+                },
+            )
+            .collect();
+
+        let encode_data = e
+            .inputs
+            .iter()
+            .zip(names.iter())
+            .zip(components.iter())
+            .filter(|((param, _), _)| !param.indexed)
+            .map(|((param, name), components)| {
+                to_token(&quote! { self.#name }, &param.kind, components, options)
+            })
+            .collect();
+
+        let proto_fields: Vec<_> = e
+            .inputs
+            .iter()
+            .zip(names.iter())
+            .zip(components.iter())
+            .map(|((param, name), components)| Field {
+                name,
+                kind: &param.kind,
+                components,
+                indexed_hash: param.indexed && param.kind.is_dynamic(),
+            })
+            .collect();
+        let camel_name = name.to_upper_camel_case();
+        // Both live in the same protobuf package and struct messages are referenced by name
+        // from other messages, so the event's message is the one suffixed on a collision
+        let message_name = match structs.contains(&camel_name) {
+            true => format!("{}Event", camel_name),
+            false => camel_name.clone(),
+        };
+        let proto = Message::new(
+            &syn::Ident::new(&camel_name, Span::call_site()),
+            &message_name,
+            &proto_fields,
+            options,
+        );
+
         Event {
             name: name.clone(),
             original_name: e.name.clone(),
+            anonymous: e.anonymous,
+            signature: canonical_signature(&e.name, e.inputs.iter().map(|param| &param.kind)),
             topic_hash: e.signature().to_fixed_bytes(),
+            input_types: e
+                .inputs
+                .iter()
+                .map(|param| to_syntax_string(&param.kind))
+                .collect(),
+            param_names: e.inputs.iter().map(|param| param.name.clone()).collect(),
+            docs: Docs::default(),
             topic_count,
             fixed_data_size,
             min_data_size,
@@ -138,21 +258,34 @@ impl<'a> From<(&'a String, &'a ethabi::Event)> for Event {
             decode_indexed_fields,
             decode_unindexed_fields,
             decode_data,
+            encode_topics,
+            encode_data,
+            attributes: options.struct_attributes(options.event_attributes.get(&e.name)),
+            proto,
         }
     }
 }
 
 impl Event {
+    /// Attaches the event's documentation found in the `natspec` of the compilation artifact.
+    pub(crate) fn document(&mut self, natspec: &NatSpec) {
+        self.docs = natspec.event(&self.signature);
+    }
+
     /// Generates rust interface for contract's event.
     pub fn generate_event(&self) -> TokenStream {
         let original_name = &self.original_name;
+        let signature = &self.signature;
+        let input_types = &self.input_types;
+        let attributes = &self.attributes;
+        let proto_conversion = self.proto.as_ref().map(|proto| &proto.conversion);
         let topic_count = &self.topic_count;
         let topic_hash_bytes: Vec<_> = self
             .topic_hash
             .iter()
             .map(|value| quote! { #value })
             .collect();
-        let camel_name = syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
+        let camel_name = self.camel_name();
         let log_fields = &self.log_fields;
 
         let decode_data = &self.decode_data;
@@ -162,6 +295,24 @@ impl Event {
         decode_fields.extend(self.decode_indexed_fields.iter());
         decode_fields.extend(self.decode_unindexed_fields.iter());
 
+        // Anonymous events have no `TOPIC_ID` constant, their signature hash is never emitted
+        let topic0 = match self.anonymous {
+            false => quote! { Self::TOPIC_ID },
+            true => quote! { [#(#topic_hash_bytes),*] },
+        };
+
+        let anonymous_const = match self.anonymous {
+            false => quote! {},
+            true => quote! { const ANONYMOUS: bool = true; },
+        };
+
+        let encode_data = &self.encode_data;
+        let mut encode_topics = Vec::with_capacity(self.encode_topics.len() + 1);
+        if !self.anonymous {
+            encode_topics.push(quote! { Self::TOPIC_ID.to_vec() });
+        }
+        encode_topics.extend(self.encode_topics.iter().cloned());
+
         let min_data_size = &self.min_data_size;
         let log_match_data = match &self.fixed_data_size {
             Some(fixed_data_size) => {
@@ -180,13 +331,8 @@ impl Event {
             }
         };
 
-        quote! {
-            #[derive(Debug, Clone, PartialEq)]
-            pub struct #camel_name {
-                #(#log_fields),*
-            }
-
-            impl #camel_name {
+        let match_implementation = match self.anonymous {
+            false => quote! {
                 const TOPIC_ID: [u8; 32] = [#(#topic_hash_bytes),*];
 
                 pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
@@ -199,26 +345,163 @@ impl Event {
                     return log.topics.get(0).expect("bounds already checked").as_ref()
                         == Self::TOPIC_ID;
                 }
+            },
+            true => quote! {
+                /// Anonymous event, there is no signature topic to check so the log is matched
+                /// solely on its shape (topics count and data size). Use [Self::match_log_at]
+                /// to also restrict the match to logs emitted by a given contract address.
+                pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                    if log.topics.len() != #topic_count {
+                        return false;
+                    }
+
+                    #log_match_data
+
+                    true
+                }
+
+                /// Matches the log if it was emitted by `address` and has the shape of this
+                /// anonymous event.
+                pub fn match_log_at(log: &substreams_ethereum::pb::eth::v2::Log, address: &[u8]) -> bool {
+                    log.address == address && Self::match_log(log)
+                }
+            },
+        };
+
+        let docs = match self.anonymous {
+            false => self.docs.item(&format!(
+                "Event `{}`, topic 0 `0x{}`.",
+                signature,
+                hex::encode(self.topic_hash)
+            )),
+            true => self.docs.item(&format!(
+                "Anonymous event `{}`, logged without signature topic.",
+                signature
+            )),
+        };
+        let field_docs: Vec<_> = self
+            .param_names
+            .iter()
+            .map(|name| self.docs.param(name))
+            .collect();
 
-                pub fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+        quote! {
+            #docs
+            #attributes
+            pub struct #camel_name {
+                #(#field_docs #log_fields),*
+            }
+
+            impl #camel_name {
+                #match_implementation
+
+                pub fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                     #decode_data
 
                     Ok(Self {
                         #(#decode_fields),*
                     })
                 }
+
+                pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                    substreams_ethereum::pb::eth::v2::Log {
+                        topics: vec![#(#encode_topics),*],
+                        data: ethabi::encode(&[#(#encode_data),*]),
+                        ..Default::default()
+                    }
+                }
+
+                pub fn to_log(&self, address: &[u8]) -> substreams_ethereum::pb::eth::v2::Log {
+                    substreams_ethereum::pb::eth::v2::Log {
+                        address: address.to_vec(),
+                        ..self.encode()
+                    }
+                }
             }
 
             impl substreams_ethereum::Event for #camel_name {
                 const NAME: &'static str = #original_name;
+                const SIGNATURE: &'static str = #signature;
+                const TOPIC0: [u8; 32] = #topic0;
+                #anonymous_const
+
+                fn input_types() -> Vec<ethabi::ParamType> {
+                    vec![#(#input_types),*]
+                }
                 fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                     Self::match_log(log)
                 }
-                fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                     Self::decode(log)
                 }
+                fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                    self.encode()
+                }
             }
+
+            #proto_conversion
+        }
+    }
+
+    /// Returns the definition of the event's protobuf message, if enabled.
+    pub(crate) fn proto_definition(&self) -> Option<&str> {
+        self.proto.as_ref().map(|proto| proto.definition.as_str())
+    }
+
+    /// Generates the contract-level `Events` enum dispatching on the signature topic of
+    /// a log. Anonymous events have no signature topic and are left out.
+    pub fn generate_enum(events: &[Event]) -> TokenStream {
+        let events: Vec<_> = events.iter().filter(|event| !event.anonymous).collect();
+        if events.is_empty() {
+            return quote! {};
         }
+
+        let variants: Vec<_> = events
+            .iter()
+            .map(|event| {
+                let camel_name = event.camel_name();
+                quote! { #camel_name(#camel_name) }
+            })
+            .collect();
+
+        let decode_arms: Vec<_> = events
+            .iter()
+            .map(|event| {
+                let camel_name = event.camel_name();
+                quote! {
+                    #camel_name::TOPIC_ID if #camel_name::match_log(log) => {
+                        Some(#camel_name::decode(log).map(Events::#camel_name))
+                    }
+                }
+            })
+            .collect();
+
+        quote! {
+            /// All non-anonymous events of the contract, use [Events::decode_any] to decode any
+            /// log into the matching event.
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum Events {
+                #(#variants),*
+            }
+
+            impl Events {
+                /// Decodes the `log` into the event whose signature matches the log's first topic.
+                /// Returns `None` if no event of the contract matches the log, anonymous events
+                /// are never matched.
+                pub fn decode_any(log: &substreams_ethereum::pb::eth::v2::Log) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+                    let topic_id: [u8; 32] = log.topics.get(0)?.as_slice().try_into().ok()?;
+
+                    match topic_id {
+                        #(#decode_arms,)*
+                        _ => None,
+                    }
+                }
+            }
+        }
+    }
+
+    fn camel_name(&self) -> syn::Ident {
+        syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site())
     }
 }
 
@@ -242,6 +525,7 @@ mod tests {
         assert_ast_eq(
             e.generate_event(),
             quote! {
+                #[doc = " Event `hello()`, topic 0 `0x19ff1d210e06a53ee50e5bad25fa509a6b00ed395695f7d9b82b68155d9e1065`."]
                 #[derive(Debug, Clone, PartialEq)]
                 pub struct Hello {}
                 impl Hello {
@@ -291,18 +575,43 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
                         Ok(Self {})
                     }
+
+                    pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            topics: vec![Self::TOPIC_ID.to_vec()],
+                            data: ethabi::encode(&[]),
+                            ..Default::default()
+                        }
+                    }
+                    pub fn to_log(
+                        &self,
+                        address: &[u8]
+                    ) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address: address.to_vec(),
+                            ..self.encode()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for Hello {
                     const NAME: &'static str = "hello";
+                    const SIGNATURE: &'static str = "hello()";
+                    const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+                    fn input_types() -> Vec<ethabi::ParamType> {
+                        vec![]
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode()
+                    }
                 }
             },
         );
@@ -325,6 +634,7 @@ mod tests {
         assert_ast_eq(
             e.generate_event(),
             quote! {
+                #[doc = " Event `one(address)`, topic 0 `0xf2889ac4c1896b0db9fb737bb08ff6e9ab47dfd8bf35c0dd788cc01379281642`."]
                 #[derive(Debug, Clone, PartialEq)]
                 pub struct One {
                     pub foo: Vec<u8>
@@ -376,16 +686,24 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() < 2usize {
+                            return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                                event: "one".into(),
+                                expected: 2usize,
+                                actual: log.topics.len(),
+                            });
+                        }
                         Ok(Self {
                             foo: ethabi::decode(
                                     &[ethabi::ParamType::Address],
                                     log.topics[1usize].as_ref()
                                 )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'foo' from topic of type 'address': {:?}",
-                                    e
-                                ))?
+                                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                    item: "one".into(),
+                                    param: Some("foo".into()),
+                                    reason: e.to_string(),
+                                })?
                                 .pop()
                                 .expect(INTERNAL_ERR)
                                 .into_address()
@@ -394,15 +712,40 @@ mod tests {
                                 .to_vec()
                         })
                     }
+
+                    pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            topics: vec![Self::TOPIC_ID.to_vec(), ethabi::encode(&[ethabi::Token::Address(ethabi::Address::from_slice(&self.foo))])],
+                            data: ethabi::encode(&[]),
+                            ..Default::default()
+                        }
+                    }
+                    pub fn to_log(
+                        &self,
+                        address: &[u8]
+                    ) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address: address.to_vec(),
+                            ..self.encode()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for One {
                     const NAME: &'static str = "one";
+                    const SIGNATURE: &'static str = "one(address)";
+                    const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+                    fn input_types() -> Vec<ethabi::ParamType> {
+                        vec![ethabi::ParamType::Address]
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode()
+                    }
                 }
             },
         );
@@ -437,6 +780,7 @@ mod tests {
         assert_ast_eq(
             e.generate_event(),
             quote! {
+                #[doc = " Event `Transfer(address,address,uint256)`, topic 0 `0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef`."]
                 #[derive(Debug, Clone, PartialEq)]
                 pub struct Transfer {
                     pub from: Vec<u8>,
@@ -490,22 +834,34 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() < 3usize {
+                            return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                                event: "Transfer".into(),
+                                expected: 3usize,
+                                actual: log.topics.len(),
+                            });
+                        }
                         let mut values = ethabi::decode(
                                 &[ethabi::ParamType::Uint(256usize)],
                                 log.data.as_ref()
                             )
-                            .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                            .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                item: "Transfer".into(),
+                                param: None,
+                                reason: e.to_string(),
+                            })?;
                         values.reverse();
                         Ok(Self {
                             from: ethabi::decode(
                                     &[ethabi::ParamType::Address],
                                     log.topics[1usize].as_ref()
                                 )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'from' from topic of type 'address': {:?}",
-                                    e
-                                ))?
+                                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                    item: "Transfer".into(),
+                                    param: Some("from".into()),
+                                    reason: e.to_string(),
+                                })?
                                 .pop()
                                 .expect(INTERNAL_ERR)
                                 .into_address()
@@ -516,9 +872,11 @@ mod tests {
                                     &[ethabi::ParamType::Address],
                                     log.topics[2usize].as_ref()
                                 )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'to' from topic of type 'address': {:?}", e
-                                ))?
+                                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                    item: "Transfer".into(),
+                                    param: Some("to".into()),
+                                    reason: e.to_string(),
+                                })?
                                 .pop()
                                 .expect(INTERNAL_ERR)
                                 .into_address()
@@ -537,15 +895,51 @@ mod tests {
                             }
                         })
                     }
+
+                    pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            topics: vec![Self::TOPIC_ID.to_vec(), ethabi::encode(&[ethabi::Token::Address(ethabi::Address::from_slice(&self.from))]), ethabi::encode(&[ethabi::Token::Address(ethabi::Address::from_slice(&self.to))])],
+                            data: ethabi::encode(&[ethabi::Token::Uint(
+                                        ethabi::Uint::from_big_endian(
+                                            match self.quantity.clone().to_bytes_be() {
+                                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                                (num_bigint::Sign::Minus, _) => {
+                                                    panic!("negative numbers are not supported")
+                                                },
+                                            }
+                                            .as_slice(),
+                                        ),
+                                    )]),
+                            ..Default::default()
+                        }
+                    }
+                    pub fn to_log(
+                        &self,
+                        address: &[u8]
+                    ) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address: address.to_vec(),
+                            ..self.encode()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for Transfer {
                     const NAME: &'static str = "Transfer";
+                    const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+                    const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+                    fn input_types() -> Vec<ethabi::ParamType> {
+                        vec![ethabi::ParamType::Address, ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)]
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode()
+                    }
                 }
             },
         );
@@ -580,6 +974,7 @@ mod tests {
         assert_ast_eq(
             e.generate_event(),
             quote! {
+                #[doc = " Event `Transfer(address,address,uint256)`, topic 0 `0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef`."]
                 #[derive(Debug, Clone, PartialEq)]
                 pub struct Transfer {
                     pub from: Vec<u8>,
@@ -633,16 +1028,24 @@ mod tests {
                     }
                     pub fn decode(
                         log: &substreams_ethereum::pb::eth::v2::Log
-                    ) -> Result<Self, String> {
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() < 4usize {
+                            return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                                event: "Transfer".into(),
+                                expected: 4usize,
+                                actual: log.topics.len(),
+                            });
+                        }
                         Ok(Self {
                             from: ethabi::decode(
                                     &[ethabi::ParamType::Address],
                                     log.topics[1usize].as_ref()
                                 )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'from' from topic of type 'address': {:?}",
-                                    e
-                                ))?
+                                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                    item: "Transfer".into(),
+                                    param: Some("from".into()),
+                                    reason: e.to_string(),
+                                })?
                                 .pop()
                                 .expect(INTERNAL_ERR)
                                 .into_address()
@@ -653,9 +1056,11 @@ mod tests {
                                     &[ethabi::ParamType::Address],
                                     log.topics[2usize].as_ref()
                                 )
-                                .map_err(|e| format!(
-                                    "unable to decode param 'to' from topic of type 'address': {:?}", e
-                                ))?
+                                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                    item: "Transfer".into(),
+                                    param: Some("to".into()),
+                                    reason: e.to_string(),
+                                })?
                                 .pop()
                                 .expect(INTERNAL_ERR)
                                 .into_address()
@@ -668,10 +1073,11 @@ mod tests {
                                     &[ethabi::ParamType::Uint(256usize)],
                                     log.topics[3usize].as_ref()
                                 )
-                                    .map_err(|e| format!(
-                                        "unable to decode param 'token_id' from topic of type 'uint256': {:?}",
-                                        e
-                                    ))?
+                                    .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                    item: "Transfer".into(),
+                                    param: Some("token_id".into()),
+                                    reason: e.to_string(),
+                                })?
                                     .pop()
                                     .expect(INTERNAL_ERR)
                                     .into_uint()
@@ -681,15 +1087,234 @@ mod tests {
                             }
                         })
                     }
+
+                    pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            topics: vec![Self::TOPIC_ID.to_vec(), ethabi::encode(&[ethabi::Token::Address(ethabi::Address::from_slice(&self.from))]), ethabi::encode(&[ethabi::Token::Address(ethabi::Address::from_slice(&self.to))]), ethabi::encode(&[ethabi::Token::Uint(
+                                        ethabi::Uint::from_big_endian(
+                                            match self.token_id.clone().to_bytes_be() {
+                                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                                (num_bigint::Sign::Minus, _) => {
+                                                    panic!("negative numbers are not supported")
+                                                },
+                                            }
+                                            .as_slice(),
+                                        ),
+                                    )])],
+                            data: ethabi::encode(&[]),
+                            ..Default::default()
+                        }
+                    }
+                    pub fn to_log(
+                        &self,
+                        address: &[u8]
+                    ) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address: address.to_vec(),
+                            ..self.encode()
+                        }
+                    }
                 }
                 impl substreams_ethereum::Event for Transfer {
                     const NAME: &'static str = "Transfer";
+                    const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+                    const TOPIC0: [u8; 32] = Self::TOPIC_ID;
+                    fn input_types() -> Vec<ethabi::ParamType> {
+                        vec![ethabi::ParamType::Address, ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)]
+                    }
+                    fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                        Self::match_log(log)
+                    }
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Self::decode(log)
+                    }
+                    fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode()
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_event_anonymous() {
+        let ethabi_event = ethabi::Event {
+            name: "Note".into(),
+            inputs: vec![
+                ethabi::EventParam {
+                    name: "guy".into(),
+                    kind: ethabi::ParamType::Address,
+                    indexed: true,
+                },
+                ethabi::EventParam {
+                    name: "wad".into(),
+                    kind: ethabi::ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: true,
+        };
+
+        let e = Event::from((&ethabi_event.name, &ethabi_event));
+
+        assert_ast_eq(
+            e.generate_event(),
+            quote! {
+                #[doc = " Anonymous event `Note(address,uint256)`, logged without signature topic."]
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct Note {
+                    pub guy: Vec<u8>,
+                    pub wad: substreams::scalar::BigInt
+                }
+                impl Note {
+                    /// Anonymous event, there is no signature topic to check so the log is matched
+                    /// solely on its shape (topics count and data size). Use [Self::match_log_at]
+                    /// to also restrict the match to logs emitted by a given contract address.
+                    pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                        if log.topics.len() != 1usize {
+                            return false;
+                        }
+                        if log.data.len() != 32usize {
+                            return false;
+                        }
+                        true
+                    }
+                    /// Matches the log if it was emitted by `address` and has the shape of this
+                    /// anonymous event.
+                    pub fn match_log_at(
+                        log: &substreams_ethereum::pb::eth::v2::Log,
+                        address: &[u8]
+                    ) -> bool {
+                        log.address == address && Self::match_log(log)
+                    }
+                    pub fn decode(
+                        log: &substreams_ethereum::pb::eth::v2::Log
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        if log.topics.len() < 1usize {
+                            return Err(substreams_ethereum::DecodeError::TopicMismatch {
+                                event: "Note".into(),
+                                expected: 1usize,
+                                actual: log.topics.len(),
+                            });
+                        }
+                        let mut values = ethabi::decode(
+                                &[ethabi::ParamType::Uint(256usize)],
+                                log.data.as_ref()
+                            )
+                            .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                item: "Note".into(),
+                                param: None,
+                                reason: e.to_string(),
+                            })?;
+                        values.reverse();
+                        Ok(Self {
+                            guy: ethabi::decode(
+                                    &[ethabi::ParamType::Address],
+                                    log.topics[0usize].as_ref()
+                                )
+                                .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                                    item: "Note".into(),
+                                    param: Some("guy".into()),
+                                    reason: e.to_string(),
+                                })?
+                                .pop()
+                                .expect(INTERNAL_ERR)
+                                .into_address()
+                                .expect(INTERNAL_ERR)
+                                .as_bytes()
+                                .to_vec(),
+                            wad: {
+                                let mut v = [0 as u8; 32];
+                                values
+                                    .pop()
+                                    .expect(INTERNAL_ERR)
+                                    .into_uint()
+                                    .expect(INTERNAL_ERR)
+                                    .to_big_endian(v.as_mut_slice());
+                                substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
+                            }
+                        })
+                    }
+
+                    pub fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            topics: vec![ethabi::encode(&[ethabi::Token::Address(ethabi::Address::from_slice(&self.guy))])],
+                            data: ethabi::encode(&[ethabi::Token::Uint(
+                                        ethabi::Uint::from_big_endian(
+                                            match self.wad.clone().to_bytes_be() {
+                                                (num_bigint::Sign::Plus, bytes) => bytes,
+                                                (num_bigint::Sign::NoSign, bytes) => bytes,
+                                                (num_bigint::Sign::Minus, _) => {
+                                                    panic!("negative numbers are not supported")
+                                                },
+                                            }
+                                            .as_slice(),
+                                        ),
+                                    )]),
+                            ..Default::default()
+                        }
+                    }
+                    pub fn to_log(
+                        &self,
+                        address: &[u8]
+                    ) -> substreams_ethereum::pb::eth::v2::Log {
+                        substreams_ethereum::pb::eth::v2::Log {
+                            address: address.to_vec(),
+                            ..self.encode()
+                        }
+                    }
+                }
+                impl substreams_ethereum::Event for Note {
+                    const NAME: &'static str = "Note";
+                    const SIGNATURE: &'static str = "Note(address,uint256)";
+                    const TOPIC0: [u8; 32] = [
+                        7u8,
+                        97u8,
+                        128u8,
+                        134u8,
+                        178u8,
+                        221u8,
+                        49u8,
+                        134u8,
+                        222u8,
+                        50u8,
+                        115u8,
+                        213u8,
+                        31u8,
+                        178u8,
+                        181u8,
+                        106u8,
+                        248u8,
+                        148u8,
+                        201u8,
+                        210u8,
+                        197u8,
+                        29u8,
+                        125u8,
+                        79u8,
+                        176u8,
+                        210u8,
+                        40u8,
+                        255u8,
+                        199u8,
+                        232u8,
+                        29u8,
+                        96u8
+                    ];
+                    const ANONYMOUS: bool = true;
+                    fn input_types() -> Vec<ethabi::ParamType> {
+                        vec![ethabi::ParamType::Address, ethabi::ParamType::Uint(256usize)]
+                    }
                     fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
                         Self::match_log(log)
                     }
-                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, String> {
+                    fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Self, substreams_ethereum::DecodeError> {
                         Self::decode(log)
                     }
+                    fn encode(&self) -> substreams_ethereum::pb::eth::v2::Log {
+                        self.encode()
+                    }
                 }
             },
         );
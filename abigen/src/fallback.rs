@@ -0,0 +1,280 @@
+use std::collections::BTreeSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::Options;
+
+/// Structure used to generate the interface of contract's `receive` and `fallback` functions.
+pub struct Fallback {
+    /// The contract declares a `receive` function
+    receive: bool,
+    /// The contract declares a `fallback` function
+    fallback: bool,
+    /// The caller is decoded into a `substreams_ethereum::Address`
+    address_types: bool,
+    /// Selectors of all the contract's functions, including those left out by the filters
+    known_selectors: Vec<[u8; 4]>,
+    fields: TokenStream,
+    attributes: TokenStream,
+}
+
+impl<'a> From<(&'a ethabi::Contract, &'a Options)> for Fallback {
+    fn from((c, options): (&'a ethabi::Contract, &'a Options)) -> Self {
+        let caller_type = match options.address_types {
+            true => quote! { substreams_ethereum::Address },
+            false => quote! { Vec<u8> },
+        };
+
+        Fallback {
+            receive: c.receive,
+            fallback: c.fallback,
+            address_types: options.address_types,
+            known_selectors: c
+                .functions()
+                .map(|function| function.short_signature())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+            fields: quote! {
+                pub caller: #caller_type,
+                pub value: substreams::scalar::BigInt,
+                pub input: Vec<u8>
+            },
+            attributes: options.struct_attributes(None),
+        }
+    }
+}
+
+impl Fallback {
+    /// Generates the `Receive` and `Fallback` structs, each only when the contract declares
+    /// the matching function. The `fallback` function is executed for calls whose selector
+    /// is none of the contract's functions.
+    pub fn generate(&self) -> TokenStream {
+        let receive = match self.receive {
+            false => quote! {},
+            true => self.generate_struct(
+                quote! { Receive },
+                "receive",
+                quote! {
+                    /// Contract's `receive` function, executed for calls with an empty input and a value
+                    /// like plain Ether transfers.
+                },
+                quote! {
+                    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                        call.call_type != substreams_ethereum::pb::eth::v2::CallType::Create as i32
+                            && call.input.is_empty()
+                            && call.value.as_ref().map_or(false, |value| value.bytes.iter().any(|byte| *byte != 0))
+                    }
+                },
+            ),
+        };
+
+        let fallback = match self.fallback {
+            false => quote! {},
+            true => {
+                let selectors_count = self.known_selectors.len();
+                let selectors = self
+                    .known_selectors
+                    .iter()
+                    .map(|selector| quote! { [#(#selector),*] });
+
+                // Calls with an empty input go to `receive` when the contract declares it
+                let match_short_input = match self.receive {
+                    true => quote! { !call.input.is_empty() },
+                    false => quote! { true },
+                };
+
+                self.generate_struct(
+                    quote! { Fallback },
+                    "fallback",
+                    quote! {
+                        /// Contract's `fallback` function, executed for calls whose input doesn't
+                        /// start with the selector of any of the contract's functions.
+                    },
+                    quote! {
+                        const KNOWN_SELECTORS: [[u8; 4]; #selectors_count] = [#(#selectors),*];
+
+                        pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                            // The input of a contract creation is its init code, not a call
+                            if call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32 {
+                                return false;
+                            }
+
+                            match call.input.get(0..4) {
+                                Some(selector) => !Self::KNOWN_SELECTORS
+                                    .iter()
+                                    .any(|known| known == selector),
+                                None => #match_short_input,
+                            }
+                        }
+                    },
+                )
+            }
+        };
+
+        quote! {
+            #receive
+
+            #fallback
+        }
+    }
+
+    fn generate_struct(
+        &self,
+        name: TokenStream,
+        item: &str,
+        doc: TokenStream,
+        match_implementation: TokenStream,
+    ) -> TokenStream {
+        let attributes = &self.attributes;
+        let fields = &self.fields;
+        let decode_caller = match self.address_types {
+            true => quote! {
+                substreams_ethereum::Address::from_slice(&call.caller).map_err(|_| {
+                    substreams_ethereum::DecodeError::DataSize {
+                        item: #item.into(),
+                        expected: substreams_ethereum::Address::LEN,
+                        actual: call.caller.len(),
+                    }
+                })?
+            },
+            false => quote! { call.caller.clone() },
+        };
+
+        quote! {
+            #doc
+            #attributes
+            pub struct #name {
+                #fields
+            }
+
+            impl #name {
+                #match_implementation
+
+                /// Decodes the caller, the value and the raw input of the `call`, it doesn't
+                /// check that the call matches, see [Self::match_and_decode].
+                pub fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, substreams_ethereum::DecodeError> {
+                    Ok(Self {
+                        caller: #decode_caller,
+                        value: call.value.as_ref().map(Into::into).unwrap_or_default(),
+                        input: call.input.clone()
+                    })
+                }
+
+                pub fn match_and_decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Option<Self> {
+                    if !Self::match_call(call) {
+                        return None;
+                    }
+
+                    Self::decode(call).ok()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use crate::assertions::assert_ast_eq;
+
+    use super::Fallback;
+
+    #[test]
+    fn test_receive_and_fallback() {
+        let ethabi_contract = ethabi::Contract {
+            constructor: None,
+            functions: Default::default(),
+            events: Default::default(),
+            errors: Default::default(),
+            receive: true,
+            fallback: true,
+        };
+
+        let options = Default::default();
+        let f = Fallback::from((&ethabi_contract, &options));
+
+        assert_ast_eq(
+            f.generate(),
+            quote! {
+                /// Contract's `receive` function, executed for calls with an empty input and a value
+                /// like plain Ether transfers.
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct Receive {
+                    pub caller: Vec<u8>,
+                    pub value: substreams::scalar::BigInt,
+                    pub input: Vec<u8>
+                }
+                impl Receive {
+                    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                        call.call_type != substreams_ethereum::pb::eth::v2::CallType::Create as i32
+                            && call.input.is_empty()
+                            && call.value.as_ref().map_or(false, |value| value.bytes.iter().any(|byte| *byte != 0))
+                    }
+                    /// Decodes the caller, the value and the raw input of the `call`, it doesn't
+                    /// check that the call matches, see [Self::match_and_decode].
+                    pub fn decode(
+                        call: &substreams_ethereum::pb::eth::v2::Call
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Ok(Self {
+                            caller: call.caller.clone(),
+                            value: call.value.as_ref().map(Into::into).unwrap_or_default(),
+                            input: call.input.clone()
+                        })
+                    }
+                    pub fn match_and_decode(
+                        call: &substreams_ethereum::pb::eth::v2::Call
+                    ) -> Option<Self> {
+                        if !Self::match_call(call) {
+                            return None;
+                        }
+                        Self::decode(call).ok()
+                    }
+                }
+                /// Contract's `fallback` function, executed for calls whose input doesn't
+                /// start with the selector of any of the contract's functions.
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct Fallback {
+                    pub caller: Vec<u8>,
+                    pub value: substreams::scalar::BigInt,
+                    pub input: Vec<u8>
+                }
+                impl Fallback {
+                    const KNOWN_SELECTORS: [[u8; 4]; 0usize] = [];
+                    pub fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
+                        if call.call_type == substreams_ethereum::pb::eth::v2::CallType::Create as i32 {
+                            return false;
+                        }
+                        match call.input.get(0..4) {
+                            Some(selector) => !Self::KNOWN_SELECTORS
+                                .iter()
+                                .any(|known| known == selector),
+                            None => !call.input.is_empty(),
+                        }
+                    }
+                    /// Decodes the caller, the value and the raw input of the `call`, it doesn't
+                    /// check that the call matches, see [Self::match_and_decode].
+                    pub fn decode(
+                        call: &substreams_ethereum::pb::eth::v2::Call
+                    ) -> Result<Self, substreams_ethereum::DecodeError> {
+                        Ok(Self {
+                            caller: call.caller.clone(),
+                            value: call.value.as_ref().map(Into::into).unwrap_or_default(),
+                            input: call.input.clone()
+                        })
+                    }
+                    pub fn match_and_decode(
+                        call: &substreams_ethereum::pb::eth::v2::Call
+                    ) -> Option<Self> {
+                        if !Self::match_call(call) {
+                            return None;
+                        }
+                        Self::decode(call).ok()
+                    }
+                }
+            },
+        );
+    }
+}
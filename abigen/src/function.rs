@@ -6,15 +6,29 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use heck::ToUpperCamelCase;
+use std::collections::HashSet;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use crate::to_syntax_string;
+use crate::{
+    canonical_signature,
+    natspec::{Docs, NatSpec},
+    proto::{Field, Message},
+    structs::Structs,
+    to_syntax_string, DecodeContext, Options,
+};
 
 use super::{from_token, get_output_kinds, param_names, rust_type, to_token};
 
 struct Inputs {
+    /// Rust name of each param.
+    names: Vec<syn::Ident>,
+    /// Rust type of each param.
+    kinds: Vec<TokenStream>,
+    /// `ethabi::ParamType` of each param.
+    types: Vec<TokenStream>,
     tokenize: Vec<TokenStream>,
     decoded_values: TokenStream,
     decoded_fields: Vec<TokenStream>,
@@ -26,6 +40,8 @@ struct Outputs {
     implementation: TokenStream,
     /// Decode result.
     result: TokenStream,
+    /// `ethabi::ParamType` of each param.
+    types: Vec<TokenStream>,
 
     count: usize,
 }
@@ -37,15 +53,42 @@ pub struct Function {
     /// Original name of the function as defined in the ABI
     original_name: String,
 
+    /// Canonical signature, e.g. `transfer(address,uint256)`
+    signature: String,
     short_signature: [u8; 4],
+    /// The function is `view` or `pure` (or `constant` in ABIs prior to Solidity 0.5.0)
+    view: bool,
+    /// The function's `stateMutability`, `view` for `constant` functions
+    state_mutability: ethabi::StateMutability,
+    /// Generate the RPC helpers, only for `view` and `pure` functions unless all are requested
+    rpc: bool,
     /// Function input params.
     inputs: Inputs,
     /// Function output params.
     outputs: Outputs,
+    /// Names of the input params as defined in the ABI, used to look up their documentation
+    param_names: Vec<String>,
+    /// NatSpec documentation, only available when loaded from a compilation artifact
+    docs: Docs,
+    /// Derives and attributes of the function's struct.
+    attributes: TokenStream,
+    /// Protobuf message of the function's inputs, if enabled.
+    proto: Option<Message>,
 }
 
 impl<'a> From<(String, &'a ethabi::Function)> for Function {
     fn from((name, f): (String, &'a ethabi::Function)) -> Self {
+        (name, f, &Structs::default(), &Options::default()).into()
+    }
+}
+
+impl<'a> From<(String, &'a ethabi::Function, &'a Structs, &'a Options)> for Function {
+    fn from(
+        (name, f, structs, options): (String, &'a ethabi::Function, &'a Structs, &'a Options),
+    ) -> Self {
+        let input_components = structs.function_inputs(f);
+        let output_components = structs.function_outputs(f);
+
         // [param0, hello_world, param2]
         let input_names = param_names(&f.inputs);
 
@@ -53,7 +96,8 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
         let input_kinds: Vec<_> = f
             .inputs
             .iter()
-            .map(|param| rust_type(&param.kind))
+            .zip(input_components.iter())
+            .map(|(param, components)| rust_type(&param.kind, components, options))
             .collect();
 
         let input_struct_fields = input_names
@@ -62,6 +106,7 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
             .map(|(param_name, kind)| quote! { pub #param_name: #kind })
             .collect();
 
+        let function_name = &f.name;
         let input_ethabi_param_types = if !f.inputs.is_empty() {
             let params: Vec<_> = f
                 .inputs
@@ -72,11 +117,19 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
             quote! {
                 let maybe_data = call.input.get(4..);
                 if maybe_data.is_none() {
-                    return Err("no data to decode".to_string());
+                    return Err(substreams_ethereum::DecodeError::DataSize {
+                        item: #function_name.into(),
+                        expected: 4,
+                        actual: call.input.len(),
+                    });
                 }
 
                 let mut values = ethabi::decode(&[#(#params),*], maybe_data.unwrap())
-                        .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
+                        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                            item: #function_name.into(),
+                            param: None,
+                            reason: e.to_string(),
+                        })?;
                 values.reverse();
             }
         } else {
@@ -89,9 +142,16 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
             .inputs
             .iter()
             .zip(input_names.iter())
-            .map(|(param, name)| {
+            .zip(input_components.iter())
+            .map(|((param, name), components)| {
                 let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
-                let decode_input = from_token(&param.kind, &data_access);
+                let decode_input = from_token(
+                    &param.kind,
+                    &data_access,
+                    components,
+                    options,
+                    DecodeContext::new(&f.name, &name.to_string()),
+                );
                 quote! {
                    #name: #decode_input
                 }
@@ -102,10 +162,35 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
         let tokenize: Vec<_> = input_names
             .iter()
             .zip(f.inputs.iter())
-            .map(|(param_name, param)| to_token(&quote! { self.#param_name }, &param.kind))
+            .zip(input_components.iter())
+            .map(|((param_name, param), components)| {
+                to_token(
+                    &quote! { self.#param_name },
+                    &param.kind,
+                    components,
+                    options,
+                )
+            })
             .collect();
 
-        let output_result = get_output_kinds(&f.outputs);
+        let output_result = get_output_kinds(&f.outputs, &output_components, options);
+        // Outputs are often unnamed, they are then reported by their position
+        let output_names: Vec<_> = f
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(index, param)| match param.name.is_empty() {
+                true => format!("output{}", index),
+                false => param.name.clone(),
+            })
+            .collect();
+        let map_output_err = quote! {
+            .map_err(|e| substreams_ethereum::DecodeError::Abi {
+                item: #function_name.into(),
+                param: None,
+                reason: e.to_string(),
+            })?
+        };
 
         let output_param_types: Vec<_> = f
             .outputs
@@ -113,25 +198,58 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
             .map(|output| to_syntax_string(&output.kind))
             .collect();
 
+        let output_tokens: Vec<_> = f
+            .outputs
+            .iter()
+            .zip(output_components.iter())
+            .enumerate()
+            .map(|(index, (param, components))| {
+                let value = match f.outputs.len() {
+                    1 => quote! { value },
+                    _ => {
+                        let index = syn::Index::from(index);
+                        quote! { value.#index }
+                    }
+                };
+
+                to_token(&value, &param.kind, components, options)
+            })
+            .collect();
+        // Takes a reference to the type returned by `output`, even when it's a `String` or a `Vec`
+        let encode_output = quote! {
+            #[allow(clippy::ptr_arg)]
+            pub fn encode_output(value: &#output_result) -> Vec<u8> {
+                ethabi::encode(&[#(#output_tokens),*])
+            }
+        };
+
         let output_implementation = match f.outputs.len() {
             0 => quote! {},
             1 => {
                 let decode_param_type = &output_param_types[0];
                 let data_access =
                     quote! { values.pop().expect("one output data should have existed") };
-                let decode_input = from_token(&f.outputs[0].kind, &data_access);
+                let decode_input = from_token(
+                    &f.outputs[0].kind,
+                    &data_access,
+                    &output_components[0],
+                    options,
+                    DecodeContext::new(&f.name, &output_names[0]),
+                );
 
                 quote! {
-                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, String> {
+                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, substreams_ethereum::DecodeError> {
                         Self::output(call.return_data.as_ref())
                     }
 
-                    pub fn output(data: &[u8]) -> Result<#output_result, String> {
+                    pub fn output(data: &[u8]) -> Result<#output_result, substreams_ethereum::DecodeError> {
                         let mut values = ethabi::decode(&[#decode_param_type], data.as_ref())
-                        .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                        #map_output_err;
 
                         Ok(#decode_input)
                     }
+
+                    #encode_output
                 }
             }
             _ => {
@@ -143,7 +261,7 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
 
                 let output_ethabi_decoded_values = quote! {
                     let mut values = ethabi::decode(&[#(#output_tuple_fields),*], data.as_ref())
-                            .map_err(|e| format!("unable to decode output data: {:?}", e))?;
+                            #map_output_err;
                     values.reverse();
                 };
 
@@ -152,9 +270,17 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
                 let output_tuple_decoded_fields: Vec<TokenStream> = f
                     .outputs
                     .iter()
-                    .map(|param| {
+                    .zip(output_components.iter())
+                    .zip(output_names.iter())
+                    .map(|((param, components), name)| {
                         let data_access = quote! { values.pop().expect(INTERNAL_ERR) };
-                        let decode_input = from_token(&param.kind, &data_access);
+                        let decode_input = from_token(
+                            &param.kind,
+                            &data_access,
+                            components,
+                            options,
+                            DecodeContext::new(&f.name, name),
+                        );
                         quote! {
                            #decode_input
                         }
@@ -162,19 +288,42 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
                     .collect();
 
                 quote! {
-                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, String> {
+                    pub fn output_call(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<#output_result, substreams_ethereum::DecodeError> {
                         Self::output(call.return_data.as_ref())
                     }
 
-                    pub fn output(data: &[u8]) -> Result<#output_result, String> {
+                    pub fn output(data: &[u8]) -> Result<#output_result, substreams_ethereum::DecodeError> {
                         #output_ethabi_decoded_values
 
                         Ok((#(#output_tuple_decoded_fields),*))
                     }
+
+                    #encode_output
                 }
             }
         };
 
+        let proto_fields: Vec<_> = f
+            .inputs
+            .iter()
+            .zip(input_names.iter())
+            .zip(input_components.iter())
+            .map(|((param, name), components)| Field {
+                name,
+                kind: &param.kind,
+                components,
+                indexed_hash: false,
+            })
+            .collect();
+        let camel_name = name.to_upper_camel_case();
+        // Suffixed so that it does not clash with the message of an event of the same name
+        let proto = Message::new(
+            &syn::Ident::new(&camel_name, Span::call_site()),
+            &format!("{}Call", camel_name),
+            &proto_fields,
+            options,
+        );
+
         // The allow deprecated only applies to the field 'constant', but
         // due to this issue: https://github.com/rust-lang/rust/issues/60681
         // it must go on the entire struct
@@ -182,8 +331,23 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
         Function {
             name,
             original_name: f.name.clone(),
+            signature: canonical_signature(&f.name, f.inputs.iter().map(|param| &param.kind)),
             short_signature: f.short_signature(),
+            view: is_view(f),
+            state_mutability: state_mutability(f),
+            rpc: is_view(f) || options.rpc_all_functions,
+            attributes: options.struct_attributes(options.function_attributes.get(&f.name)),
+            proto,
+            param_names: f.inputs.iter().map(|param| param.name.clone()).collect(),
+            docs: Docs::default(),
             inputs: Inputs {
+                names: input_names,
+                kinds: input_kinds,
+                types: f
+                    .inputs
+                    .iter()
+                    .map(|param| to_syntax_string(&param.kind))
+                    .collect(),
                 tokenize,
                 decoded_values: input_ethabi_param_types,
                 decoded_fields: input_struct_decoded_fields,
@@ -192,6 +356,7 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
             outputs: Outputs {
                 implementation: output_implementation,
                 result: output_result,
+                types: output_param_types,
                 count: f.outputs.len(),
             },
         }
@@ -199,10 +364,15 @@ impl<'a> From<(String, &'a ethabi::Function)> for Function {
 }
 
 impl Function {
+    /// Attaches the function's documentation found in the `natspec` of the compilation artifact.
+    pub(crate) fn document(&mut self, natspec: &NatSpec) {
+        self.docs = natspec.function(&self.signature);
+    }
+
     /// Generates the interface for contract's function.
     pub fn generate(&self) -> TokenStream {
         let original_name = &self.original_name;
-        let camel_name = syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
+        let camel_name = self.camel_name();
 
         let signature_hash_bytes: Vec<_> = self
             .short_signature
@@ -210,6 +380,9 @@ impl Function {
             .map(|value| quote! { #value })
             .collect();
 
+        let signature = &self.signature;
+        let input_types = &self.inputs.types;
+        let output_types = &self.outputs.types;
         let function_fields = &self.inputs.fields;
         let tokenize = &self.inputs.tokenize;
         let decoded_input_values = &self.inputs.decoded_values;
@@ -218,8 +391,15 @@ impl Function {
         let output_implementation = &self.outputs.implementation;
         let outputs_result = &self.outputs.result;
 
-        let call_implementation = match self.outputs.count {
-            0 => quote! {},
+        let state_mutability = match self.state_mutability {
+            ethabi::StateMutability::Pure => quote! { Pure },
+            ethabi::StateMutability::View => quote! { View },
+            ethabi::StateMutability::NonPayable => quote! { NonPayable },
+            ethabi::StateMutability::Payable => quote! { Payable },
+        };
+
+        let call_implementation = match (self.rpc, self.outputs.count) {
+            (false, _) | (_, 0) => quote! {},
             _ => quote! {
                 pub fn call(&self, address: Vec<u8>) -> Option<#outputs_result> {
                     use substreams_ethereum::pb::eth::rpc;
@@ -255,27 +435,48 @@ impl Function {
             },
         };
 
-        let rpc_decodable_implementation = match self.outputs.count {
-            0 => quote! {},
+        let rpc_decodable_implementation = match (self.rpc, self.outputs.count) {
+            (false, _) | (_, 0) => quote! {},
             _ => quote! {
                 impl substreams_ethereum::rpc::RPCDecodable<#outputs_result> for #camel_name {
-                    fn output(data: &[u8]) -> Result<#outputs_result, String> {
+                    fn output(data: &[u8]) -> Result<#outputs_result, substreams_ethereum::DecodeError> {
                     Self::output(data)
                     }
                 }
             },
         };
 
+        let attributes = &self.attributes;
+        let proto_conversion = self.proto.as_ref().map(|proto| &proto.conversion);
+
+        let docs = self.docs.item(&format!(
+            "Function `{}`, selector `0x{}`.",
+            signature,
+            hex::encode(self.short_signature)
+        ));
+        let field_docs: Vec<_> = self
+            .param_names
+            .iter()
+            .map(|name| self.docs.param(name))
+            .collect();
+
         quote! {
-            #[derive(Debug, Clone, PartialEq)]
+            #docs
+            #attributes
             pub struct #camel_name {
-                #(#function_fields),*
+                #(#field_docs #function_fields),*
             }
 
             impl #camel_name {
                 const METHOD_ID: [u8; 4] = [#(#signature_hash_bytes),*];
+                pub const STATE_MUTABILITY: ethabi::StateMutability = ethabi::StateMutability::#state_mutability;
+
+                /// Returns whether the function is `payable`, i.e. its calls can carry a value.
+                pub fn is_payable() -> bool {
+                    Self::STATE_MUTABILITY == ethabi::StateMutability::Payable
+                }
 
-                pub fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, String> {
+                pub fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, substreams_ethereum::DecodeError> {
                     #decoded_input_values
 
                     Ok(Self {
@@ -307,10 +508,19 @@ impl Function {
 
             impl substreams_ethereum::Function for #camel_name {
                 const NAME: &'static str = #original_name;
+                const SIGNATURE: &'static str = #signature;
+                const SELECTOR: [u8; 4] = Self::METHOD_ID;
+
+                fn input_types() -> Vec<ethabi::ParamType> {
+                    vec![#(#input_types),*]
+                }
+                fn output_types() -> Vec<ethabi::ParamType> {
+                    vec![#(#output_types),*]
+                }
                 fn match_call(call: &substreams_ethereum::pb::eth::v2::Call) -> bool {
                     Self::match_call(call)
                 }
-                fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, String> {
+                fn decode(call: &substreams_ethereum::pb::eth::v2::Call) -> Result<Self, substreams_ethereum::DecodeError> {
                     Self::decode(call)
                 }
                 fn encode(&self) -> Vec<u8> {
@@ -319,6 +529,248 @@ impl Function {
             }
 
             #rpc_decodable_implementation
+
+            #proto_conversion
+        }
+    }
+
+    /// Returns the definition of the function's protobuf message, if enabled.
+    pub(crate) fn proto_definition(&self) -> Option<&str> {
+        self.proto.as_ref().map(|proto| proto.definition.as_str())
+    }
+
+    /// Generates the contract-level `Functions` enum dispatching on the selector of
+    /// a call's input.
+    pub fn generate_enum(functions: &[Function]) -> TokenStream {
+        if functions.is_empty() {
+            return quote! {};
+        }
+
+        let variants: Vec<_> = functions
+            .iter()
+            .map(|function| {
+                let camel_name = function.camel_name();
+                quote! { #camel_name(#camel_name) }
+            })
+            .collect();
+
+        // ABIs can list the same function more than once, only the first one is decoded
+        let mut seen_selectors = HashSet::new();
+        let decode_arms: Vec<_> = functions
+            .iter()
+            .filter(|function| seen_selectors.insert(function.short_signature))
+            .map(|function| {
+                let camel_name = function.camel_name();
+                quote! {
+                    #camel_name::METHOD_ID => Some(#camel_name::decode(call).map(Functions::#camel_name))
+                }
+            })
+            .collect();
+
+        quote! {
+            /// All functions of the contract, use [Functions::decode_any] to decode any call
+            /// into the matching function.
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum Functions {
+                #(#variants),*
+            }
+
+            impl Functions {
+                /// Decodes the `call` into the function whose selector matches the first 4 bytes
+                /// of the call's input. Returns `None` if no function of the contract matches.
+                pub fn decode_any(call: &substreams_ethereum::pb::eth::v2::Call) -> Option<Result<Self, substreams_ethereum::DecodeError>> {
+                    let selector: [u8; 4] = call.input.get(0..4)?.try_into().expect(INTERNAL_ERR);
+
+                    match selector {
+                        #(#decode_arms,)*
+                        _ => None,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates the `Contract` handle bound to an address, with a method calling each of
+    /// the `view` and `pure` functions through RPC, and its `ContractBatch` counterpart
+    /// collecting the calls into a single RPC batch. Nothing is generated when the contract
+    /// has no such function.
+    pub fn generate_contract_handle(functions: &[Function]) -> TokenStream {
+        let functions: Vec<_> = functions
+            .iter()
+            .filter(|function| function.view && function.outputs.count > 0)
+            .collect();
+        if functions.is_empty() {
+            return quote! {};
+        }
+
+        let mut call_methods = Vec::with_capacity(functions.len());
+        let mut batch_methods = Vec::with_capacity(functions.len());
+        for function in functions {
+            let camel_name = function.camel_name();
+            let method_name = function.method_name();
+            let names = &function.inputs.names;
+            let kinds = &function.inputs.kinds;
+            let outputs_result = &function.outputs.result;
+
+            call_methods.push(quote! {
+                pub fn #method_name(&self, #(#names: #kinds),*) -> Option<#outputs_result> {
+                    functions::#camel_name { #(#names),* }.call(self.address.clone())
+                }
+            });
+            batch_methods.push(quote! {
+                pub fn #method_name(self, #(#names: #kinds),*) -> Self {
+                    let batch = self.batch.add(functions::#camel_name { #(#names),* }, self.address.clone());
+
+                    Self {
+                        address: self.address,
+                        batch,
+                    }
+                }
+            });
+        }
+
+        quote! {
+            /// Contract deployed at an address, calling its `view` and `pure` functions through RPC.
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct Contract {
+                address: Vec<u8>,
+            }
+
+            impl Contract {
+                pub fn at<A: AsRef<[u8]>>(address: A) -> Self {
+                    Self {
+                        address: address.as_ref().to_vec(),
+                    }
+                }
+
+                pub fn address(&self) -> &[u8] {
+                    &self.address
+                }
+
+                /// Starts collecting calls to the contract into a single RPC batch.
+                pub fn batch(&self) -> ContractBatch {
+                    self.batch_with(substreams_ethereum::rpc::RpcBatch::new())
+                }
+
+                /// Collects calls to the contract into an existing RPC `batch`, e.g. one holding
+                /// calls to other contracts.
+                pub fn batch_with(&self, batch: substreams_ethereum::rpc::RpcBatch) -> ContractBatch {
+                    ContractBatch {
+                        address: self.address.clone(),
+                        batch,
+                    }
+                }
+
+                #(#call_methods)*
+            }
+
+            /// Calls to a [Contract] collected into a single RPC batch, the responses are in
+            /// the order of the calls and decode with `RpcBatch::decode`.
+            pub struct ContractBatch {
+                address: Vec<u8>,
+                batch: substreams_ethereum::rpc::RpcBatch,
+            }
+
+            impl ContractBatch {
+                pub fn into_rpc_batch(self) -> substreams_ethereum::rpc::RpcBatch {
+                    self.batch
+                }
+
+                pub fn execute(self) -> Result<substreams_ethereum::pb::eth::rpc::RpcResponses, String> {
+                    self.batch.execute()
+                }
+
+                #(#batch_methods)*
+            }
+        }
+    }
+
+    pub(crate) fn camel_name(&self) -> syn::Ident {
+        syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site())
+    }
+
+    /// Name of the function's method on the generated `Contract` handle, prefixed by `call_`
+    /// when it clashes with one of the handle's own methods.
+    fn method_name(&self) -> syn::Ident {
+        let name = self.name.to_snake_case();
+
+        match name.as_str() {
+            "at" | "address" | "batch" | "batch_with" | "into_rpc_batch" | "execute" => {
+                syn::Ident::new(&format!("call_{}", name), Span::call_site())
+            }
+            _ => syn::parse_str(&name)
+                .unwrap_or_else(|_| syn::Ident::new_raw(&name, Span::call_site())),
+        }
+    }
+}
+
+/// Returns the function's `stateMutability`. ABIs generated by Solidity prior to 0.4.16 have
+/// none and flag read-only functions as `constant`, the flag is only used then.
+///
+/// A missing `stateMutability` is deserialized as `nonpayable`, which is never declared along
+/// with `constant: true`, so an explicit `view` or `pure` always wins over the flag.
+fn state_mutability(f: &ethabi::Function) -> ethabi::StateMutability {
+    #[allow(deprecated)]
+    match (f.state_mutability, f.constant) {
+        (ethabi::StateMutability::NonPayable, Some(true)) => ethabi::StateMutability::View,
+        (state_mutability, _) => state_mutability,
+    }
+}
+
+/// Returns whether the function doesn't modify the state.
+fn is_view(f: &ethabi::Function) -> bool {
+    matches!(
+        state_mutability(f),
+        ethabi::StateMutability::View | ethabi::StateMutability::Pure
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ethabi::StateMutability;
+
+    use super::{is_view, state_mutability};
+
+    #[test]
+    fn test_state_mutability() {
+        let function = |json: &str| -> ethabi::Function {
+            serde_json::from_str(&format!(
+                r#"{{"type": "function", "name": "f", "inputs": [], "outputs": [], {}}}"#,
+                json
+            ))
+            .unwrap()
+        };
+
+        let cases = [
+            (r#""stateMutability": "pure""#, StateMutability::Pure),
+            (r#""stateMutability": "payable""#, StateMutability::Payable),
+            // solc 0.4.x ABIs carry both the flag and the state mutability
+            (
+                r#""constant": true, "stateMutability": "pure""#,
+                StateMutability::Pure,
+            ),
+            (
+                r#""constant": true, "stateMutability": "view""#,
+                StateMutability::View,
+            ),
+            (
+                r#""constant": false, "stateMutability": "nonpayable""#,
+                StateMutability::NonPayable,
+            ),
+            // Older ABIs only have the flag
+            (r#""constant": true"#, StateMutability::View),
+            (r#""constant": false"#, StateMutability::NonPayable),
+        ];
+
+        for (json, expected) in cases {
+            let function = function(json);
+            assert_eq!(state_mutability(&function), expected, "{}", json);
+            assert_eq!(
+                is_view(&function),
+                matches!(expected, StateMutability::View | StateMutability::Pure),
+                "{}",
+                json
+            );
         }
     }
 }
@@ -10,12 +10,20 @@
 
 extern crate proc_macro;
 
+mod artifact;
 mod assertions;
 pub mod build;
-// mod constructor;
+mod constructor;
 mod contract;
+mod error;
 mod event;
+mod fallback;
 mod function;
+mod natspec;
+mod options;
+mod proto;
+mod signatures;
+mod structs;
 
 use anyhow::format_err;
 // use ethabi::{Contract, Error, Param, ParamType, Result};
@@ -29,29 +37,96 @@ use std::{
     env, fs,
     path::{Path, PathBuf},
 };
+use structs::{Components, Structs};
+use substreams_ethereum_core::abi::canonical_signature;
 use syn::Index;
 
+pub use artifact::Artifact;
+pub use options::{Attributes, Filter, Options, ProtoOptions};
+
 pub fn generate_abi_code<S: AsRef<str>>(
     path: S,
 ) -> Result<proc_macro2::TokenStream, anyhow::Error> {
-    let normalized_path = normalize_path(path.as_ref())?;
-    let source_file = fs::File::open(&normalized_path).map_err(|_| {
+    generate_abi_code_with_options(path, &Options::default())
+}
+
+pub fn generate_abi_code_with_options<S: AsRef<str>>(
+    path: S,
+    options: &Options,
+) -> Result<proc_macro2::TokenStream, anyhow::Error> {
+    let source = read_abi(path.as_ref())?;
+
+    generate_abi_code_from_bytes_with_options(&source, options)
+}
+
+pub fn generate_abi_code_from_bytes(
+    bytes: &[u8],
+) -> Result<proc_macro2::TokenStream, anyhow::Error> {
+    generate_abi_code_from_bytes_with_options(bytes, &Options::default())
+}
+
+pub fn generate_abi_code_from_bytes_with_options(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<proc_macro2::TokenStream, anyhow::Error> {
+    Ok(load_contract_from_bytes(bytes, options)?.generate())
+}
+
+pub fn generate_abi_code_from_signatures<S: AsRef<str>>(
+    signatures: &[S],
+) -> Result<proc_macro2::TokenStream, anyhow::Error> {
+    generate_abi_code_from_signatures_with_options(signatures, &Options::default())
+}
+
+/// Generates the contract's bindings from human-readable (ethers style) signatures like
+/// `event Transfer(address indexed from, address indexed to, uint256 value)` instead of an
+/// ABI JSON file.
+pub fn generate_abi_code_from_signatures_with_options<S: AsRef<str>>(
+    signatures: &[S],
+    options: &Options,
+) -> Result<proc_macro2::TokenStream, anyhow::Error> {
+    Ok(load_contract_from_signatures(signatures, options)?.generate())
+}
+
+fn read_abi(path: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let normalized_path = normalize_path(path)?;
+
+    fs::read(&normalized_path).map_err(|_| {
         Error::Other(Cow::Owned(format!(
             "Cannot load contract abi from `{}`",
             normalized_path.display()
         )))
-    })?;
-    let contract = Contract::load(source_file)?;
-    let c = contract::Contract::from(&contract);
-    Ok(c.generate())
+        .into()
+    })
 }
 
-pub fn generate_abi_code_from_bytes(
+fn load_contract_from_bytes(
     bytes: &[u8],
-) -> Result<proc_macro2::TokenStream, anyhow::Error> {
-    let contract = Contract::load(bytes)?;
-    let c = contract::Contract::from(&contract);
-    Ok(c.generate())
+    options: &Options,
+) -> Result<contract::Contract, anyhow::Error> {
+    options.validate()?;
+
+    let artifact = Artifact::load(bytes)?;
+    let contract: Contract = serde_json::from_value(artifact.abi.clone())?;
+    options.validate_selection(&contract)?;
+
+    let structs = Structs::from_json(&options.filter_abi(&artifact.abi));
+    Ok(contract::Contract::from((&contract, &structs, options)).with_artifact(&artifact))
+}
+
+fn load_contract_from_signatures<S: AsRef<str>>(
+    signatures: &[S],
+    options: &Options,
+) -> Result<contract::Contract, anyhow::Error> {
+    options.validate()?;
+
+    let contract = signatures::parse_signatures(signatures)?;
+    options.validate_selection(&contract)?;
+    Ok(contract::Contract::from((
+        &contract,
+        &Structs::default(),
+        options,
+    )))
 }
 
 fn normalize_path<S: AsRef<Path>>(relative_path: S) -> Result<PathBuf, anyhow::Error> {
@@ -109,19 +184,32 @@ fn to_syntax_string(param_type: &ethabi::ParamType) -> proc_macro2::TokenStream
 //     quote! { vec![ #(#p),* ] }
 // }
 
-fn rust_type_indexed(input: &ParamType) -> proc_macro2::TokenStream {
+fn rust_type_indexed(
+    input: &ParamType,
+    components: &Components,
+    options: &Options,
+) -> proc_macro2::TokenStream {
     match input.is_dynamic() {
         true => {
-            let t = rust_type(input);
+            let t = rust_type(input, components, options);
             return quote! { substreams_ethereum::IndexedDynamicValue<#t> };
         }
-        false => rust_type(input),
+        false => rust_type(input, components, options),
     }
 }
 
-fn rust_type(input: &ParamType) -> proc_macro2::TokenStream {
+fn rust_type(
+    input: &ParamType,
+    components: &Components,
+    options: &Options,
+) -> proc_macro2::TokenStream {
     match *input {
+        ParamType::Address if options.address_types => quote! { substreams_ethereum::Address },
         ParamType::Address => quote! { Vec<u8> },
+        ParamType::FixedBytes(32) if options.address_types => quote! { substreams_ethereum::H256 },
+        ParamType::Int(_) | ParamType::Uint(_) if native_int_type(input, options).is_some() => {
+            native_int_type(input, options).to_token_stream()
+        }
         ParamType::Bytes => quote! { Vec<u8> },
         ParamType::FixedBytes(size) => quote! { [u8; #size] },
         ParamType::Int(_) => quote! { substreams::scalar::BigInt },
@@ -129,66 +217,74 @@ fn rust_type(input: &ParamType) -> proc_macro2::TokenStream {
         ParamType::Bool => quote! { bool },
         ParamType::String => quote! { String },
         ParamType::Array(ref kind) => {
-            let t = rust_type(&*kind);
+            let t = rust_type(kind, components.child(0), options);
             quote! { Vec<#t> }
         }
         ParamType::FixedArray(ref kind, size) => {
-            let t = rust_type(&*kind);
+            let t = rust_type(kind, components.child(0), options);
             quote! { [#t; #size] }
         }
         ParamType::Tuple(ref types) => {
-            let tuple_elements = types.iter().map(rust_type);
+            if let Some(struct_path) = components.struct_path() {
+                return struct_path;
+            }
+
+            let tuple_elements = types
+                .iter()
+                .enumerate()
+                .map(|(i, kind)| rust_type(kind, components.child(i), options));
             quote! { (#(#tuple_elements,)*) }
         }
     }
 }
 
-fn fixed_data_size(input: &ParamType) -> Option<usize> {
-    match input {
-        ParamType::Address
-        | ParamType::Int(_)
-        | ParamType::Uint(_)
-        | ParamType::Bool
-        | ParamType::FixedBytes(_) => Some(32),
-        ParamType::Bytes | ParamType::String | ParamType::Array(_) => None,
-        ParamType::FixedArray(ref sub_type, count) => match sub_type.is_dynamic() {
-            true => None,
-            false => Some(
-                count * fixed_data_size(sub_type).expect("not dynamic, will always be Some(_)"),
-            ),
-        },
-        ParamType::Tuple(ref types) => {
-            if types.iter().any(ParamType::is_dynamic) {
-                return None;
-            }
-            Some(types.iter().map(fixed_data_size).map(Option::unwrap).sum())
-        }
+/// Rust primitive integer type used for `intN` and `uintN` params when the `native_ints` option
+/// is enabled, `None` when the param is not an integer or is wider than 128 bits.
+fn native_int_type(input: &ParamType, options: &Options) -> Option<syn::Ident> {
+    if !options.native_ints {
+        return None;
+    }
+
+    let (prefix, size) = match *input {
+        ParamType::Int(size) => ("i", size),
+        ParamType::Uint(size) => ("u", size),
+        _ => return None,
+    };
+
+    let bits = match size {
+        0..=8 => 8,
+        9..=16 => 16,
+        17..=32 => 32,
+        33..=64 => 64,
+        65..=128 => 128,
+        _ => return None,
+    };
+
+    Some(syn::Ident::new(
+        &format!("{}{}", prefix, bits),
+        Span::call_site(),
+    ))
+}
+
+/// Converts the `v` 256 bits token value, already checked to fit, to the native `int_type`.
+fn native_int_value(int_type: Option<&syn::Ident>) -> proc_macro2::TokenStream {
+    match int_type {
+        Some(int_type) if int_type == "u128" => quote! { v.low_u128() },
+        Some(int_type) => quote! { v.low_u128() as #int_type },
+        None => unreachable!("only called for native integer types"),
     }
 }
 
-fn min_data_size(input: &ParamType) -> usize {
+/// Returns `true` if decoding the param can fail because one of its integers does not fit
+/// in its native Rust type.
+fn can_overflow(input: &ParamType, options: &Options) -> bool {
     match input {
-        ParamType::Address
-        | ParamType::Int(_)
-        | ParamType::Uint(_)
-        | ParamType::Bool
-        | ParamType::FixedBytes(_) => {
-            fixed_data_size(input).expect("not dynamic, will always be Some(_)")
-        }
-        // FixedArray with dynamic element becomes "dynamic" so we have
-        // an initial data offset (32) plus the minimal data size of the sub type multipled
-        // by number of element in the fixed array (count * min_data_size(sub_type)).
-        //
-        // If the sub type is not dynamic, we use its fixed data size.
-        ParamType::FixedArray(ref sub_type, count) => match sub_type.is_dynamic() {
-            true => 32 + count * min_data_size(sub_type),
-            false => fixed_data_size(input).expect("not dynamic, will always be Some(_)"),
-        },
-        // Those are dynamic type meaning there is first an offset where to find the data written (32 bytes)
-        // and then minimally a length (32 bytes) so minimum size is `size(offset) + size(length)` which is
-        // `32 + 32`.
-        ParamType::Bytes | ParamType::String | ParamType::Array(_) => 32 + 32,
-        ParamType::Tuple(ref types) => types.iter().map(min_data_size).sum(),
+        ParamType::Int(_) | ParamType::Uint(_) => native_int_type(input, options).is_some(),
+        ParamType::Array(ref kind) | ParamType::FixedArray(ref kind, _) => {
+            can_overflow(kind, options)
+        }
+        ParamType::Tuple(ref types) => types.iter().any(|kind| can_overflow(kind, options)),
+        _ => false,
     }
 }
 
@@ -248,13 +344,43 @@ fn min_data_size(input: &ParamType) -> usize {
 //     }
 // }
 
-fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::TokenStream {
+fn to_token(
+    name: &proc_macro2::TokenStream,
+    kind: &ParamType,
+    components: &Components,
+    options: &Options,
+) -> proc_macro2::TokenStream {
     match *kind {
+        ParamType::Address if options.address_types => {
+            quote! { ethabi::Token::Address(ethabi::Address::from(#name.0)) }
+        }
         ParamType::Address => {
             quote! { ethabi::Token::Address(ethabi::Address::from_slice(&#name)) }
         }
         ParamType::Bytes => quote! { ethabi::Token::Bytes(#name.clone()) },
+        ParamType::FixedBytes(32) if options.address_types => {
+            quote! { ethabi::Token::FixedBytes(#name.0.to_vec()) }
+        }
         ParamType::FixedBytes(_) => quote! { ethabi::Token::FixedBytes(#name.as_ref().to_vec()) },
+        ParamType::Int(_) if native_int_type(kind, options).is_some() => {
+            let value = match native_int_type(kind, options) {
+                Some(int_type) if int_type == "i128" => quote! { #name.to_owned() },
+                _ => quote! { i128::from(#name.to_owned()) },
+            };
+
+            quote! {
+                {
+                    let v = #value;
+                    let mut full_signed_bytes = [if v < 0 { 0xff } else { 0x00 }; 32];
+                    full_signed_bytes[16..].copy_from_slice(&v.to_be_bytes());
+
+                    ethabi::Token::Int(ethabi::Int::from_big_endian(full_signed_bytes.as_ref()))
+                }
+            }
+        }
+        ParamType::Uint(_) if native_int_type(kind, options).is_some() => {
+            quote! { ethabi::Token::Uint(ethabi::Uint::from(#name.to_owned())) }
+        }
         ParamType::Int(_) => {
             // The check non_full_signed_bytes[0] & 0x80 == 0x80 is checking if the leftmost bit of the first byte is set.
             // If it is, the number is negative and full_signed_bytes_init is set to 0xff. Otherwise, it's set to 0x00.
@@ -288,7 +414,7 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
         ParamType::String => quote! { ethabi::Token::String(#name.clone()) },
         ParamType::Array(ref kind) => {
             let inner_name = quote! { inner };
-            let inner_loop = to_token(&inner_name, kind);
+            let inner_loop = to_token(&inner_name, kind, components.child(0), options);
             quote! {
                 // note the double {{
                 {
@@ -299,7 +425,7 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
         }
         ParamType::FixedArray(ref kind, _) => {
             let inner_name = quote! { inner };
-            let inner_loop = to_token(&inner_name, kind);
+            let inner_loop = to_token(&inner_name, kind, components.child(0), options);
             quote! {
                 // note the double {{
                 {
@@ -309,17 +435,32 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
             }
         }
         ParamType::Tuple(ref types) => {
-            let inner_names = (0..types.len())
-                .map(|i| {
-                    let i = Index::from(i);
-                    quote! { #name.#i }
-                })
-                .collect::<Vec<_>>();
+            let inner_names = match components.struct_path() {
+                Some(_) => components
+                    .field_names()
+                    .into_iter()
+                    .map(|field| quote! { #name.#field })
+                    .collect::<Vec<_>>(),
+                None => (0..types.len())
+                    .map(|i| {
+                        let i = Index::from(i);
+                        quote! { #name.#i }
+                    })
+                    .collect::<Vec<_>>(),
+            };
 
             let inner_tokens = types
                 .iter()
                 .zip(&inner_names)
-                .map(|(kind, inner_name)| to_token(&inner_name.to_token_stream(), kind))
+                .enumerate()
+                .map(|(i, (kind, inner_name))| {
+                    to_token(
+                        &inner_name.to_token_stream(),
+                        kind,
+                        components.child(i),
+                        options,
+                    )
+                })
                 .collect::<Vec<_>>();
 
             quote! {
@@ -331,14 +472,43 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
     }
 }
 
-fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+/// Names of the event, function, error or constructor being decoded and of its param, reported
+/// in the `substreams_ethereum::DecodeError` returned by the generated code.
+#[derive(Clone, Copy)]
+struct DecodeContext<'a> {
+    item: &'a str,
+    param: &'a str,
+}
+
+impl<'a> DecodeContext<'a> {
+    fn new(item: &'a str, param: &'a str) -> Self {
+        DecodeContext {
+            item,
+            param: param.trim_start_matches("r#"),
+        }
+    }
+}
+
+fn from_token(
+    kind: &ParamType,
+    token: &proc_macro2::TokenStream,
+    components: &Components,
+    options: &Options,
+    context: DecodeContext,
+) -> proc_macro2::TokenStream {
     match *kind {
+        ParamType::Address if options.address_types => {
+            quote! { substreams_ethereum::Address(#token.into_address().expect(INTERNAL_ERR).to_fixed_bytes()) }
+        }
         ParamType::Address => {
             quote! { #token.into_address().expect(INTERNAL_ERR).as_bytes().to_vec() }
         }
         ParamType::Bytes => {
             quote! { #token.into_bytes().expect(INTERNAL_ERR) }
         }
+        ParamType::FixedBytes(32) if options.address_types => {
+            quote! { substreams_ethereum::H256(#token.into_fixed_bytes().expect(INTERNAL_ERR).try_into().expect(INTERNAL_ERR)) }
+        }
         ParamType::FixedBytes(size) => {
             let size: syn::Index = size.into();
             quote! {
@@ -350,6 +520,39 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
                 }
             }
         }
+        ParamType::Int(size) if native_int_type(kind, options).is_some() => {
+            let int_type = native_int_type(kind, options);
+            let value = native_int_value(int_type.as_ref());
+            let overflow = overflow_error(kind, context);
+
+            // A negative value is stored in two's complement, its bitwise complement is the
+            // absolute value minus one which must fit in `size - 1` bits like positive values.
+            quote! {
+                {
+                    let v = #token.into_int().expect(INTERNAL_ERR);
+                    let magnitude = if v.bit(255) { !v } else { v };
+                    if magnitude.bits() >= #size {
+                        return Err(#overflow);
+                    }
+                    #value
+                }
+            }
+        }
+        ParamType::Uint(size) if native_int_type(kind, options).is_some() => {
+            let int_type = native_int_type(kind, options);
+            let value = native_int_value(int_type.as_ref());
+            let overflow = overflow_error(kind, context);
+
+            quote! {
+                {
+                    let v = #token.into_uint().expect(INTERNAL_ERR);
+                    if v.bits() > #size {
+                        return Err(#overflow);
+                    }
+                    #value
+                }
+            }
+        }
         ParamType::Int(_) => quote! {
             {
                 let mut v = [0 as u8; 32];
@@ -366,18 +569,41 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
         },
         ParamType::Bool => quote! { #token.into_bool().expect(INTERNAL_ERR) },
         ParamType::String => quote! { #token.into_string().expect(INTERNAL_ERR) },
+        // Elements that can overflow return early with an error, the closure must then return
+        // a `Result` that is collected and propagated.
+        ParamType::Array(ref kind) if can_overflow(kind, options) => {
+            let inner = quote! { inner };
+            let inner_loop = from_token(kind, &inner, components.child(0), options, context);
+            quote! {
+                #token.into_array().expect(INTERNAL_ERR).into_iter()
+                    .map(|#inner| -> Result<_, substreams_ethereum::DecodeError> { Ok(#inner_loop) })
+                    .collect::<Result<_, substreams_ethereum::DecodeError>>()?
+            }
+        }
         ParamType::Array(ref kind) => {
             let inner = quote! { inner };
-            let inner_loop = from_token(kind, &inner);
+            let inner_loop = from_token(kind, &inner, components.child(0), options, context);
             quote! {
                 #token.into_array().expect(INTERNAL_ERR).into_iter()
                     .map(|#inner| #inner_loop)
                     .collect()
             }
         }
+        ParamType::FixedArray(ref kind, size) if can_overflow(kind, options) => {
+            let inner = quote! { inner };
+            let inner_loop = from_token(kind, &inner, components.child(0), options, context);
+            let to_array = vec![quote! { iter.next().expect(INTERNAL_ERR)? }; size];
+            quote! {
+                {
+                    let mut iter = #token.into_fixed_array().expect(INTERNAL_ERR).into_iter()
+                        .map(|#inner| -> Result<_, substreams_ethereum::DecodeError> { Ok(#inner_loop) });
+                    [#(#to_array),*]
+                }
+            }
+        }
         ParamType::FixedArray(ref kind, size) => {
             let inner = quote! { inner };
-            let inner_loop = from_token(kind, &inner);
+            let inner_loop = from_token(kind, &inner, components.child(0), options, context);
             let to_array = vec![quote! { iter.next().expect(INTERNAL_ERR) }; size];
             quote! {
                 {
@@ -390,32 +616,52 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
         ParamType::Tuple(ref types) => {
             let conversion = types.iter().enumerate().map(|(i, t)| {
                 let inner = quote! { tuple_elements[#i].clone() };
-                let inner_conversion = from_token(t, &inner);
+                let inner_conversion = from_token(t, &inner, components.child(i), options, context);
                 quote! { #inner_conversion }
             });
 
-            quote! {
-                {
-                    let tuple_elements = #token.into_tuple().expect(INTERNAL_ERR);
-                    (#(#conversion,)*)
+            match components.struct_path() {
+                Some(struct_path) => {
+                    let fields = components.field_names();
+
+                    quote! {
+                        {
+                            let tuple_elements = #token.into_tuple().expect(INTERNAL_ERR);
+                            #struct_path {
+                                #(#fields: #conversion),*
+                            }
+                        }
+                    }
                 }
+                None => quote! {
+                    {
+                        let tuple_elements = #token.into_tuple().expect(INTERNAL_ERR);
+                        (#(#conversion,)*)
+                    }
+                },
             }
         }
     }
 }
 
 fn decode_topic(
-    name: &String,
     kind: &ParamType,
     data_token: &proc_macro2::TokenStream,
+    components: &Components,
+    options: &Options,
+    context: DecodeContext,
 ) -> proc_macro2::TokenStream {
-    let error_msg = format!(
-        "unable to decode param '{}' from topic of type '{}': {{:?}}",
-        name, kind
-    );
+    let (item, param) = (context.item, context.param);
+    let map_err = quote! {
+        .map_err(|e| substreams_ethereum::DecodeError::Abi {
+            item: #item.into(),
+            param: Some(#param.into()),
+            reason: e.to_string(),
+        })?
+    };
 
     match kind {
-        ParamType::Int(_) => {
+        ParamType::Int(_) if native_int_type(kind, options).is_none() => {
             quote! {
                 substreams::scalar::BigInt::from_signed_bytes_be(#data_token)
             }
@@ -425,7 +671,7 @@ fn decode_topic(
 
             quote! {
                 ethabi::decode(&[#syntax_type], #data_token)
-                    .map_err(|e| format!(#error_msg, e))?
+                    #map_err
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_fixed_bytes()
@@ -437,12 +683,27 @@ fn decode_topic(
             let syntax_type = to_syntax_string(kind);
             let decode_topic = quote! {
                         ethabi::decode(&[#syntax_type], #data_token)
-                        .map_err(|e| format!(#error_msg, e))?
+                        #map_err
                         .pop()
                         .expect(INTERNAL_ERR)
             };
 
-            from_token(kind, &decode_topic)
+            from_token(kind, &decode_topic, components, options, context)
+        }
+    }
+}
+
+/// Generates the `DecodeError::Overflow` of a value `v` not fitting in the native type of `kind`.
+fn overflow_error(kind: &ParamType, context: DecodeContext) -> proc_macro2::TokenStream {
+    let (item, param) = (context.item, context.param);
+    let kind = kind.to_string();
+
+    quote! {
+        substreams_ethereum::DecodeError::Overflow {
+            item: #item.into(),
+            param: #param.into(),
+            kind: #kind,
+            value: format!("{:#x}", v),
         }
     }
 }
@@ -469,15 +730,23 @@ fn param_names(inputs: &[Param]) -> Vec<syn::Ident> {
 //         .collect()
 // }
 
-fn get_output_kinds(outputs: &[Param]) -> proc_macro2::TokenStream {
+fn get_output_kinds(
+    outputs: &[Param],
+    components: &[Components],
+    options: &Options,
+) -> proc_macro2::TokenStream {
     match outputs.len() {
         0 => quote! {()},
         1 => {
-            let t = rust_type(&outputs[0].kind);
+            let t = rust_type(&outputs[0].kind, &components[0], options);
             quote! { #t }
         }
         _ => {
-            let outs: Vec<_> = outputs.iter().map(|param| rust_type(&param.kind)).collect();
+            let outs: Vec<_> = outputs
+                .iter()
+                .zip(components.iter())
+                .map(|(param, components)| rust_type(&param.kind, components, options))
+                .collect();
             quote! { (#(#outs),*) }
         }
     }
@@ -496,10 +765,6 @@ fn rust_variable(name: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use ethabi::ParamType;
-
-    use crate::{fixed_data_size, min_data_size};
-
     #[test]
     fn from_firehose_types_to_ethabi_token() {
         use substreams::hex;
@@ -509,54 +774,4 @@ mod tests {
         // Compilation is enough for those tests
         ethabi::Token::Address(ethabi::Address::from_slice(firehose_address.as_ref()));
     }
-
-    #[test]
-    fn it_fixed_data_size_works() {
-        let inputs: Vec<(&str, ParamType, Option<usize>)> = vec![
-            (
-                "tuple(address)",
-                ParamType::Tuple(vec![ParamType::Address]),
-                Some(32),
-            ),
-            (
-                "bool[2]",
-                ParamType::FixedArray(Box::new(ParamType::Bool), 2),
-                Some(64),
-            ),
-            (
-                "string[2]",
-                ParamType::FixedArray(Box::new(ParamType::String), 2),
-                None,
-            ),
-        ];
-
-        for (name, actual, expected) in inputs {
-            assert_eq!(fixed_data_size(&actual), expected, "test case {}", name);
-        }
-    }
-
-    #[test]
-    fn it_min_data_size_works() {
-        let inputs: Vec<(&str, ParamType, usize)> = vec![
-            (
-                "tuple(address)",
-                ParamType::Tuple(vec![ParamType::Address]),
-                32,
-            ),
-            (
-                "bool[2]",
-                ParamType::FixedArray(Box::new(ParamType::Bool), 2),
-                2 * 32,
-            ),
-            (
-                "string[2]",
-                ParamType::FixedArray(Box::new(ParamType::String), 2),
-                32 + (2 * 32) + (2 * 32),
-            ),
-        ];
-
-        for (name, actual, expected) in inputs {
-            assert_eq!(min_data_size(&actual), expected, "test case {}", name);
-        }
-    }
 }
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::Value;
+
+use crate::artifact::Artifact;
+
+/// NatSpec documentation of the contract's items, looked up by canonical signature in the
+/// `userdoc` and `devdoc` of the compilation artifact.
+pub(crate) struct NatSpec<'a> {
+    devdoc: &'a Value,
+    userdoc: &'a Value,
+}
+
+/// NatSpec documentation of a function, an event or an error.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Docs {
+    /// The `@notice` text, from the `userdoc`.
+    pub(crate) notice: Option<String>,
+    /// The `@dev` text, from the `devdoc`.
+    pub(crate) details: Option<String>,
+    /// The `@param` texts by param name, from the `devdoc`.
+    pub(crate) params: BTreeMap<String, String>,
+}
+
+impl<'a> From<&'a Artifact> for NatSpec<'a> {
+    fn from(artifact: &'a Artifact) -> Self {
+        NatSpec {
+            devdoc: artifact.devdoc.as_ref().unwrap_or(&Value::Null),
+            userdoc: artifact.userdoc.as_ref().unwrap_or(&Value::Null),
+        }
+    }
+}
+
+impl<'a> NatSpec<'a> {
+    pub(crate) fn function(&self, signature: &str) -> Docs {
+        self.docs("methods", signature)
+    }
+
+    /// The compiler documents the constructor among the functions, under the `constructor` key.
+    pub(crate) fn constructor(&self) -> Docs {
+        self.docs("methods", "constructor")
+    }
+
+    pub(crate) fn event(&self, signature: &str) -> Docs {
+        self.docs("events", signature)
+    }
+
+    pub(crate) fn error(&self, signature: &str) -> Docs {
+        self.docs("errors", signature)
+    }
+
+    fn docs(&self, section: &str, signature: &str) -> Docs {
+        let devdoc = entry(&self.devdoc[section][signature]);
+        let userdoc = entry(&self.userdoc[section][signature]);
+
+        Docs {
+            notice: userdoc["notice"].as_str().map(str::to_string),
+            details: devdoc["details"].as_str().map(str::to_string),
+            params: devdoc["params"]
+                .as_object()
+                .map(|params| {
+                    params
+                        .iter()
+                        .filter_map(|(name, doc)| Some((name.clone(), doc.as_str()?.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Errors can be declared more than once with the same signature, the compiler lists the
+/// documentation of each declaration, the first one is used.
+fn entry(value: &Value) -> &Value {
+    match value {
+        Value::Array(entries) => entries.first().unwrap_or(&Value::Null),
+        value => value,
+    }
+}
+
+impl Docs {
+    /// Generates the `///` comments of an item: the notice and the details paragraphs,
+    /// followed by the always present `summary`, e.g. the item's signature and selector.
+    pub(crate) fn item(&self, summary: &str) -> TokenStream {
+        let paragraphs: Vec<&str> = [self.notice.as_deref(), self.details.as_deref()]
+            .into_iter()
+            .flatten()
+            .chain(std::iter::once(summary))
+            .collect();
+
+        let lines: Vec<_> = paragraphs
+            .iter()
+            .enumerate()
+            .flat_map(|(index, paragraph)| {
+                let separator = match index {
+                    0 => None,
+                    _ => Some(String::new()),
+                };
+
+                separator.into_iter().chain(doc_lines(paragraph))
+            })
+            .collect();
+
+        quote! { #(#[doc = #lines])* }
+    }
+
+    /// Generates the `///` comments of the param `name`, empty when it's not documented.
+    pub(crate) fn param(&self, name: &str) -> TokenStream {
+        let lines: Vec<_> = self
+            .params
+            .get(name)
+            .map(|doc| doc_lines(doc).collect())
+            .unwrap_or_default();
+
+        quote! { #(#[doc = #lines])* }
+    }
+}
+
+/// Splits `text` into doc comment lines, prefixed by a space like `///` comments are.
+fn doc_lines(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.trim()
+        .lines()
+        .map(|line| format!(" {}", line.trim()).trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use serde_json::json;
+
+    use crate::{artifact::Artifact, assertions::assert_ast_eq};
+
+    use super::NatSpec;
+
+    #[test]
+    fn test_docs() {
+        let artifact = Artifact {
+            devdoc: Some(json!({
+                "kind": "dev",
+                "methods": {
+                    "constructor": { "params": { "owner": "Initial owner" } },
+                    "mint(address,uint256)": {
+                        "details": "Only callable by the owner.\nEmits a {Minted} event.",
+                        "params": { "to": "Receiver of the tokens" }
+                    }
+                },
+                "errors": {
+                    "Unauthorized(address)": [{ "params": { "caller": "The caller" } }]
+                }
+            })),
+            userdoc: Some(json!({
+                "kind": "user",
+                "methods": { "mint(address,uint256)": { "notice": "Mints tokens" } }
+            })),
+            ..Default::default()
+        };
+        let natspec = NatSpec::from(&artifact);
+
+        let docs = natspec.function("mint(address,uint256)");
+        assert_ast_eq(
+            docs.item("Function `mint(address,uint256)`."),
+            quote! {
+                #[doc = " Mints tokens"]
+                #[doc = ""]
+                #[doc = " Only callable by the owner."]
+                #[doc = " Emits a {Minted} event."]
+                #[doc = ""]
+                #[doc = " Function `mint(address,uint256)`."]
+            },
+        );
+        assert_ast_eq(
+            docs.param("to"),
+            quote! { #[doc = " Receiver of the tokens"] },
+        );
+        assert_ast_eq(docs.param("amount"), quote! {});
+
+        let docs = natspec.error("Unauthorized(address)");
+        assert_ast_eq(docs.param("caller"), quote! { #[doc = " The caller"] });
+
+        let docs = natspec.constructor();
+        assert_ast_eq(docs.param("owner"), quote! { #[doc = " Initial owner"] });
+
+        assert_ast_eq(
+            natspec.event("Minted(address,uint256)").item("Event."),
+            quote! { #[doc = " Event."] },
+        );
+    }
+}
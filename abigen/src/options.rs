@@ -0,0 +1,530 @@
+use std::collections::BTreeMap;
+
+use anyhow::{format_err, Context};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parser;
+
+use crate::canonical_signature;
+
+/// Options tweaking the code generated for a contract, see [crate::build::Abigen] for
+/// the builder methods setting them.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Use `substreams_ethereum::Address` for `address` params and `substreams_ethereum::H256`
+    /// for `bytes32` params instead of `Vec<u8>` and `[u8; 32]`.
+    pub address_types: bool,
+
+    /// Use Rust primitive integers (`u8` to `u128` and `i8` to `i128`) for `uintN` and `intN`
+    /// params up to 128 bits instead of `substreams::scalar::BigInt`.
+    pub native_ints: bool,
+
+    /// Extra derives and attributes added to every generated struct (events, functions,
+    /// errors, constructor and Solidity structs).
+    pub attributes: Attributes,
+
+    /// Extra derives and attributes added to the struct of the events with the given ABI name.
+    pub event_attributes: BTreeMap<String, Attributes>,
+
+    /// Extra derives and attributes added to the struct of the functions with the given ABI name.
+    pub function_attributes: BTreeMap<String, Attributes>,
+
+    /// Paths imported in every generated module, e.g. `serde::{Deserialize, Serialize}`,
+    /// for derives and attributes not written with their full path.
+    pub imports: Vec<String>,
+
+    /// Generate the RPC helpers (`call` and `RPCDecodable`) for every function with outputs, not
+    /// only the `view` and `pure` ones which are the only ones meant to be called through
+    /// `eth_call`.
+    pub rpc_all_functions: bool,
+
+    /// Name overloaded functions and events after their params types (e.g.
+    /// `SafeTransferFromAddressAddressUint256Bytes`) instead of suffixing them with an index
+    /// which depends on the ordering of the ABI.
+    pub typed_overload_names: bool,
+
+    /// Rust names of the functions with the given canonical signature, e.g.
+    /// `safeTransferFrom(address,address,uint256,bytes)` named `SafeTransferFromWithData`.
+    pub function_renames: BTreeMap<String, String>,
+
+    /// Rust names of the events with the given canonical signature, e.g.
+    /// `Transfer(address,address,uint256)` named `TransferErc20`.
+    pub event_renames: BTreeMap<String, String>,
+
+    /// Events to generate, all of them by default.
+    pub events: Filter,
+
+    /// Functions to generate, all of them by default.
+    pub functions: Filter,
+
+    /// Generate a `.proto` file with one message per event, function and Solidity struct, along
+    /// with the `From` conversions of the generated structs into the prost messages.
+    pub proto: Option<ProtoOptions>,
+}
+
+/// Options of the protobuf messages generation.
+#[derive(Debug, Clone, Default)]
+pub struct ProtoOptions {
+    /// Package of the generated `.proto` file, e.g. `contract.v1`.
+    pub package: String,
+
+    /// Rust path of the module holding the prost messages generated from the `.proto` file,
+    /// e.g. `crate::pb::contract::v1`.
+    pub rust_module: String,
+
+    /// Represent addresses as `0x` prefixed hexadecimal strings instead of bytes.
+    pub hex_addresses: bool,
+}
+
+/// Selection of the events or functions to generate, by ABI name (all overloads) or by
+/// canonical signature (e.g. `Transfer(address,address,uint256)`).
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    /// Only generate the items matching one of these, all items when empty.
+    pub include: Vec<String>,
+
+    /// Don't generate the items matching one of these, even if included.
+    pub exclude: Vec<String>,
+}
+
+impl Filter {
+    /// Returns whether the item named `name` with the canonical `signature` is selected.
+    pub(crate) fn selects(&self, name: &str, signature: &str) -> bool {
+        let matches =
+            |pattern: &String| pattern == name || normalize_signature(pattern) == signature;
+
+        (self.include.is_empty() || self.include.iter().any(matches))
+            && !self.exclude.iter().any(matches)
+    }
+
+    /// Checks that every name or signature of the filter matches one of the items given by
+    /// name and canonical signature.
+    fn validate(&self, kind: &str, items: &[(String, String)]) -> Result<(), anyhow::Error> {
+        for pattern in self.include.iter().chain(self.exclude.iter()) {
+            let found = items.iter().any(|(name, signature)| {
+                pattern == name || &normalize_signature(pattern) == signature
+            });
+
+            if !found {
+                return Err(format_err!(
+                    "cannot filter {} `{}`, no {} of the contract has this name or signature",
+                    kind,
+                    pattern,
+                    kind
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Extra derives and attributes added to generated structs on top of the default
+/// `#[derive(Debug, Clone, PartialEq)]`.
+#[derive(Debug, Clone, Default)]
+pub struct Attributes {
+    /// Derive paths, e.g. `Eq`, `Hash` or `serde::Serialize`.
+    pub derives: Vec<String>,
+
+    /// Outer attributes, with or without the surrounding `#[...]`, e.g.
+    /// `serde(rename_all = "camelCase")`.
+    pub attributes: Vec<String>,
+}
+
+const DEFAULT_DERIVES: [&str; 3] = ["Debug", "Clone", "PartialEq"];
+
+impl Options {
+    /// Checks that the configured derives, attributes and imports are valid Rust, the code
+    /// generation relies on it.
+    pub(crate) fn validate(&self) -> Result<(), anyhow::Error> {
+        let all_attributes = std::iter::once(&self.attributes)
+            .chain(self.event_attributes.values())
+            .chain(self.function_attributes.values());
+
+        for attributes in all_attributes {
+            for derive in &attributes.derives {
+                syn::parse_str::<syn::Path>(derive)
+                    .with_context(|| format!("invalid derive `{}`", derive))?;
+            }
+
+            for attribute in &attributes.attributes {
+                syn::Attribute::parse_outer
+                    .parse_str(&outer_attribute(attribute))
+                    .with_context(|| format!("invalid attribute `{}`", attribute))?;
+            }
+        }
+
+        if let Some(proto) = &self.proto {
+            let valid_package = proto
+                .package
+                .split('.')
+                .all(|part| syn::parse_str::<syn::Ident>(part).is_ok());
+            if !valid_package {
+                return Err(format_err!(
+                    "invalid protobuf package `{}`, see `Abigen::proto`",
+                    proto.package
+                ));
+            }
+
+            syn::parse_str::<syn::Path>(&proto.rust_module)
+                .with_context(|| format!("invalid protobuf Rust module `{}`", proto.rust_module))?;
+        }
+
+        for name in self
+            .function_renames
+            .values()
+            .chain(self.event_renames.values())
+        {
+            syn::parse_str::<syn::Ident>(name)
+                .with_context(|| format!("invalid rename `{}`, expected an identifier", name))?;
+        }
+
+        for import in &self.imports {
+            import
+                .parse::<TokenStream>()
+                .map_err(|_| format_err!("invalid import `{}`", import))?;
+        }
+
+        Ok(())
+    }
+
+    /// Generates the `#[derive(...)]` and extra attributes of a struct, `item` being the
+    /// attributes configured for the specific event or function, if any.
+    pub(crate) fn struct_attributes(&self, item: Option<&Attributes>) -> TokenStream {
+        let mut derives: Vec<String> = DEFAULT_DERIVES.iter().map(|d| d.to_string()).collect();
+        let mut attributes: Vec<TokenStream> = vec![];
+
+        for extra in std::iter::once(&self.attributes).chain(item) {
+            for derive in &extra.derives {
+                let path = syn::parse_str::<syn::Path>(derive).expect("derives are validated");
+                let normalized = quote! { #path }.to_string();
+                let default_derive = path.segments.len() == 1
+                    && DEFAULT_DERIVES.contains(&path.segments[0].ident.to_string().as_str());
+
+                if !default_derive && !derives.contains(&normalized) {
+                    derives.push(normalized);
+                }
+            }
+
+            for attribute in &extra.attributes {
+                let attribute = syn::Attribute::parse_outer
+                    .parse_str(&outer_attribute(attribute))
+                    .expect("attributes are validated");
+
+                attributes.push(quote! { #(#attribute)* });
+            }
+        }
+
+        let derives: Vec<syn::Path> = derives
+            .iter()
+            .map(|derive| syn::parse_str(derive).expect("derives are validated"))
+            .collect();
+
+        quote! {
+            #[derive(#(#derives),*)]
+            #(#attributes)*
+        }
+    }
+
+    /// Checks that every renamed or filtered function and event exists in the `contract`, so
+    /// that a typo in a name or a signature doesn't go unnoticed.
+    pub(crate) fn validate_selection(
+        &self,
+        contract: &ethabi::Contract,
+    ) -> Result<(), anyhow::Error> {
+        let functions: Vec<_> = contract
+            .functions()
+            .map(|f| {
+                let signature =
+                    canonical_signature(&f.name, f.inputs.iter().map(|param| &param.kind));
+                (f.name.clone(), signature)
+            })
+            .collect();
+        let events: Vec<_> = contract
+            .events()
+            .map(|e| {
+                let signature =
+                    canonical_signature(&e.name, e.inputs.iter().map(|param| &param.kind));
+                (e.name.clone(), signature)
+            })
+            .collect();
+
+        for (kind, renames, items) in [
+            ("function", &self.function_renames, &functions),
+            ("event", &self.event_renames, &events),
+        ] {
+            for signature in renames.keys() {
+                let signature = normalize_signature(signature);
+                if !items.iter().any(|(_, item)| item == &signature) {
+                    return Err(format_err!(
+                        "cannot rename {} `{}`, no {} of the contract has this signature",
+                        kind,
+                        signature,
+                        kind
+                    ));
+                }
+            }
+        }
+
+        for (kind, attributes, items) in [
+            ("function", &self.function_attributes, &functions),
+            ("event", &self.event_attributes, &events),
+        ] {
+            for name in attributes.keys() {
+                if !items.iter().any(|(item, _)| item == name) {
+                    return Err(format_err!(
+                        "cannot add attributes to {} `{}`, no {} of the contract has this name",
+                        kind,
+                        name,
+                        kind
+                    ));
+                }
+            }
+        }
+
+        self.functions.validate("function", &functions)?;
+        self.events.validate("event", &events)?;
+
+        Ok(())
+    }
+
+    /// Returns the `contract` without the events and functions that are not selected by the
+    /// filters.
+    pub(crate) fn filter_contract(&self, contract: &ethabi::Contract) -> ethabi::Contract {
+        let mut contract = contract.clone();
+        for events in contract.events.values_mut() {
+            events.retain(|e| {
+                let signature =
+                    canonical_signature(&e.name, e.inputs.iter().map(|param| &param.kind));
+                self.events.selects(&e.name, &signature)
+            });
+        }
+        contract.events.retain(|_, events| !events.is_empty());
+
+        for functions in contract.functions.values_mut() {
+            functions.retain(|f| {
+                let signature =
+                    canonical_signature(&f.name, f.inputs.iter().map(|param| &param.kind));
+                self.functions.selects(&f.name, &signature)
+            });
+        }
+        contract
+            .functions
+            .retain(|_, functions| !functions.is_empty());
+
+        contract
+    }
+
+    /// Removes the events and functions that are not selected by the filters from the JSON
+    /// `abi`, so that the Solidity structs only they use are not generated either.
+    pub(crate) fn filter_abi(&self, abi: &serde_json::Value) -> serde_json::Value {
+        let entries = match abi.as_array() {
+            Some(entries) => entries,
+            None => return abi.clone(),
+        };
+
+        let selected = entries
+            .iter()
+            .filter(
+                |entry| match entry.get("type").and_then(|kind| kind.as_str()) {
+                    Some("event") => {
+                        match serde_json::from_value::<ethabi::Event>((*entry).clone()) {
+                            Ok(e) => {
+                                let signature = canonical_signature(
+                                    &e.name,
+                                    e.inputs.iter().map(|param| &param.kind),
+                                );
+                                self.events.selects(&e.name, &signature)
+                            }
+                            Err(_) => true,
+                        }
+                    }
+                    Some("function") => {
+                        match serde_json::from_value::<ethabi::Function>((*entry).clone()) {
+                            Ok(f) => {
+                                let signature = canonical_signature(
+                                    &f.name,
+                                    f.inputs.iter().map(|param| &param.kind),
+                                );
+                                self.functions.selects(&f.name, &signature)
+                            }
+                            Err(_) => true,
+                        }
+                    }
+                    _ => true,
+                },
+            )
+            .cloned()
+            .collect();
+
+        serde_json::Value::Array(selected)
+    }
+
+    /// Returns the configured name of the function with the canonical `signature`, if any.
+    pub(crate) fn function_rename(&self, signature: &str) -> Option<&String> {
+        find_rename(&self.function_renames, signature)
+    }
+
+    /// Returns the configured name of the event with the canonical `signature`, if any.
+    pub(crate) fn event_rename(&self, signature: &str) -> Option<&String> {
+        find_rename(&self.event_renames, signature)
+    }
+
+    /// Returns the paths of the configured imports, to be used in `use` statements.
+    pub(crate) fn imports(&self) -> Vec<TokenStream> {
+        self.imports
+            .iter()
+            .map(|import| import.parse().expect("imports are validated"))
+            .collect()
+    }
+}
+
+fn find_rename<'a>(renames: &'a BTreeMap<String, String>, signature: &str) -> Option<&'a String> {
+    renames
+        .iter()
+        .find(|(renamed, _)| normalize_signature(renamed) == signature)
+        .map(|(_, name)| name)
+}
+
+/// Removes the whitespaces of a signature written by hand, e.g. `transfer(address, uint256)`.
+fn normalize_signature(signature: &str) -> String {
+    signature.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn outer_attribute(attribute: &str) -> String {
+    let attribute = attribute.trim();
+
+    match attribute.starts_with("#[") {
+        true => attribute.to_string(),
+        false => format!("#[{}]", attribute),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::{Attributes, Filter, Options};
+
+    #[test]
+    fn test_struct_attributes() {
+        let options = Options {
+            attributes: Attributes {
+                derives: vec!["Eq".into(), "Clone".into()],
+                attributes: vec![r#"serde(rename_all = "camelCase")"#.into()],
+            },
+            ..Default::default()
+        };
+        let item = Attributes {
+            derives: vec!["Eq".into(), "serde::Serialize".into()],
+            attributes: vec!["#[allow(deprecated)]".into()],
+        };
+
+        assert_eq!(
+            options.struct_attributes(None).to_string(),
+            quote! {
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #[serde(rename_all = "camelCase")]
+            }
+            .to_string()
+        );
+        assert_eq!(
+            options.struct_attributes(Some(&item)).to_string(),
+            quote! {
+                #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+                #[serde(rename_all = "camelCase")]
+                #[allow(deprecated)]
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut options = Options::default();
+        assert!(options.validate().is_ok());
+
+        options.attributes.derives.push("serde::".into());
+        assert!(options.validate().is_err());
+    }
+
+    #[test]
+    fn test_renames() {
+        let contract =
+            crate::signatures::parse_signatures(&["function transfer(address to, uint256 amount)"])
+                .unwrap();
+
+        let mut options = Options::default();
+        options
+            .function_renames
+            .insert("transfer(address, uint256)".into(), "Send".into());
+        assert!(options.validate().is_ok());
+        assert!(options.validate_selection(&contract).is_ok());
+        assert_eq!(
+            options.function_rename("transfer(address,uint256)"),
+            Some(&"Send".to_string())
+        );
+
+        options
+            .event_renames
+            .insert("Transfer(address,uint256)".into(), "Sent".into());
+        assert!(options.validate_selection(&contract).is_err());
+
+        options
+            .function_renames
+            .insert("transfer(address)".into(), "Not an ident".into());
+        assert!(options.validate().is_err());
+    }
+
+    #[test]
+    fn test_item_attributes() {
+        let contract = crate::signatures::parse_signatures(&[
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "function transfer(address to, uint256 amount)",
+        ])
+        .unwrap();
+
+        let mut options = Options::default();
+        options
+            .event_attributes
+            .entry("Transfer".into())
+            .or_default()
+            .derives
+            .push("Hash".into());
+        options
+            .function_attributes
+            .entry("transfer".into())
+            .or_default()
+            .derives
+            .push("Hash".into());
+        assert!(options.validate_selection(&contract).is_ok());
+
+        options
+            .event_attributes
+            .entry("Tranfser".into())
+            .or_default()
+            .derives
+            .push("Hash".into());
+        assert_eq!(
+            options
+                .validate_selection(&contract)
+                .unwrap_err()
+                .to_string(),
+            "cannot add attributes to event `Tranfser`, no event of the contract has this name"
+        );
+    }
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter {
+            include: vec!["transfer".into(), "approve(address, uint256)".into()],
+            exclude: vec!["transfer(address,uint256,bytes)".into()],
+        };
+
+        assert!(filter.selects("transfer", "transfer(address,uint256)"));
+        assert!(!filter.selects("transfer", "transfer(address,uint256,bytes)"));
+        assert!(filter.selects("approve", "approve(address,uint256)"));
+        assert!(!filter.selects("approve", "approve(address)"));
+        assert!(Filter::default().selects("approve", "approve(address)"));
+    }
+}
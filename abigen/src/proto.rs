@@ -0,0 +1,269 @@
+use ethabi::ParamType;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::{structs::Components, to_token, Options};
+
+/// Protobuf message generated for an event, a function or a Solidity struct, along with the
+/// `From` conversion of the generated Rust struct into the message generated by prost.
+pub(crate) struct Message {
+    /// The message definition in the `.proto` file.
+    pub(crate) definition: String,
+    /// The `impl From<RustStruct> for pb::Message` block.
+    pub(crate) conversion: TokenStream,
+}
+
+/// A field of the generated Rust struct converted into a field of the message.
+pub(crate) struct Field<'a> {
+    pub(crate) name: &'a syn::Ident,
+    pub(crate) kind: &'a ParamType,
+    pub(crate) components: &'a Components,
+    /// Indexed dynamic event params only carry the hash of their value.
+    pub(crate) indexed_hash: bool,
+}
+
+/// Protobuf type of a field, `repeated` being tracked apart as it's a field label.
+struct ProtoType {
+    name: String,
+    repeated: bool,
+}
+
+impl Message {
+    /// Creates the message `name` for the Rust struct `rust_struct`, returns `None` when
+    /// protobuf generation is disabled.
+    pub(crate) fn new(
+        rust_struct: &syn::Ident,
+        name: &str,
+        fields: &[Field],
+        options: &Options,
+    ) -> Option<Message> {
+        let proto = options.proto.as_ref()?;
+        let module: syn::Path = syn::parse_str(&proto.rust_module).expect("module is validated");
+        let message = syn::Ident::new(name, Span::call_site());
+
+        let mut definition = format!("message {} {{\n", name);
+        let mut conversions = vec![];
+        for (index, field) in fields.iter().enumerate() {
+            let field_name = field.name;
+            let value = quote! { value.#field_name };
+
+            let (proto_type, conversion) = match field.indexed_hash {
+                true => (scalar("bytes"), quote! { #value.hash }),
+                false => convert(field.kind, field.components, value, options),
+            };
+
+            definition.push_str(&format!(
+                "  {}{} {} = {};\n",
+                if proto_type.repeated { "repeated " } else { "" },
+                proto_type.name,
+                field_name.to_string().trim_start_matches("r#"),
+                index + 1
+            ));
+            conversions.push(quote! { #field_name: #conversion });
+        }
+        definition.push('}');
+
+        Some(Message {
+            definition,
+            conversion: quote! {
+                impl From<#rust_struct> for #module::#message {
+                    fn from(value: #rust_struct) -> Self {
+                        Self {
+                            #(#conversions),*
+                        }
+                    }
+                }
+            },
+        })
+    }
+}
+
+/// Generates the `.proto` file content for the given messages.
+pub(crate) fn generate_file(package: &str, messages: &[&str]) -> String {
+    let mut file = String::from("// Code generated by substreams-ethereum-abigen. DO NOT EDIT.\n");
+    file.push_str("syntax = \"proto3\";\n\n");
+    file.push_str(&format!("package {};\n", package));
+
+    for message in messages {
+        file.push('\n');
+        file.push_str(message);
+        file.push('\n');
+    }
+
+    file
+}
+
+/// Returns the protobuf type of a param of type `kind` along with the expression converting
+/// its Rust `value` into the prost field's value.
+///
+/// Addresses are `bytes` (or `0x` prefixed hexadecimal strings), integers are decimal strings
+/// and Solidity structs are nested messages. Types protobuf cannot represent, like tuples
+/// without a struct or arrays of arrays, are kept ABI encoded in a `bytes` field.
+fn convert(
+    kind: &ParamType,
+    components: &Components,
+    value: TokenStream,
+    options: &Options,
+) -> (ProtoType, TokenStream) {
+    let hex_addresses = options
+        .proto
+        .as_ref()
+        .map(|proto| proto.hex_addresses)
+        .unwrap_or(false);
+
+    match kind {
+        ParamType::Address if hex_addresses => (
+            scalar("string"),
+            quote! { format!("0x{}", substreams::Hex(&#value)) },
+        ),
+        ParamType::Address if options.address_types => {
+            (scalar("bytes"), quote! { #value.to_vec() })
+        }
+        ParamType::Address | ParamType::Bytes => (scalar("bytes"), value),
+        ParamType::FixedBytes(_) => (scalar("bytes"), quote! { #value.to_vec() }),
+        ParamType::Int(_) | ParamType::Uint(_) => (scalar("string"), quote! { #value.to_string() }),
+        ParamType::Bool => (scalar("bool"), value),
+        ParamType::String => (scalar("string"), value),
+        ParamType::Tuple(_) if components.struct_name().is_some() => (
+            scalar(components.struct_name().expect("checked above")),
+            quote! { Some(#value.into()) },
+        ),
+        ParamType::Array(inner) | ParamType::FixedArray(inner, _)
+            if is_repeatable(inner, components.child(0)) =>
+        {
+            let element = quote! { element };
+            let (proto_type, conversion) = match inner.as_ref() {
+                // Repeated message fields are not wrapped in an `Option` by prost
+                ParamType::Tuple(_) => (
+                    scalar(
+                        components
+                            .child(0)
+                            .struct_name()
+                            .expect("checked by is_repeatable"),
+                    ),
+                    quote! { element.into() },
+                ),
+                inner => convert(inner, components.child(0), element.clone(), options),
+            };
+
+            let conversion = match (kind, conversion.to_string() == element.to_string()) {
+                (ParamType::Array(_), true) => value,
+                (_, true) => quote! { #value.to_vec() },
+                (_, false) => quote! { #value.into_iter().map(|#element| #conversion).collect() },
+            };
+
+            (
+                ProtoType {
+                    name: proto_type.name,
+                    repeated: true,
+                },
+                conversion,
+            )
+        }
+        _ => {
+            let token = to_token(&quote! { encoded }, kind, components, options);
+
+            (
+                scalar("bytes"),
+                quote! {
+                    {
+                        let encoded = #value;
+                        ethabi::encode(&[#token])
+                    }
+                },
+            )
+        }
+    }
+}
+
+fn is_repeatable(inner: &ParamType, components: &Components) -> bool {
+    match inner {
+        ParamType::Array(_) | ParamType::FixedArray(_, _) => false,
+        ParamType::Tuple(_) => components.struct_name().is_some(),
+        _ => true,
+    }
+}
+
+fn scalar(name: &str) -> ProtoType {
+    ProtoType {
+        name: name.to_string(),
+        repeated: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethabi::ParamType;
+    use proc_macro2::Span;
+    use quote::quote;
+
+    use crate::{assertions::assert_ast_eq, structs::Components, Options, ProtoOptions};
+
+    use super::{Field, Message};
+
+    #[test]
+    fn test_message_hex_addresses() {
+        let options = Options {
+            proto: Some(ProtoOptions {
+                package: "contract.v1".into(),
+                rust_module: "crate::pb".into(),
+                hex_addresses: true,
+            }),
+            ..Default::default()
+        };
+        let owner = syn::Ident::new("owner", Span::call_site());
+        let amounts = syn::Ident::new("amounts", Span::call_site());
+        let components = Components::default();
+        let amounts_kind = ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2);
+
+        let message = Message::new(
+            &syn::Ident::new("Deposit", Span::call_site()),
+            "Deposit",
+            &[
+                Field {
+                    name: &owner,
+                    kind: &ParamType::Address,
+                    components: &components,
+                    indexed_hash: false,
+                },
+                Field {
+                    name: &amounts,
+                    kind: &amounts_kind,
+                    components: &components,
+                    indexed_hash: false,
+                },
+            ],
+            &options,
+        )
+        .unwrap();
+
+        assert_eq!(
+            message.definition,
+            "message Deposit {\n  string owner = 1;\n  repeated string amounts = 2;\n}"
+        );
+        assert_ast_eq(
+            message.conversion,
+            quote! {
+                impl From<Deposit> for crate::pb::Deposit {
+                    fn from(value: Deposit) -> Self {
+                        Self {
+                            owner: format!("0x{}", substreams::Hex(&value.owner)),
+                            amounts: value
+                                .amounts
+                                .into_iter()
+                                .map(|element| element.to_string())
+                                .collect()
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_message_disabled() {
+        let name = syn::Ident::new("Deposit", Span::call_site());
+
+        assert!(Message::new(&name, "Deposit", &[], &Options::default()).is_none());
+    }
+}
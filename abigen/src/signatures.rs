@@ -0,0 +1,467 @@
+use anyhow::{bail, format_err, Context};
+use ethabi::{
+    AbiError, Constructor, Contract, Event, EventParam, Function, Param, ParamType, StateMutability,
+};
+
+/// A param parsed from a human-readable signature, `indexed` is only meaningful for events.
+struct ParsedParam {
+    name: String,
+    kind: ParamType,
+    indexed: bool,
+}
+
+/// Parses human-readable (ethers style) signatures like
+/// `event Transfer(address indexed from, address indexed to, uint256 value)` or
+/// `function balanceOf(address owner) view returns (uint256)` into a contract.
+///
+/// Supported fragments are `constructor`, `function`, `event`, `error`, `fallback` and `receive`.
+/// Tuples are written either `(uint256,address)` or `tuple(uint256 amount, address to)`, data
+/// locations (`memory`, `calldata`, `storage`) and visibility modifiers are accepted and ignored.
+pub(crate) fn parse_signatures<S: AsRef<str>>(signatures: &[S]) -> Result<Contract, anyhow::Error> {
+    let mut contract = Contract {
+        constructor: None,
+        functions: Default::default(),
+        events: Default::default(),
+        errors: Default::default(),
+        receive: false,
+        fallback: false,
+    };
+
+    for signature in signatures {
+        let signature = signature.as_ref();
+        parse_signature(signature, &mut contract)
+            .with_context(|| format!("invalid signature `{}`", signature))?;
+    }
+
+    Ok(contract)
+}
+
+fn parse_signature(signature: &str, contract: &mut Contract) -> Result<(), anyhow::Error> {
+    let signature = signature.trim().trim_end_matches(';').trim_end();
+    let (keyword, rest) = split_identifier(signature);
+
+    match keyword {
+        "event" => {
+            let (name, rest) = split_name(rest)?;
+            let (params, rest) = split_group(rest)?;
+
+            let anonymous = match rest {
+                "" => false,
+                "anonymous" => true,
+                _ => bail!("unexpected `{}` after event params", rest),
+            };
+
+            let event = Event {
+                name: name.to_string(),
+                inputs: parse_params(params)?
+                    .into_iter()
+                    .map(|param| EventParam {
+                        name: param.name,
+                        kind: param.kind,
+                        indexed: param.indexed,
+                    })
+                    .collect(),
+                anonymous,
+            };
+
+            contract
+                .events
+                .entry(event.name.clone())
+                .or_default()
+                .push(event);
+        }
+        "function" => {
+            let (name, rest) = split_name(rest)?;
+            let (params, mut rest) = split_group(rest)?;
+
+            let mut outputs = vec![];
+            let mut state_mutability = StateMutability::NonPayable;
+            while !rest.is_empty() {
+                let (modifier, remaining) = split_identifier(rest);
+                rest = remaining;
+
+                match modifier {
+                    "returns" => {
+                        let (params, remaining) = split_group(rest)?;
+                        outputs = to_params(parse_params(params)?)?;
+                        rest = remaining;
+                    }
+                    "pure" => state_mutability = StateMutability::Pure,
+                    "view" | "constant" => state_mutability = StateMutability::View,
+                    "payable" => state_mutability = StateMutability::Payable,
+                    "nonpayable" => state_mutability = StateMutability::NonPayable,
+                    "external" | "public" | "internal" | "private" | "virtual" | "override" => {}
+                    _ => bail!(
+                        "unexpected `{}` after function params",
+                        rest_or(modifier, rest)
+                    ),
+                }
+            }
+
+            #[allow(deprecated)]
+            let function = Function {
+                name: name.to_string(),
+                inputs: to_params(parse_params(params)?)?,
+                outputs,
+                constant: None,
+                state_mutability,
+            };
+
+            contract
+                .functions
+                .entry(function.name.clone())
+                .or_default()
+                .push(function);
+        }
+        "error" => {
+            let (name, rest) = split_name(rest)?;
+            let (params, rest) = split_group(rest)?;
+            if !rest.is_empty() {
+                bail!("unexpected `{}` after error params", rest);
+            }
+
+            let error = AbiError {
+                name: name.to_string(),
+                inputs: to_params(parse_params(params)?)?,
+            };
+
+            contract
+                .errors
+                .entry(error.name.clone())
+                .or_default()
+                .push(error);
+        }
+        "constructor" => {
+            let (params, _modifiers) = split_group(rest)?;
+            if contract.constructor.is_some() {
+                bail!("a contract can only have one constructor");
+            }
+
+            contract.constructor = Some(Constructor {
+                inputs: to_params(parse_params(params)?)?,
+            });
+        }
+        "fallback" => contract.fallback = true,
+        "receive" => contract.receive = true,
+        _ => bail!(
+            "expected one of `constructor`, `function`, `event`, `error`, `fallback` or `receive`"
+        ),
+    }
+
+    Ok(())
+}
+
+fn parse_params(input: &str) -> Result<Vec<ParsedParam>, anyhow::Error> {
+    if input.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    split_top_level(input)?
+        .into_iter()
+        .map(|param| parse_param(param.trim()))
+        .collect()
+}
+
+fn parse_param(input: &str) -> Result<ParsedParam, anyhow::Error> {
+    let is_tuple = input.starts_with('(')
+        || input
+            .strip_prefix("tuple")
+            .map(|rest| rest.trim_start().starts_with('('))
+            .unwrap_or(false);
+
+    let (kind, rest) = if is_tuple {
+        let input = input.strip_prefix("tuple").unwrap_or(input).trim_start();
+        let end = matching_paren(input)?;
+        let components = parse_params(&input[1..end])?
+            .into_iter()
+            .map(|param| param.kind)
+            .collect();
+
+        parse_array_suffix(ParamType::Tuple(components), &input[end + 1..])?
+    } else {
+        let end = input
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(input.len());
+
+        parse_array_suffix(elementary_type(&input[..end])?, &input[end..])?
+    };
+
+    let mut name = None;
+    let mut indexed = false;
+    for word in rest.split_whitespace() {
+        match word {
+            "indexed" => indexed = true,
+            "memory" | "calldata" | "storage" | "payable" => {}
+            _ if name.is_none() && is_identifier(word) => name = Some(word.to_string()),
+            _ => bail!("unexpected `{}` in param `{}`", word, input),
+        }
+    }
+
+    Ok(ParsedParam {
+        name: name.unwrap_or_default(),
+        kind,
+        indexed,
+    })
+}
+
+/// Parses an elementary type, contrary to `ethabi::param_type::Reader` unknown types are
+/// rejected instead of being treated as a Solidity `enum`.
+fn elementary_type(kind: &str) -> Result<ParamType, anyhow::Error> {
+    let sized = |prefix: &str, valid: &dyn Fn(usize) -> bool| {
+        kind.strip_prefix(prefix)
+            .and_then(|size| size.parse::<usize>().ok())
+            .filter(|size| valid(*size))
+    };
+
+    Ok(match kind {
+        "address" => ParamType::Address,
+        "bool" => ParamType::Bool,
+        "string" => ParamType::String,
+        "bytes" => ParamType::Bytes,
+        "uint" => ParamType::Uint(256),
+        "int" => ParamType::Int(256),
+        _ => {
+            let is_int_size = |size: usize| size > 0 && size <= 256 && size % 8 == 0;
+
+            if let Some(size) = sized("uint", &is_int_size) {
+                ParamType::Uint(size)
+            } else if let Some(size) = sized("int", &is_int_size) {
+                ParamType::Int(size)
+            } else if let Some(size) = sized("bytes", &|size| size > 0 && size <= 32) {
+                ParamType::FixedBytes(size)
+            } else {
+                bail!("unknown type `{}`", kind)
+            }
+        }
+    })
+}
+
+fn parse_array_suffix(
+    mut kind: ParamType,
+    mut rest: &str,
+) -> Result<(ParamType, &str), anyhow::Error> {
+    while let Some(remaining) = rest.strip_prefix('[') {
+        let end = remaining
+            .find(']')
+            .ok_or_else(|| format_err!("unclosed array bracket"))?;
+
+        kind = match remaining[..end].trim() {
+            "" => ParamType::Array(Box::new(kind)),
+            size => ParamType::FixedArray(
+                Box::new(kind),
+                size.parse()
+                    .map_err(|_| format_err!("invalid array size `{}`", size))?,
+            ),
+        };
+        rest = &remaining[end + 1..];
+    }
+
+    Ok((kind, rest))
+}
+
+fn to_params(params: Vec<ParsedParam>) -> Result<Vec<Param>, anyhow::Error> {
+    params
+        .into_iter()
+        .map(|param| match param.indexed {
+            true => Err(format_err!("only event params can be `indexed`")),
+            false => Ok(Param {
+                name: param.name,
+                kind: param.kind,
+                internal_type: None,
+            }),
+        })
+        .collect()
+}
+
+/// Splits the leading identifier of `input` from the rest, which is returned trimmed.
+fn split_identifier(input: &str) -> (&str, &str) {
+    let end = input
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(input.len());
+
+    (&input[..end], input[end..].trim_start())
+}
+
+fn split_name(input: &str) -> Result<(&str, &str), anyhow::Error> {
+    match split_identifier(input) {
+        ("", _) => bail!("expected a name"),
+        split => Ok(split),
+    }
+}
+
+/// Splits the parenthesized group at the start of `input` from the rest, which is returned
+/// trimmed, the group is returned without its parentheses.
+fn split_group(input: &str) -> Result<(&str, &str), anyhow::Error> {
+    if !input.starts_with('(') {
+        bail!("expected `(`");
+    }
+
+    let end = matching_paren(input)?;
+    Ok((&input[1..end], input[end + 1..].trim()))
+}
+
+/// Returns the index of the parenthesis closing the one `input` starts with.
+fn matching_paren(input: &str) -> Result<usize, anyhow::Error> {
+    let mut depth = 0;
+    for (index, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    bail!("unbalanced parentheses")
+}
+
+/// Splits `input` on the commas that are not nested in a tuple.
+fn split_top_level(input: &str) -> Result<Vec<&str>, anyhow::Error> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&input[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        bail!("unbalanced parentheses");
+    }
+
+    parts.push(&input[start..]);
+    Ok(parts)
+}
+
+fn is_identifier(word: &str) -> bool {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        }
+        _ => false,
+    }
+}
+
+fn rest_or<'a>(modifier: &'a str, rest: &'a str) -> &'a str {
+    match modifier {
+        "" => rest,
+        modifier => modifier,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethabi::{ParamType, StateMutability};
+
+    use super::parse_signatures;
+
+    #[test]
+    fn test_parse_event() {
+        let contract = parse_signatures(&[
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        ])
+        .unwrap();
+
+        let event = contract.event("Transfer").unwrap();
+        assert!(!event.anonymous);
+        assert_eq!(
+            event
+                .inputs
+                .iter()
+                .map(|input| (input.name.as_str(), input.kind.clone(), input.indexed))
+                .collect::<Vec<_>>(),
+            vec![
+                ("from", ParamType::Address, true),
+                ("to", ParamType::Address, true),
+                ("value", ParamType::Uint(256), false),
+            ]
+        );
+        assert_eq!(
+            format!("{:x}", event.signature()),
+            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+    }
+
+    #[test]
+    fn test_parse_function() {
+        let contract = parse_signatures(&[
+            "function balanceOf(address owner) external view returns (uint256)",
+            "function swap((address,uint24) calldata key, int256[2] memory amounts) payable returns (tuple(uint256 a, bool b)[] results);",
+            "function swap(address)",
+        ])
+        .unwrap();
+
+        let balance_of = contract.function("balanceOf").unwrap();
+        assert_eq!(balance_of.state_mutability, StateMutability::View);
+        assert_eq!(balance_of.inputs[0].name, "owner");
+        assert_eq!(balance_of.outputs[0].name, "");
+        assert_eq!(balance_of.outputs[0].kind, ParamType::Uint(256));
+        assert_eq!(balance_of.short_signature(), [0x70, 0xa0, 0x82, 0x31]);
+
+        let swaps = contract.functions_by_name("swap").unwrap();
+        assert_eq!(swaps.len(), 2);
+        assert_eq!(swaps[0].state_mutability, StateMutability::Payable);
+        assert_eq!(
+            swaps[0].signature(),
+            "swap((address,uint24),int256[2]):((uint256,bool)[])"
+        );
+        assert_eq!(swaps[1].state_mutability, StateMutability::NonPayable);
+    }
+
+    #[test]
+    fn test_parse_other_fragments() {
+        let contract = parse_signatures(&[
+            "constructor(address owner, uint supply) payable",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+            "event Paused() anonymous",
+            "receive() external payable",
+            "fallback() external",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            contract.constructor().unwrap().inputs[1].kind,
+            ParamType::Uint(256)
+        );
+        assert_eq!(
+            contract.error("InsufficientBalance").unwrap().inputs.len(),
+            2
+        );
+        assert!(contract.event("Paused").unwrap().anonymous);
+        assert!(contract.receive);
+        assert!(contract.fallback);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for signature in [
+            "struct Order { address maker; }",
+            "event Transfer(address indexed from",
+            "event Transfer(adress from)",
+            "event Transfer(uint7 value)",
+            "function transfer(address indexed to)",
+            "function transfer(address to) returns",
+            "function transfer(address to) mutable",
+            "event (address from)",
+        ] {
+            assert!(
+                parse_signatures(&[signature]).is_err(),
+                "expected `{}` to be invalid",
+                signature
+            );
+        }
+    }
+}
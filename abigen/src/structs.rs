@@ -0,0 +1,473 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use ethabi::ParamType;
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde_json::Value;
+
+use crate::{
+    proto::{Field, Message},
+    rust_type, rust_variable, Options,
+};
+
+static NO_COMPONENTS: Components = Components {
+    struct_name: None,
+    fields: Vec::new(),
+    children: Vec::new(),
+};
+
+/// Struct information of a parameter's type. `ethabi` drops the tuple's `components` names and
+/// `internalType` when it parses the ABI, so we extract them ourselves from the ABI JSON.
+///
+/// The tree follows the shape of the `ethabi::ParamType` it describes, a tuple has one child
+/// per component and an array has a single child describing its elements.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Components {
+    /// Rust struct generated for this tuple, `None` renders a plain Rust tuple
+    struct_name: Option<String>,
+    /// Rust field names of the struct, one per tuple component
+    fields: Vec<String>,
+    children: Vec<Components>,
+}
+
+impl Components {
+    /// Returns the components of the tuple element at `index`, for arrays the `index` is
+    /// always `0` and returns the components of the array's elements.
+    pub(crate) fn child(&self, index: usize) -> &Components {
+        self.children.get(index).unwrap_or(&NO_COMPONENTS)
+    }
+
+    /// Name of the generated struct when this tuple is rendered as a named struct.
+    pub(crate) fn struct_name(&self) -> Option<&str> {
+        self.struct_name.as_deref()
+    }
+
+    /// Path of the generated struct when this tuple is rendered as a named struct.
+    pub(crate) fn struct_path(&self) -> Option<TokenStream> {
+        self.struct_name.as_ref().map(|name| {
+            let name = syn::Ident::new(name, Span::call_site());
+            quote! { structs::#name }
+        })
+    }
+
+    pub(crate) fn field_names(&self) -> Vec<syn::Ident> {
+        self.fields
+            .iter()
+            .map(|field| syn::Ident::new(field, Span::call_site()))
+            .collect()
+    }
+}
+
+struct StructDefinition {
+    kinds: Vec<ParamType>,
+    components: Components,
+}
+
+/// Solidity structs found in the ABI JSON, that is `tuple` params whose `internalType` is
+/// `struct <Name>`. Structs are de-duped by their fully qualified name across functions, events,
+/// errors and the constructor.
+#[derive(Default)]
+pub(crate) struct Structs {
+    definitions: BTreeMap<String, StructDefinition>,
+    params: HashMap<String, Vec<Components>>,
+}
+
+impl Structs {
+    /// Extracts the structs of an ABI JSON, entries that cannot be parsed are ignored as
+    /// `ethabi` reports them already.
+    pub(crate) fn from_json(abi: &Value) -> Structs {
+        let entries = match abi.as_array() {
+            Some(entries) => entries,
+            None => return Structs::default(),
+        };
+
+        let mut qualified_names = BTreeSet::new();
+        for entry in entries {
+            for (json_params, kinds) in entry_params(entry) {
+                for (json_param, kind) in json_params.iter().zip(kinds.iter()) {
+                    collect_qualified_names(json_param, kind, &mut qualified_names);
+                }
+            }
+        }
+
+        let mut structs = Structs::default();
+        let rust_names = rust_struct_names(&qualified_names);
+
+        for entry in entries {
+            let key = match entry_key(entry) {
+                Some(key) => key,
+                None => continue,
+            };
+
+            for (index, (json_params, kinds)) in entry_params(entry).into_iter().enumerate() {
+                let components = json_params
+                    .iter()
+                    .zip(kinds.iter())
+                    .map(|(json_param, kind)| structs.components(json_param, kind, &rust_names))
+                    .collect();
+
+                // Function outputs are keyed separately from their inputs
+                let key = match index {
+                    0 => key.clone(),
+                    _ => format!("{}:outputs", key),
+                };
+
+                structs.params.insert(key, components);
+            }
+        }
+
+        structs
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// Returns `true` if a struct is generated with the Rust name `name`.
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.definitions.contains_key(name)
+    }
+
+    pub(crate) fn function_inputs(&self, f: &ethabi::Function) -> Vec<Components> {
+        self.lookup(&function_key(f), f.inputs.len())
+    }
+
+    pub(crate) fn function_outputs(&self, f: &ethabi::Function) -> Vec<Components> {
+        self.lookup(&format!("{}:outputs", function_key(f)), f.outputs.len())
+    }
+
+    pub(crate) fn event_inputs(&self, e: &ethabi::Event) -> Vec<Components> {
+        let kinds: Vec<_> = e.inputs.iter().map(|param| param.kind.clone()).collect();
+        self.lookup(&signature_key("event", &e.name, &kinds), e.inputs.len())
+    }
+
+    pub(crate) fn error_inputs(&self, e: &ethabi::AbiError) -> Vec<Components> {
+        let kinds: Vec<_> = e.inputs.iter().map(|param| param.kind.clone()).collect();
+        self.lookup(&signature_key("error", &e.name, &kinds), e.inputs.len())
+    }
+
+    pub(crate) fn constructor_inputs(&self, c: &ethabi::Constructor) -> Vec<Components> {
+        self.lookup("constructor", c.inputs.len())
+    }
+
+    /// Generates the Rust structs, one per Solidity struct found in the ABI.
+    pub(crate) fn generate(&self, options: &Options) -> TokenStream {
+        let attributes = options.struct_attributes(None);
+        let structs = self.definitions.iter().map(|(name, definition)| {
+            let name = syn::Ident::new(name, Span::call_site());
+            let fields = definition
+                .components
+                .field_names()
+                .into_iter()
+                .zip(definition.kinds.iter())
+                .enumerate()
+                .map(|(index, (field, kind))| {
+                    let kind = rust_type(kind, definition.components.child(index), options);
+                    quote! { pub #field: #kind }
+                });
+
+            quote! {
+                #attributes
+                pub struct #name {
+                    #(#fields),*
+                }
+            }
+        });
+
+        let proto_conversions = self
+            .proto_messages(options)
+            .into_iter()
+            .map(|message| message.conversion);
+
+        quote! {
+            #(#structs)*
+            #(#proto_conversions)*
+        }
+    }
+
+    /// Returns the protobuf message of each struct, empty if protobuf generation is disabled.
+    pub(crate) fn proto_messages(&self, options: &Options) -> Vec<Message> {
+        self.definitions
+            .iter()
+            .filter_map(|(name, definition)| {
+                let field_names = definition.components.field_names();
+                let fields: Vec<_> = field_names
+                    .iter()
+                    .zip(definition.kinds.iter())
+                    .enumerate()
+                    .map(|(index, (field, kind))| Field {
+                        name: field,
+                        kind,
+                        components: definition.components.child(index),
+                        indexed_hash: false,
+                    })
+                    .collect();
+
+                Message::new(
+                    &syn::Ident::new(name, Span::call_site()),
+                    name,
+                    &fields,
+                    options,
+                )
+            })
+            .collect()
+    }
+
+    fn lookup(&self, key: &str, count: usize) -> Vec<Components> {
+        match self.params.get(key) {
+            Some(components) => components.clone(),
+            None => vec![Components::default(); count],
+        }
+    }
+
+    fn components(
+        &mut self,
+        json_param: &Value,
+        kind: &ParamType,
+        rust_names: &HashMap<String, String>,
+    ) -> Components {
+        match kind {
+            ParamType::Array(inner) | ParamType::FixedArray(inner, _) => Components {
+                children: vec![self.components(json_param, inner, rust_names)],
+                ..Default::default()
+            },
+            ParamType::Tuple(kinds) => {
+                let json_components = json_param["components"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+
+                let fields = (0..kinds.len())
+                    .map(|index| match json_components.get(index)?["name"].as_str() {
+                        Some(name) if !name.is_empty() => Some(rust_variable(name)),
+                        _ => None,
+                    })
+                    .enumerate()
+                    .map(|(index, name)| name.unwrap_or_else(|| format!("field{}", index)))
+                    .collect();
+
+                let children = kinds
+                    .iter()
+                    .enumerate()
+                    .map(|(index, kind)| match json_components.get(index) {
+                        Some(json_component) => self.components(json_component, kind, rust_names),
+                        None => Components::default(),
+                    })
+                    .collect();
+
+                let struct_name = struct_qualified_name(json_param)
+                    .and_then(|qualified_name| rust_names.get(&qualified_name))
+                    .cloned();
+
+                let components = Components {
+                    struct_name,
+                    fields,
+                    children,
+                };
+
+                if let Some(name) = &components.struct_name {
+                    self.definitions
+                        .entry(name.clone())
+                        .or_insert_with(|| StructDefinition {
+                            kinds: kinds.clone(),
+                            components: components.clone(),
+                        });
+                }
+
+                components
+            }
+            _ => Components::default(),
+        }
+    }
+}
+
+fn function_key(f: &ethabi::Function) -> String {
+    format!("function {}", f.signature())
+}
+
+fn signature_key(prefix: &str, name: &str, kinds: &[ParamType]) -> String {
+    let types: Vec<_> = kinds.iter().map(ToString::to_string).collect();
+    format!("{} {}({})", prefix, name, types.join(","))
+}
+
+/// Computes the key under which the entry's params are registered, mirroring the keys used by
+/// the lookup methods of [Structs].
+fn entry_key(entry: &Value) -> Option<String> {
+    match entry["type"].as_str()? {
+        "function" => {
+            let f: ethabi::Function = serde_json::from_value(entry.clone()).ok()?;
+            Some(function_key(&f))
+        }
+        "event" => {
+            let e: ethabi::Event = serde_json::from_value(entry.clone()).ok()?;
+            let kinds: Vec<_> = e.inputs.iter().map(|param| param.kind.clone()).collect();
+            Some(signature_key("event", &e.name, &kinds))
+        }
+        "error" => {
+            let e: ethabi::AbiError = serde_json::from_value(entry.clone()).ok()?;
+            let kinds: Vec<_> = e.inputs.iter().map(|param| param.kind.clone()).collect();
+            Some(signature_key("error", &e.name, &kinds))
+        }
+        "constructor" => Some("constructor".to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the JSON params of the entry paired with their parsed type, the inputs first and
+/// then the outputs for functions.
+fn entry_params(entry: &Value) -> Vec<(Vec<Value>, Vec<ParamType>)> {
+    let params = |field: &str| -> Option<(Vec<Value>, Vec<ParamType>)> {
+        let json_params = entry[field].as_array().cloned().unwrap_or_default();
+        let kinds = json_params
+            .iter()
+            .map(|param| {
+                serde_json::from_value::<ethabi::Param>(param.clone())
+                    .ok()
+                    .map(|param| param.kind)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some((json_params, kinds))
+    };
+
+    let fields: &[&str] = match entry["type"].as_str() {
+        Some("function") => &["inputs", "outputs"],
+        _ => &["inputs"],
+    };
+
+    fields.iter().filter_map(|field| params(field)).collect()
+}
+
+fn collect_qualified_names(json_param: &Value, kind: &ParamType, names: &mut BTreeSet<String>) {
+    match kind {
+        ParamType::Array(inner) | ParamType::FixedArray(inner, _) => {
+            collect_qualified_names(json_param, inner, names)
+        }
+        ParamType::Tuple(kinds) => {
+            if let Some(qualified_name) = struct_qualified_name(json_param) {
+                names.insert(qualified_name);
+            }
+
+            if let Some(json_components) = json_param["components"].as_array() {
+                for (json_component, kind) in json_components.iter().zip(kinds.iter()) {
+                    collect_qualified_names(json_component, kind, names);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extracts `ISwapRouter.ExactInputSingleParams` out of an `internalType` like
+/// `struct ISwapRouter.ExactInputSingleParams[]`.
+fn struct_qualified_name(json_param: &Value) -> Option<String> {
+    let internal_type = json_param["internalType"].as_str()?;
+    let name = internal_type.strip_prefix("struct ")?;
+    let name = match name.find('[') {
+        Some(index) => &name[..index],
+        None => name,
+    };
+
+    Some(name.trim().to_string())
+}
+
+/// Solidity structs are named after their last path segment, structs with the same name
+/// defined in different contracts or libraries use their fully qualified name instead.
+fn rust_struct_names(qualified_names: &BTreeSet<String>) -> HashMap<String, String> {
+    let short_name = |qualified_name: &str| -> String {
+        qualified_name
+            .rsplit('.')
+            .next()
+            .unwrap_or(qualified_name)
+            .to_upper_camel_case()
+    };
+
+    qualified_names
+        .iter()
+        .map(|qualified_name| {
+            let name = short_name(qualified_name);
+            let conflicts = qualified_names
+                .iter()
+                .filter(|other| short_name(other) == name)
+                .count();
+
+            match conflicts {
+                1 => (qualified_name.clone(), name),
+                _ => (
+                    qualified_name.clone(),
+                    qualified_name.replace('.', "_").to_upper_camel_case(),
+                ),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::assert_ast_eq;
+
+    use super::Structs;
+    use crate::Options;
+    use quote::quote;
+
+    #[test]
+    fn test_structs_dedup_and_conflicts() {
+        let abi = serde_json::json!([
+            {
+                "type": "function",
+                "name": "swap",
+                "stateMutability": "nonpayable",
+                "inputs": [{
+                    "name": "params",
+                    "type": "tuple",
+                    "internalType": "struct ISwapRouter.Params",
+                    "components": [
+                        { "name": "tokenIn", "type": "address", "internalType": "address" },
+                        { "name": "amountIn", "type": "uint256", "internalType": "uint256" }
+                    ]
+                }],
+                "outputs": [{
+                    "name": "",
+                    "type": "tuple[]",
+                    "internalType": "struct IPool.Params[]",
+                    "components": [
+                        { "name": "", "type": "bool", "internalType": "bool" }
+                    ]
+                }]
+            },
+            {
+                "type": "event",
+                "name": "Swapped",
+                "anonymous": false,
+                "inputs": [{
+                    "name": "params",
+                    "type": "tuple",
+                    "indexed": false,
+                    "internalType": "struct ISwapRouter.Params",
+                    "components": [
+                        { "name": "tokenIn", "type": "address", "internalType": "address" },
+                        { "name": "amountIn", "type": "uint256", "internalType": "uint256" }
+                    ]
+                }]
+            }
+        ]);
+
+        let structs = Structs::from_json(&abi);
+
+        assert_ast_eq(
+            structs.generate(&Options::default()),
+            quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct IPoolParams {
+                    pub field0: bool
+                }
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct ISwapRouterParams {
+                    pub token_in: Vec<u8>,
+                    pub amount_in: substreams::scalar::BigInt
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use ethabi::ParamType;
+
+use crate::pb::eth::v2::Log;
+
+/// Canonical signature of an event, a function or an error as hashed for its topic or
+/// selector, e.g. `Transfer(address,address,uint256)`.
+pub fn canonical_signature<'a>(
+    name: &str,
+    kinds: impl IntoIterator<Item = &'a ParamType>,
+) -> String {
+    let kinds: Vec<_> = kinds
+        .into_iter()
+        .map(ethabi::param_type::Writer::write)
+        .collect();
+
+    format!("{}({})", name, kinds.join(","))
+}
+
+/// Size of the ABI encoding of a value of type `input`, `None` for dynamic types.
+pub fn fixed_data_size(input: &ParamType) -> Option<usize> {
+    match input {
+        ParamType::Address
+        | ParamType::Int(_)
+        | ParamType::Uint(_)
+        | ParamType::Bool
+        | ParamType::FixedBytes(_) => Some(32),
+        ParamType::Bytes | ParamType::String | ParamType::Array(_) => None,
+        ParamType::FixedArray(ref sub_type, count) => match sub_type.is_dynamic() {
+            true => None,
+            false => Some(
+                count * fixed_data_size(sub_type).expect("not dynamic, will always be Some(_)"),
+            ),
+        },
+        ParamType::Tuple(ref types) => {
+            if types.iter().any(ParamType::is_dynamic) {
+                return None;
+            }
+            Some(types.iter().map(fixed_data_size).map(Option::unwrap).sum())
+        }
+    }
+}
+
+/// Minimal size of the ABI encoding of a value of type `input`.
+pub fn min_data_size(input: &ParamType) -> usize {
+    match input {
+        ParamType::Address
+        | ParamType::Int(_)
+        | ParamType::Uint(_)
+        | ParamType::Bool
+        | ParamType::FixedBytes(_) => {
+            fixed_data_size(input).expect("not dynamic, will always be Some(_)")
+        }
+        // FixedArray with dynamic element becomes "dynamic" so we have
+        // an initial data offset (32) plus the minimal data size of the sub type multipled
+        // by number of element in the fixed array (count * min_data_size(sub_type)).
+        //
+        // If the sub type is not dynamic, we use its fixed data size.
+        ParamType::FixedArray(ref sub_type, count) => match sub_type.is_dynamic() {
+            true => 32 + count * min_data_size(sub_type),
+            false => fixed_data_size(input).expect("not dynamic, will always be Some(_)"),
+        },
+        // Those are dynamic type meaning there is first an offset where to find the data written (32 bytes)
+        // and then minimally a length (32 bytes) so minimum size is `size(offset) + size(length)` which is
+        // `32 + 32`.
+        ParamType::Bytes | ParamType::String | ParamType::Array(_) => 32 + 32,
+        ParamType::Tuple(ref types) => types.iter().map(min_data_size).sum(),
+    }
+}
+
+/// Shape of the logs emitted for an event: their topics count and data size. The generated
+/// `match_log` and the runtime [crate::dynamic] decoder match logs on it, along with the
+/// signature topic for non-anonymous events.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogShape {
+    /// One topic per indexed param, plus the signature topic for non-anonymous events.
+    pub topic_count: usize,
+    /// Size of the data when all non-indexed params have a fixed size.
+    pub fixed_data_size: Option<usize>,
+    /// Minimal size of the data, used when some non-indexed params are dynamic.
+    pub min_data_size: usize,
+}
+
+impl LogShape {
+    pub fn new(event: &ethabi::Event) -> Self {
+        // Anonymous events don't emit the event's signature as the first topic
+        let first_indexed_topic = if event.anonymous { 0 } else { 1 };
+        let data_kinds = || {
+            event
+                .inputs
+                .iter()
+                .filter(|param| !param.indexed)
+                .map(|param| &param.kind)
+        };
+
+        LogShape {
+            topic_count: event.inputs.iter().filter(|param| param.indexed).count()
+                + first_indexed_topic,
+            fixed_data_size: data_kinds().map(fixed_data_size).sum(),
+            min_data_size: data_kinds().map(min_data_size).sum(),
+        }
+    }
+
+    /// Returns `true` if the log has the topics count and data size of the event, its
+    /// signature topic is not checked.
+    pub fn matches(&self, log: &Log) -> bool {
+        if log.topics.len() != self.topic_count {
+            return false;
+        }
+
+        match self.fixed_data_size {
+            Some(fixed_data_size) => log.data.len() == fixed_data_size,
+            None => log.data.len() >= self.min_data_size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethabi::ParamType;
+
+    use super::{fixed_data_size, min_data_size};
+    #[test]
+    fn it_fixed_data_size_works() {
+        let inputs: Vec<(&str, ParamType, Option<usize>)> = vec![
+            (
+                "tuple(address)",
+                ParamType::Tuple(vec![ParamType::Address]),
+                Some(32),
+            ),
+            (
+                "bool[2]",
+                ParamType::FixedArray(Box::new(ParamType::Bool), 2),
+                Some(64),
+            ),
+            (
+                "string[2]",
+                ParamType::FixedArray(Box::new(ParamType::String), 2),
+                None,
+            ),
+        ];
+
+        for (name, actual, expected) in inputs {
+            assert_eq!(fixed_data_size(&actual), expected, "test case {}", name);
+        }
+    }
+
+    #[test]
+    fn it_min_data_size_works() {
+        let inputs: Vec<(&str, ParamType, usize)> = vec![
+            (
+                "tuple(address)",
+                ParamType::Tuple(vec![ParamType::Address]),
+                32,
+            ),
+            (
+                "bool[2]",
+                ParamType::FixedArray(Box::new(ParamType::Bool), 2),
+                2 * 32,
+            ),
+            (
+                "string[2]",
+                ParamType::FixedArray(Box::new(ParamType::String), 2),
+                32 + (2 * 32) + (2 * 32),
+            ),
+        ];
+
+        for (name, actual, expected) in inputs {
+            assert_eq!(min_data_size(&actual), expected, "test case {}", name);
+        }
+    }
+}
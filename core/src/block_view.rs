@@ -1,7 +1,7 @@
 use prost_types::Timestamp;
 
 use crate::pb::eth::v2::{Call, Log};
-use crate::{pb::eth::v2 as pb, Event};
+use crate::{pb::eth::v2 as pb, revert::RevertReason, Event};
 
 impl pb::Block {
     /// Iterates over successful transactions
@@ -41,6 +41,21 @@ impl pb::Block {
     ///     }
     /// }
     /// ```
+    ///
+    /// Contracts generated by `substreams-abigen` also have an `events::Events` enum whose
+    /// `decode_any` dispatches on the log's signature topic once instead of trying each event:
+    /// ```ignore
+    /// for log in block.logs() {
+    ///     match events::Events::decode_any(log.log) {
+    ///         Some(Ok(events::Events::E1(event))) => { /* Process events of type E1 */ }
+    ///         Some(Ok(events::Events::E2(event))) => { /* Process events of type E2 */ }
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Anonymous events are matched on their shape alone (see [Event::match_log]), `addresses`
+    /// must list the contracts emitting them or logs of unrelated events will be decoded.
     pub fn events<'a, E: Event>(
         &'a self,
         addresses: &'a [&[u8]],
@@ -97,6 +112,23 @@ impl CallView<'_> {
             .iter()
             .find(|call| call.index == self.call.parent_index);
     }
+
+    /// Returns why the call failed, `None` when it succeeded.
+    ///
+    /// The revert data in `return_data` is decoded as an `Error(string)` or a `Panic(uint256)`
+    /// and kept as raw bytes otherwise. A call failing without revert data, e.g. out of gas,
+    /// returns the node's `failure_reason`.
+    pub fn revert_reason(&self) -> Option<RevertReason> {
+        if !self.call.status_failed {
+            return None;
+        }
+
+        if self.call.return_data.is_empty() {
+            return Some(RevertReason::Failure(self.call.failure_reason.clone()));
+        }
+
+        Some(RevertReason::decode(&self.call.return_data))
+    }
 }
 
 impl AsRef<pb::Call> for CallView<'_> {
@@ -154,6 +186,45 @@ impl pb::TransactionTrace {
         res.into_iter()
     }
 
+    /// Returns the deepest failed call that caused the transaction to fail, `None` when the
+    /// transaction succeeded.
+    ///
+    /// Starting from the root call, the revert is followed down to a failed child only when it
+    /// bubbled up, that is the parent reverted with the same `return_data` as the child. A call
+    /// that caught the failure of a child (e.g. with `try`/`catch`) and then reverted for its
+    /// own reason is the reverting call.
+    pub fn reverting_call(&self) -> Option<CallView> {
+        let mut current = self
+            .calls
+            .iter()
+            .find(|call| call.depth == 0)
+            .filter(|call| call.status_failed)?;
+
+        while let Some(child) = self
+            .calls
+            .iter()
+            .filter(|call| {
+                call.parent_index == current.index
+                    && call.status_failed
+                    && call.return_data == current.return_data
+            })
+            .last()
+        {
+            current = child;
+        }
+
+        Some(CallView {
+            transaction: self,
+            call: current,
+        })
+    }
+
+    /// Returns why the transaction failed, see [CallView::revert_reason] of its
+    /// [reverting call](Self::reverting_call).
+    pub fn revert_reason(&self) -> Option<RevertReason> {
+        self.reverting_call()?.revert_reason()
+    }
+
     // TODO: Call view, filtering out failed calls
     // pub fn calls: Vec<CallView> { }
 }
@@ -218,6 +289,7 @@ mod tests {
     use crate::{
         block_view::CallView,
         pb::eth::v2::{Call, Log, TransactionTrace},
+        revert::RevertReason,
     };
 
     #[test]
@@ -262,4 +334,98 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn reverting_call() {
+        let call = |index, parent_index, depth, status_failed, return_data: &str| Call {
+            index,
+            parent_index,
+            depth,
+            status_failed,
+            return_data: hex::decode(return_data).unwrap(),
+            failure_reason: "execution reverted".to_string(),
+            ..Default::default()
+        };
+
+        // Error("low")
+        let error = concat!(
+            "08c379a0",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "6c6f770000000000000000000000000000000000000000000000000000000000",
+        );
+
+        let trace = TransactionTrace {
+            calls: vec![
+                call(1, 0, 0, true, error),
+                // Failure caught by the root call
+                call(2, 1, 1, true, ""),
+                call(3, 1, 1, true, error),
+                call(4, 3, 2, false, ""),
+                call(5, 3, 2, true, error),
+                call(6, 1, 1, false, ""),
+            ],
+            ..Default::default()
+        };
+
+        let reverting = trace.reverting_call().unwrap();
+        assert_eq!(reverting.call.index, 5);
+        assert_eq!(
+            trace.revert_reason(),
+            Some(RevertReason::Error("low".to_string()))
+        );
+
+        let caught = CallView {
+            transaction: &trace,
+            call: &trace.calls[1],
+        };
+        assert_eq!(
+            caught.revert_reason(),
+            Some(RevertReason::Failure("execution reverted".to_string()))
+        );
+
+        let succeeded = CallView {
+            transaction: &trace,
+            call: &trace.calls[3],
+        };
+        assert_eq!(succeeded.revert_reason(), None);
+
+        // Error("high")
+        let other_error = concat!(
+            "08c379a0",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000004",
+            "6869676800000000000000000000000000000000000000000000000000000000",
+        );
+
+        // The failure of call 4 is caught by call 3 which then reverts for its own reason
+        let trace = TransactionTrace {
+            calls: vec![
+                call(1, 0, 0, true, other_error),
+                call(2, 1, 1, true, error),
+                call(3, 1, 1, true, other_error),
+                call(4, 3, 2, true, error),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(trace.reverting_call().unwrap().call.index, 3);
+        assert_eq!(
+            trace.revert_reason(),
+            Some(RevertReason::Error("high".to_string()))
+        );
+
+        // The root call catches the failure of its only child and reverts for its own reason
+        let trace = TransactionTrace {
+            calls: vec![call(1, 0, 0, true, other_error), call(2, 1, 1, true, error)],
+            ..Default::default()
+        };
+        assert_eq!(trace.reverting_call().unwrap().call.index, 1);
+
+        let trace = TransactionTrace {
+            calls: vec![call(1, 0, 0, false, ""), call(2, 1, 1, true, error)],
+            ..Default::default()
+        };
+        assert!(trace.reverting_call().is_none());
+        assert_eq!(trace.revert_reason(), None);
+    }
 }
@@ -0,0 +1,620 @@
+//! Decoding of logs and calls with an ABI only known at runtime, e.g. loaded from the
+//! params of a module, instead of the bindings generated by `use_contract!` or `Abigen`.
+//!
+//! ```ignore
+//! use substreams_ethereum::dynamic::DynamicContract;
+//!
+//! let contract = DynamicContract::from_json(abi_json.as_bytes())?;
+//! for log in block.logs() {
+//!     if let Some(Ok(event)) = contract.decode_log(log.log) {
+//!         substreams::log::info!("{} {}", event.name, event.to_json());
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use ethabi::{ParamType, Token};
+use substreams::scalar::BigInt;
+
+use crate::{
+    abi::{canonical_signature, LogShape},
+    pb::eth::v2::{Call, Log},
+    Address, DecodeError, H256,
+};
+
+/// Contract's ABI loaded at runtime, decoding the logs of its events and the calls of its
+/// functions.
+///
+/// Logs are matched with the same rules as the generated `match_log` (see
+/// [crate::abi::LogShape]): the first topic is the event's signature hash, the log has one
+/// topic per indexed param and its data has the size of the ABI encoded non-indexed params.
+///
+/// Anonymous events have no signature topic and are matched on the topics count and data size
+/// alone, only after no other event matched. Any log with the same shape decodes into them, so
+/// only decode the logs emitted by the contract when its ABI declares anonymous events.
+#[derive(Debug, Clone)]
+pub struct DynamicContract {
+    /// Events by signature topic, an ABI can declare the same signature with different
+    /// indexed params (e.g. ERC-20 and ERC-721 `Transfer`).
+    events: HashMap<[u8; 32], Vec<(ethabi::Event, LogShape)>>,
+    anonymous_events: Vec<(ethabi::Event, LogShape)>,
+    functions: HashMap<[u8; 4], ethabi::Function>,
+}
+
+/// Log decoded into one of the contract's events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEvent {
+    /// Name of the event as defined in the ABI.
+    pub name: String,
+    /// Canonical signature of the event, e.g. `Transfer(address,address,uint256)`.
+    pub signature: String,
+    /// Params of the event in declaration order, indexed or not.
+    pub params: Vec<(String, DynValue)>,
+}
+
+/// Call decoded into one of the contract's functions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCall {
+    /// Name of the function as defined in the ABI.
+    pub name: String,
+    /// Canonical signature of the function, e.g. `transfer(address,uint256)`.
+    pub signature: String,
+    /// Input params of the function in declaration order.
+    pub params: Vec<(String, DynValue)>,
+}
+
+/// Value of a param decoded at runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynValue {
+    Address(Address),
+    /// A `bytesN` value.
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    Int(BigInt),
+    Uint(BigInt),
+    Bool(bool),
+    String(String),
+    Array(Vec<DynValue>),
+    FixedArray(Vec<DynValue>),
+    Tuple(Vec<DynValue>),
+    /// Indexed param of a reference type (`string`, `bytes`, arrays, including fixed size ones,
+    /// and tuples), the log only contains the Keccak-256 hash of its encoded value.
+    IndexedHash(H256),
+}
+
+impl DynamicContract {
+    pub fn new(contract: &ethabi::Contract) -> Self {
+        let mut events = HashMap::<[u8; 32], Vec<_>>::new();
+        let mut anonymous_events = vec![];
+        for event in contract.events() {
+            let entry = (event.clone(), LogShape::new(event));
+
+            match event.anonymous {
+                true => anonymous_events.push(entry),
+                false => events
+                    .entry(event.signature().to_fixed_bytes())
+                    .or_default()
+                    .push(entry),
+            }
+        }
+
+        let functions = contract
+            .functions()
+            .map(|function| (function.short_signature(), function.clone()))
+            .collect();
+
+        DynamicContract {
+            events,
+            anonymous_events,
+            functions,
+        }
+    }
+
+    /// Loads the contract from its ABI JSON array.
+    pub fn from_json(json: &[u8]) -> Result<Self, ethabi::Error> {
+        Ok(Self::new(&ethabi::Contract::load(json)?))
+    }
+
+    /// Decodes the `log` into the event matching its topics and data. Returns `None` if no
+    /// event of the contract matches the log.
+    pub fn decode_log(&self, log: &Log) -> Option<Result<DecodedEvent, DecodeError>> {
+        let events = log
+            .topics
+            .get(0)
+            .and_then(|topic0| <[u8; 32]>::try_from(topic0.as_slice()).ok())
+            .and_then(|topic0| self.events.get(&topic0))
+            .into_iter()
+            .flatten()
+            .chain(self.anonymous_events.iter());
+
+        let (event, _) = events.into_iter().find(|(_, shape)| shape.matches(log))?;
+
+        Some(decode_log(event, log))
+    }
+
+    /// Decodes the input of the `call` into the function matching its selector. Returns `None`
+    /// if no function of the contract matches the call.
+    pub fn decode_call(&self, call: &Call) -> Option<Result<DecodedCall, DecodeError>> {
+        let selector: [u8; 4] = call.input.get(0..4)?.try_into().expect("checked length");
+        let function = self.functions.get(&selector)?;
+
+        Some(decode_call(function, call))
+    }
+}
+
+fn decode_log(event: &ethabi::Event, log: &Log) -> Result<DecodedEvent, DecodeError> {
+    let param_name =
+        |index: usize, param: &ethabi::EventParam| match (param.name.is_empty(), param.indexed) {
+            (false, _) => param.name.clone(),
+            (true, true) => format!("topic{}", index),
+            (true, false) => format!("param{}", index),
+        };
+    let abi_error = |param: Option<String>, reason: String| DecodeError::Abi {
+        item: event.name.clone().into(),
+        param: param.map(Into::into),
+        reason,
+    };
+
+    let data_kinds: Vec<_> = event
+        .inputs
+        .iter()
+        .filter(|param| !param.indexed)
+        .map(|param| param.kind.clone())
+        .collect();
+    let mut data = ethabi::decode(&data_kinds, &log.data)
+        .map_err(|e| abi_error(None, e.to_string()))?
+        .into_iter();
+
+    // Anonymous events don't emit the event's signature as the first topic
+    let mut topics = log.topics.iter().skip(if event.anonymous { 0 } else { 1 });
+
+    let mut params = Vec::with_capacity(event.inputs.len());
+    for (index, param) in event.inputs.iter().enumerate() {
+        let name = param_name(index, param);
+        let value = match param.indexed {
+            true => {
+                let topic = topics.next().expect("topics count checked by LogShape");
+                // Reference types are hashed, including fixed size arrays and static tuples
+                match matches!(
+                    param.kind,
+                    ParamType::String
+                        | ParamType::Bytes
+                        | ParamType::Array(_)
+                        | ParamType::FixedArray(..)
+                        | ParamType::Tuple(_)
+                ) {
+                    true => DynValue::IndexedHash(
+                        H256::from_slice(topic)
+                            .map_err(|e| abi_error(Some(name.clone()), e.to_string()))?,
+                    ),
+                    false => ethabi::decode(&[param.kind.clone()], topic)
+                        .map_err(|e| abi_error(Some(name.clone()), e.to_string()))?
+                        .pop()
+                        .map(DynValue::from)
+                        .expect("one token decoded"),
+                }
+            }
+            false => DynValue::from(data.next().expect("one token per non-indexed param")),
+        };
+
+        params.push((name, value));
+    }
+
+    Ok(DecodedEvent {
+        name: event.name.clone(),
+        signature: canonical_signature(&event.name, event.inputs.iter().map(|param| &param.kind)),
+        params,
+    })
+}
+
+fn decode_call(function: &ethabi::Function, call: &Call) -> Result<DecodedCall, DecodeError> {
+    let values = function
+        .decode_input(&call.input[4..])
+        .map_err(|e| DecodeError::Abi {
+            item: function.name.clone().into(),
+            param: None,
+            reason: e.to_string(),
+        })?;
+
+    let params = function
+        .inputs
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(index, (param, value))| {
+            let name = match param.name.is_empty() {
+                false => param.name.clone(),
+                true => format!("param{}", index),
+            };
+            (name, DynValue::from(value))
+        })
+        .collect();
+
+    Ok(DecodedCall {
+        name: function.name.clone(),
+        signature: canonical_signature(
+            &function.name,
+            function.inputs.iter().map(|param| &param.kind),
+        ),
+        params,
+    })
+}
+
+impl DecodedEvent {
+    /// Returns the value of the param `name`.
+    pub fn param(&self, name: &str) -> Option<&DynValue> {
+        param(&self.params, name)
+    }
+
+    /// Converts the params into a JSON object keyed by param name.
+    pub fn to_json(&self) -> serde_json::Value {
+        params_to_json(&self.params)
+    }
+}
+
+impl DecodedCall {
+    /// Returns the value of the param `name`.
+    pub fn param(&self, name: &str) -> Option<&DynValue> {
+        param(&self.params, name)
+    }
+
+    /// Converts the params into a JSON object keyed by param name.
+    pub fn to_json(&self) -> serde_json::Value {
+        params_to_json(&self.params)
+    }
+}
+
+fn param<'a>(params: &'a [(String, DynValue)], name: &str) -> Option<&'a DynValue> {
+    params
+        .iter()
+        .find(|(param, _)| param == name)
+        .map(|(_, value)| value)
+}
+
+fn params_to_json(params: &[(String, DynValue)]) -> serde_json::Value {
+    serde_json::Value::Object(
+        params
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_json()))
+            .collect(),
+    )
+}
+
+impl DynValue {
+    /// Returns the value of an `intN` or `uintN` param.
+    pub fn as_bigint(&self) -> Option<&BigInt> {
+        match self {
+            DynValue::Int(value) | DynValue::Uint(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Converts the value into JSON. Integers are decimal strings as they can exceed the range
+    /// of JSON numbers, addresses, bytes and hashes are `0x` prefixed hexadecimal strings.
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value;
+
+        match self {
+            DynValue::Address(address) => Value::String(address.to_string()),
+            DynValue::FixedBytes(bytes) | DynValue::Bytes(bytes) => {
+                Value::String(format!("0x{}", hex::encode(bytes)))
+            }
+            DynValue::Int(value) | DynValue::Uint(value) => Value::String(value.to_string()),
+            DynValue::Bool(value) => Value::Bool(*value),
+            DynValue::String(value) => Value::String(value.clone()),
+            DynValue::Array(values) | DynValue::FixedArray(values) | DynValue::Tuple(values) => {
+                Value::Array(values.iter().map(DynValue::to_json).collect())
+            }
+            DynValue::IndexedHash(hash) => Value::String(hash.to_string()),
+        }
+    }
+}
+
+impl From<Token> for DynValue {
+    fn from(token: Token) -> Self {
+        match token {
+            Token::Address(address) => DynValue::Address(Address(address.to_fixed_bytes())),
+            Token::FixedBytes(bytes) => DynValue::FixedBytes(bytes),
+            Token::Bytes(bytes) => DynValue::Bytes(bytes),
+            Token::Int(value) => {
+                let mut bytes = [0u8; 32];
+                value.to_big_endian(&mut bytes);
+                DynValue::Int(BigInt::from_signed_bytes_be(&bytes))
+            }
+            Token::Uint(value) => {
+                let mut bytes = [0u8; 32];
+                value.to_big_endian(&mut bytes);
+                DynValue::Uint(BigInt::from_unsigned_bytes_be(&bytes))
+            }
+            Token::Bool(value) => DynValue::Bool(value),
+            Token::String(value) => DynValue::String(value),
+            Token::FixedArray(tokens) => {
+                DynValue::FixedArray(tokens.into_iter().map(DynValue::from).collect())
+            }
+            Token::Array(tokens) => {
+                DynValue::Array(tokens.into_iter().map(DynValue::from).collect())
+            }
+            Token::Tuple(tokens) => {
+                DynValue::Tuple(tokens.into_iter().map(DynValue::from).collect())
+            }
+        }
+    }
+}
+
+impl From<DynValue> for serde_json::Value {
+    fn from(value: DynValue) -> Self {
+        value.to_json()
+    }
+}
+
+impl TryFrom<DynValue> for BigInt {
+    type Error = DynValue;
+
+    /// Converts an `intN` or `uintN` value, returns the value back for other types.
+    fn try_from(value: DynValue) -> Result<Self, Self::Error> {
+        match value {
+            DynValue::Int(value) | DynValue::Uint(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use substreams::scalar::BigInt;
+
+    use crate::{
+        pb::eth::v2::{Call, Log},
+        DecodeError,
+    };
+
+    use super::{DynValue, DynamicContract};
+
+    fn hex(input: &str) -> Vec<u8> {
+        hex::decode(input).unwrap()
+    }
+
+    const ABI: &str = r#"[
+        {
+            "type": "event",
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "tokenId", "type": "uint256", "indexed": true }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "Named",
+            "anonymous": false,
+            "inputs": [
+                { "name": "name", "type": "string", "indexed": true },
+                { "name": "delta", "type": "int256", "indexed": false }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "Message",
+            "anonymous": false,
+            "inputs": [{ "name": "text", "type": "string", "indexed": false }]
+        },
+        {
+            "type": "event",
+            "name": "Range",
+            "anonymous": false,
+            "inputs": [
+                { "name": "bounds", "type": "uint256[2]", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "LogNote",
+            "anonymous": true,
+            "inputs": [
+                { "name": "sig", "type": "bytes4", "indexed": true },
+                { "name": "guy", "type": "address", "indexed": true },
+                { "name": "wad", "type": "uint256", "indexed": false }
+            ]
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "stateMutability": "nonpayable",
+            "inputs": [
+                { "name": "to", "type": "address" },
+                { "name": "", "type": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool" }]
+        }
+    ]"#;
+
+    #[test]
+    fn test_decode_log() {
+        let contract = DynamicContract::from_json(ABI.as_bytes()).unwrap();
+        let topic0 = hex("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+        let from = hex("000000000000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
+        let to = hex("000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
+        let amount = hex("000000000000000000000000000000000000000000000000000000000000002a");
+
+        let erc20 = Log {
+            topics: vec![topic0.clone(), from.clone(), to.clone()],
+            data: amount.clone(),
+            ..Default::default()
+        };
+        let event = contract.decode_log(&erc20).unwrap().unwrap();
+        assert_eq!(event.signature, "Transfer(address,address,uint256)");
+        assert_eq!(
+            event.param("value").and_then(DynValue::as_bigint),
+            Some(&BigInt::from(42))
+        );
+        assert_eq!(
+            event.to_json(),
+            serde_json::json!({
+                "from": "0xaAaAaAaaAaAaAaaAaAAAAAAAAaaaAaAaAaaAaaAa",
+                "to": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB",
+                "value": "42"
+            })
+        );
+
+        let erc721 = Log {
+            topics: vec![topic0.clone(), from.clone(), to.clone(), amount.clone()],
+            ..Default::default()
+        };
+        let event = contract.decode_log(&erc721).unwrap().unwrap();
+        assert_eq!(
+            event.param("tokenId").and_then(DynValue::as_bigint),
+            Some(&BigInt::from(42))
+        );
+
+        // Neither the ERC-20 nor the ERC-721 layout
+        let unknown = Log {
+            topics: vec![topic0.clone(), from.clone(), to.clone()],
+            ..Default::default()
+        };
+        assert!(contract.decode_log(&unknown).is_none());
+    }
+
+    #[test]
+    fn test_decode_log_indexed_dynamic_and_signed() {
+        let contract = DynamicContract::from_json(ABI.as_bytes()).unwrap();
+        let hash = hex("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8");
+
+        let log = Log {
+            topics: vec![
+                crate::types::keccak256(b"Named(string,int256)").to_vec(),
+                hash,
+            ],
+            data: hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"),
+            ..Default::default()
+        };
+
+        let event = contract.decode_log(&log).unwrap().unwrap();
+        assert_eq!(event.name, "Named");
+        assert_eq!(
+            event.to_json(),
+            serde_json::json!({
+                "name": "0x1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8",
+                "delta": "-2"
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_log_indexed_fixed_array() {
+        let contract = DynamicContract::from_json(ABI.as_bytes()).unwrap();
+        // keccak256(abi.encode([1, 2])), fixed size arrays are hashed although they're static
+        let hash = hex("e90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0");
+
+        let log = Log {
+            topics: vec![
+                crate::types::keccak256(b"Range(uint256[2],uint256)").to_vec(),
+                hash,
+            ],
+            data: hex("000000000000000000000000000000000000000000000000000000000000002a"),
+            ..Default::default()
+        };
+
+        let event = contract.decode_log(&log).unwrap().unwrap();
+        assert_eq!(event.name, "Range");
+        assert_eq!(
+            event.to_json(),
+            serde_json::json!({
+                "bounds": "0xe90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0",
+                "value": "42"
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_call() {
+        let contract = DynamicContract::from_json(ABI.as_bytes()).unwrap();
+
+        let call = Call {
+            input: hex("a9059cbb000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb0000000000000000000000000000000000000000000000000000000000000064"),
+            ..Default::default()
+        };
+        let decoded = contract.decode_call(&call).unwrap().unwrap();
+        assert_eq!(decoded.signature, "transfer(address,uint256)");
+        assert_eq!(
+            BigInt::try_from(decoded.param("param1").unwrap().clone()),
+            Ok(BigInt::from(100))
+        );
+
+        let truncated = Call {
+            input: hex("a9059cbb0000"),
+            ..Default::default()
+        };
+        assert!(matches!(
+            contract.decode_call(&truncated),
+            Some(Err(DecodeError::Abi { item, param: None, .. })) if item == "transfer"
+        ));
+
+        let unknown = Call {
+            input: hex("70a08231"),
+            ..Default::default()
+        };
+        assert!(contract.decode_call(&unknown).is_none());
+    }
+
+    #[test]
+    fn test_decode_log_anonymous() {
+        let contract = DynamicContract::from_json(ABI.as_bytes()).unwrap();
+
+        let log = Log {
+            topics: vec![
+                hex("a9059cbb00000000000000000000000000000000000000000000000000000000"),
+                hex("000000000000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+            ],
+            data: hex("000000000000000000000000000000000000000000000000000000000000002a"),
+            ..Default::default()
+        };
+
+        let event = contract.decode_log(&log).unwrap().unwrap();
+        assert_eq!(event.name, "LogNote");
+        assert_eq!(
+            event.to_json(),
+            serde_json::json!({
+                "sig": "0xa9059cbb",
+                "guy": "0xaAaAaAaaAaAaAaaAaAAAAAAAAaaaAaAaAaaAaaAa",
+                "wad": "42"
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_log_typed_error() {
+        let contract = DynamicContract::from_json(ABI.as_bytes()).unwrap();
+
+        // The string's length points past the end of the data
+        let log = Log {
+            topics: vec![crate::types::keccak256(b"Message(string)").to_vec()],
+            data: hex(concat!(
+                "0000000000000000000000000000000000000000000000000000000000000020",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+            )),
+            ..Default::default()
+        };
+
+        match contract.decode_log(&log) {
+            Some(Err(DecodeError::Abi { item, param, .. })) => {
+                assert_eq!(item, "Message");
+                assert_eq!(param, None);
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
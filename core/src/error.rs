@@ -0,0 +1,169 @@
+use std::{borrow::Cow, fmt};
+
+/// Error returned when decoding a log, a call's input or output, a revert or a RPC response
+/// with the generated bindings.
+///
+/// The variants carry the name of the event, function, error or constructor being decoded
+/// (`item`) and, when known, the name of the param that failed to decode. The names are static
+/// in the generated bindings and owned when decoding with an ABI loaded at runtime, see
+/// [crate::dynamic].
+///
+/// Code still dealing with `String` errors can convert from and into a [DecodeError], so `?`
+/// keeps working in functions returning `Result<_, String>` and manual implementations of
+/// [crate::Event] or [crate::Function] can wrap their messages with `Into::into`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    /// The log has less topics than the event has indexed params, plus its signature topic for
+    /// non-anonymous events.
+    TopicMismatch {
+        event: Cow<'static, str>,
+        expected: usize,
+        actual: usize,
+    },
+    /// The data has less bytes than required, e.g. a call's input without a selector.
+    DataSize {
+        item: Cow<'static, str>,
+        expected: usize,
+        actual: usize,
+    },
+    /// The data is not the ABI encoding of the item's params, `param` is set when a single
+    /// param is decoded like an indexed param from its topic.
+    Abi {
+        item: Cow<'static, str>,
+        param: Option<Cow<'static, str>>,
+        reason: String,
+    },
+    /// The decoded value doesn't fit in the Rust type of the param, e.g. a `uint8` above 255
+    /// decoded into an `u8`.
+    Overflow {
+        item: Cow<'static, str>,
+        param: Cow<'static, str>,
+        kind: &'static str,
+        value: String,
+    },
+    /// The input of a contract creation call doesn't start with the init bytecode it's
+    /// decoded with, e.g. the call deploys another contract.
+    BytecodeMismatch { item: Cow<'static, str> },
+    /// Any other error, described by its message.
+    Custom(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::TopicMismatch {
+                event,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "event `{}` expects {} topics, got {}",
+                event, expected, actual
+            ),
+            DecodeError::DataSize {
+                item,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "`{}` expects at least {} bytes of data, got {}",
+                item, expected, actual
+            ),
+            DecodeError::Abi {
+                item,
+                param: Some(param),
+                reason,
+            } => write!(
+                f,
+                "unable to decode param `{}` of `{}`: {}",
+                param, item, reason
+            ),
+            DecodeError::Abi {
+                item,
+                param: None,
+                reason,
+            } => write!(f, "unable to decode `{}`: {}", item, reason),
+            DecodeError::Overflow {
+                item,
+                param,
+                kind,
+                value,
+            } => write!(
+                f,
+                "value {} of param `{}` of `{}` overflows {}",
+                value, param, item, kind
+            ),
+            DecodeError::BytecodeMismatch { item } => write!(
+                f,
+                "call input of `{}` does not start with the contract's init bytecode",
+                item
+            ),
+            DecodeError::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl From<String> for DecodeError {
+    fn from(message: String) -> Self {
+        DecodeError::Custom(message)
+    }
+}
+
+impl From<&str> for DecodeError {
+    fn from(message: &str) -> Self {
+        DecodeError::Custom(message.to_string())
+    }
+}
+
+impl From<DecodeError> for String {
+    fn from(error: DecodeError) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecodeError;
+
+    #[test]
+    fn test_display() {
+        let error = DecodeError::Abi {
+            item: "Transfer".into(),
+            param: Some("from".into()),
+            reason: "Invalid data".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "unable to decode param `from` of `Transfer`: Invalid data"
+        );
+
+        let error = DecodeError::Overflow {
+            item: "fee".into(),
+            param: "amount".into(),
+            kind: "uint8",
+            value: "0x100".to_string(),
+        };
+        assert_eq!(
+            String::from(error),
+            "value 0x100 of param `amount` of `fee` overflows uint8"
+        );
+
+        let error = DecodeError::BytecodeMismatch {
+            item: "constructor".into(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "call input of `constructor` does not start with the contract's init bytecode"
+        );
+    }
+
+    #[test]
+    fn test_from_string() {
+        let error: DecodeError = "invalid log".into();
+
+        assert_eq!(error, DecodeError::Custom("invalid log".to_string()));
+        assert_eq!(error.to_string(), "invalid log");
+    }
+}
@@ -1,12 +1,44 @@
 use std::marker::PhantomData;
 
-use crate::pb::eth::v2::Log;
+use crate::{pb::eth::v2::Log, types::keccak256, DecodeError};
 
 pub trait Event: Sized {
     const NAME: &'static str;
+    /// Canonical signature of the event, e.g. `Transfer(address,address,uint256)`.
+    const SIGNATURE: &'static str;
+    /// Keccak-256 hash of [Self::SIGNATURE], the first topic of the logs emitted for
+    /// this event.
+    ///
+    /// Anonymous events never emit it: their logs don't carry this value in any topic, so
+    /// filtering logs on `TOPIC0` matches none of them. Check [Self::ANONYMOUS] first.
+    const TOPIC0: [u8; 32];
+    /// Whether the event is declared `anonymous`, its logs then have no signature topic
+    /// and are matched on their shape alone, see [Self::match_log].
+    const ANONYMOUS: bool = false;
 
+    /// Types of the event's params (indexed or not), in declaration order.
+    fn input_types() -> Vec<ethabi::ParamType>;
+
+    /// Matches the log on its signature topic, topics count and data size.
+    ///
+    /// Anonymous events have no signature topic, any log with the same topics count and
+    /// data size matches, whichever event or contract emitted it. Only match them against
+    /// logs of known addresses, e.g. through [crate::pb::eth::v2::Block::events].
     fn match_log(log: &Log) -> bool;
-    fn decode(log: &Log) -> Result<Self, String>;
+    fn decode(log: &Log) -> Result<Self, DecodeError>;
+
+    /// Encodes the event into a log with its topics and ABI encoded data, the log's
+    /// address and indexes are left empty.
+    fn encode(&self) -> Log;
+
+    /// Encodes the event into a log emitted by the contract at `address`, mostly useful
+    /// to build fixtures in tests.
+    fn to_log(&self, address: &[u8]) -> Log {
+        Log {
+            address: address.to_vec(),
+            ..self.encode()
+        }
+    }
 
     /// Attempts to match and decode the log.
     /// If `Self::match_log(log)` is `false`, returns `None`.
@@ -74,6 +106,22 @@ impl<T> IndexedDynamicValue<T> {
     }
 }
 
+impl IndexedDynamicValue<String> {
+    /// Creates the indexed value of a known `string`, its hash being the Keccak-256 of
+    /// the string's bytes as computed by Solidity.
+    pub fn from_value(value: &str) -> Self {
+        Self::new(keccak256(value.as_bytes()).to_vec())
+    }
+}
+
+impl IndexedDynamicValue<Vec<u8>> {
+    /// Creates the indexed value of a known `bytes`, its hash being the Keccak-256 of
+    /// the bytes as computed by Solidity.
+    pub fn from_value(value: &[u8]) -> Self {
+        Self::new(keccak256(value).to_vec())
+    }
+}
+
 impl<T> From<Vec<u8>> for IndexedDynamicValue<T> {
     fn from(topic: Vec<u8>) -> Self {
         Self::new(topic)
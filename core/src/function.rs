@@ -1,10 +1,19 @@
-use crate::pb::eth::v2::Call;
+use crate::{pb::eth::v2::Call, DecodeError};
 
 pub trait Function: Sized {
     const NAME: &'static str;
+    /// Canonical signature of the function, e.g. `transfer(address,uint256)`.
+    const SIGNATURE: &'static str;
+    /// First 4 bytes of the Keccak-256 hash of [Self::SIGNATURE], prefixing the call's input.
+    const SELECTOR: [u8; 4];
+
+    /// Types of the function's input params, in declaration order.
+    fn input_types() -> Vec<ethabi::ParamType>;
+    /// Types of the function's output params, in declaration order.
+    fn output_types() -> Vec<ethabi::ParamType>;
 
     fn match_call(log: &Call) -> bool;
-    fn decode(log: &Call) -> Result<Self, String>;
+    fn decode(log: &Call) -> Result<Self, DecodeError>;
     fn encode(&self) -> Vec<u8>;
 
     /// Attempts to match and decode the call.
@@ -1,15 +1,25 @@
+/// ABI helpers shared by the bindings generated by `substreams-abigen` and [dynamic].
+pub mod abi;
+pub mod dynamic;
 pub mod pb;
+pub mod revert;
 pub mod rpc;
 
 /// Helpers to deal with block sources.
 pub mod block_view;
+pub use error::DecodeError;
 pub use event::Event;
 pub use function::Function;
 pub mod scalar;
 
+/// Fixed size Ethereum primitive types.
+pub mod types;
+pub use types::{Address, H256};
+
 /// Dependencies needed by 'substreams-abigen' to generate bindings.
 pub use event::IndexedDynamicValue;
 
+mod error;
 mod event;
 mod externs;
 mod function;
@@ -0,0 +1,246 @@
+//! Decoding of the revert data returned by failed calls, see
+//! [crate::block_view::CallView::revert_reason] and
+//! [crate::pb::eth::v2::TransactionTrace::revert_reason].
+
+use std::fmt;
+
+use ethabi::{ParamType, Token};
+use substreams::scalar::BigInt;
+
+/// Selector of `Error(string)`, the revert data of `revert("...")` and `require(cond, "...")`.
+pub const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of `Panic(uint256)`, the revert data of failed `assert`, arithmetic overflows and
+/// the other checks inserted by the Solidity compiler.
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Why a call failed, decoded from its `return_data`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RevertReason {
+    /// Message of a `revert("...")` or `require(cond, "...")`, encoded as `Error(string)`.
+    Error(String),
+    /// Solidity panic, encoded as `Panic(uint256)`.
+    Panic(PanicCode),
+    /// Any other revert data, usually a custom error that can be decoded with the `Errors`
+    /// enum of the contract's generated bindings.
+    Custom(Vec<u8>),
+    /// The call failed without revert data, holding the failure reason reported by the node,
+    /// e.g. `out of gas` or `execution reverted` for a bare `revert()`.
+    Failure(String),
+}
+
+impl RevertReason {
+    /// Decodes revert data, falling back to [RevertReason::Custom] when it's neither an
+    /// `Error(string)` nor a `Panic(uint256)`.
+    pub fn decode(data: &[u8]) -> RevertReason {
+        if data.len() < 4 {
+            return RevertReason::Custom(data.to_vec());
+        }
+
+        let (selector, params) = data.split_at(4);
+        if selector == ERROR_SELECTOR {
+            if let Ok(mut tokens) = ethabi::decode(&[ParamType::String], params) {
+                if let Some(Token::String(message)) = tokens.pop() {
+                    return RevertReason::Error(message);
+                }
+            }
+        } else if selector == PANIC_SELECTOR {
+            if let Ok(mut tokens) = ethabi::decode(&[ParamType::Uint(256)], params) {
+                if let Some(Token::Uint(code)) = tokens.pop() {
+                    let mut bytes = [0u8; 32];
+                    code.to_big_endian(&mut bytes);
+
+                    return RevertReason::Panic(PanicCode::from(BigInt::from_unsigned_bytes_be(
+                        &bytes,
+                    )));
+                }
+            }
+        }
+
+        RevertReason::Custom(data.to_vec())
+    }
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevertReason::Error(message) => f.write_str(message),
+            RevertReason::Panic(code) => write!(f, "panic: {}", code),
+            RevertReason::Custom(data) => write!(f, "custom error 0x{}", hex::encode(data)),
+            RevertReason::Failure(reason) => f.write_str(reason),
+        }
+    }
+}
+
+/// Code of a Solidity panic, see
+/// <https://docs.soliditylang.org/en/latest/control-structures.html#panic-via-assert-and-error-via-require>.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanicCode {
+    /// `0x00`, generic compiler inserted panic.
+    Generic,
+    /// `0x01`, `assert` with a false condition.
+    Assert,
+    /// `0x11`, arithmetic overflow or underflow outside of an `unchecked { ... }` block.
+    ArithmeticOverflow,
+    /// `0x12`, division or modulo by zero.
+    DivisionByZero,
+    /// `0x21`, conversion of a too big or negative value into an enum.
+    InvalidEnumValue,
+    /// `0x22`, access to an incorrectly encoded storage byte array.
+    InvalidStorageByteArray,
+    /// `0x31`, `.pop()` on an empty array.
+    EmptyArrayPop,
+    /// `0x32`, array or slice access out of bounds.
+    IndexOutOfBounds,
+    /// `0x41`, allocation of too much memory or creation of a too large array.
+    OutOfMemory,
+    /// `0x51`, call of a zero-initialized variable of internal function type.
+    ZeroInitializedFunction,
+    /// Any code not defined by the Solidity compiler.
+    Unknown(BigInt),
+}
+
+impl PanicCode {
+    pub fn code(&self) -> BigInt {
+        match self {
+            PanicCode::Generic => BigInt::from(0x00),
+            PanicCode::Assert => BigInt::from(0x01),
+            PanicCode::ArithmeticOverflow => BigInt::from(0x11),
+            PanicCode::DivisionByZero => BigInt::from(0x12),
+            PanicCode::InvalidEnumValue => BigInt::from(0x21),
+            PanicCode::InvalidStorageByteArray => BigInt::from(0x22),
+            PanicCode::EmptyArrayPop => BigInt::from(0x31),
+            PanicCode::IndexOutOfBounds => BigInt::from(0x32),
+            PanicCode::OutOfMemory => BigInt::from(0x41),
+            PanicCode::ZeroInitializedFunction => BigInt::from(0x51),
+            PanicCode::Unknown(code) => code.clone(),
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            PanicCode::Generic => "generic panic",
+            PanicCode::Assert => "assertion failed",
+            PanicCode::ArithmeticOverflow => "arithmetic overflow or underflow",
+            PanicCode::DivisionByZero => "division or modulo by zero",
+            PanicCode::InvalidEnumValue => "invalid enum value",
+            PanicCode::InvalidStorageByteArray => "invalid storage byte array",
+            PanicCode::EmptyArrayPop => "pop on empty array",
+            PanicCode::IndexOutOfBounds => "array index out of bounds",
+            PanicCode::OutOfMemory => "out of memory",
+            PanicCode::ZeroInitializedFunction => "call to zero-initialized function",
+            PanicCode::Unknown(_) => "unknown panic",
+        }
+    }
+}
+
+impl From<BigInt> for PanicCode {
+    fn from(code: BigInt) -> Self {
+        if code.bits() > 8 {
+            return PanicCode::Unknown(code);
+        }
+
+        match code.to_u64() {
+            0x00 => PanicCode::Generic,
+            0x01 => PanicCode::Assert,
+            0x11 => PanicCode::ArithmeticOverflow,
+            0x12 => PanicCode::DivisionByZero,
+            0x21 => PanicCode::InvalidEnumValue,
+            0x22 => PanicCode::InvalidStorageByteArray,
+            0x31 => PanicCode::EmptyArrayPop,
+            0x32 => PanicCode::IndexOutOfBounds,
+            0x41 => PanicCode::OutOfMemory,
+            0x51 => PanicCode::ZeroInitializedFunction,
+            _ => PanicCode::Unknown(code),
+        }
+    }
+}
+
+impl fmt::Display for PanicCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PanicCode::Unknown(code) => write!(f, "unknown panic code {}", code),
+            _ => write!(f, "{} (0x{:02x})", self.description(), self.code().to_u64()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use substreams::scalar::BigInt;
+
+    use super::{PanicCode, RevertReason};
+
+    fn hex(input: &str) -> Vec<u8> {
+        hex::decode(input).unwrap()
+    }
+
+    #[test]
+    fn test_decode_error() {
+        // Error("Ownable: caller is not the owner")
+        let data = hex(concat!(
+            "08c379a0",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "4f776e61626c653a2063616c6c6572206973206e6f7420746865206f776e6572",
+        ));
+
+        let reason = RevertReason::decode(&data);
+        assert_eq!(
+            reason,
+            RevertReason::Error("Ownable: caller is not the owner".to_string())
+        );
+        assert_eq!(reason.to_string(), "Ownable: caller is not the owner");
+    }
+
+    #[test]
+    fn test_decode_panic() {
+        let data = hex(concat!(
+            "4e487b71",
+            "0000000000000000000000000000000000000000000000000000000000000011",
+        ));
+
+        let reason = RevertReason::decode(&data);
+        assert_eq!(reason, RevertReason::Panic(PanicCode::ArithmeticOverflow));
+        assert_eq!(
+            reason.to_string(),
+            "panic: arithmetic overflow or underflow (0x11)"
+        );
+
+        let data = hex(concat!(
+            "4e487b71",
+            "0000000000000000000000000000000000000000000000000000000000000099",
+        ));
+        assert_eq!(
+            RevertReason::decode(&data),
+            RevertReason::Panic(PanicCode::Unknown(BigInt::from(0x99)))
+        );
+        assert_eq!(
+            RevertReason::decode(&data).to_string(),
+            "panic: unknown panic code 153"
+        );
+    }
+
+    #[test]
+    fn test_decode_custom() {
+        // Unauthorized(address)
+        let data = hex(concat!(
+            "8e4a23d6",
+            "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+        ));
+
+        let reason = RevertReason::decode(&data);
+        assert_eq!(reason, RevertReason::Custom(data.clone()));
+        assert_eq!(
+            reason.to_string(),
+            format!("custom error 0x{}", hex::encode(&data))
+        );
+
+        // Truncated `Error(string)` falls back to the raw bytes
+        let data = hex("08c379a000");
+        assert_eq!(
+            RevertReason::decode(&data),
+            RevertReason::Custom(data.clone())
+        );
+    }
+}
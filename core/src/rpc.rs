@@ -1,9 +1,13 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use crate::pb::eth::rpc::{RpcCall, RpcCalls, RpcResponse, RpcResponses};
-use crate::Function;
+use crate::{DecodeError, Function};
 use substreams::proto;
 
 pub trait RPCDecodable<R> {
-    fn output(data: &[u8]) -> Result<R, String>;
+    fn output(data: &[u8]) -> Result<R, DecodeError>;
 }
 
 pub struct RpcBatch {
@@ -33,10 +37,20 @@ impl RpcBatch {
         self
     }
 
+    /// Calls collected so far, in the order they were added.
+    pub fn calls(&self) -> &[RpcCall] {
+        &self.store.calls
+    }
+
     pub fn execute(self) -> Result<RpcResponses, String> {
         Ok(eth_call(&self.store))
     }
 
+    /// Executes the calls against `provider` instead of the current thread's provider.
+    pub fn execute_with(self, provider: &dyn RpcProvider) -> Result<RpcResponses, String> {
+        Ok(provider.eth_call(&self.store))
+    }
+
     pub fn decode<R, T: RPCDecodable<R> + Function>(response: &RpcResponse) -> Option<R> {
         if response.failed {
             return None;
@@ -56,6 +70,142 @@ impl RpcBatch {
     }
 }
 
+/// Executes `eth_call`s, one response per call in the same order.
+///
+/// [eth_call], generated `call()` helpers and [RpcBatch::execute] use the provider set on the
+/// current thread with [set_provider], falling back to the Substreams host ([HostProvider]).
+/// Outside of `wasm32` there is no host, tests set a [MockProvider] instead.
+pub trait RpcProvider {
+    fn eth_call(&self, calls: &RpcCalls) -> RpcResponses;
+}
+
+/// Provider forwarding the calls to the Substreams host, only available on `wasm32`.
+pub struct HostProvider;
+
+impl RpcProvider for HostProvider {
+    fn eth_call(&self, calls: &RpcCalls) -> RpcResponses {
+        let raw_resp: Vec<u8> = eth_call_internal(proto::encode(calls).unwrap());
+
+        proto::decode(&raw_resp).unwrap()
+    }
+}
+
+thread_local! {
+    static PROVIDER: RefCell<Option<Rc<dyn RpcProvider>>> = RefCell::new(None);
+}
+
+/// Sets the provider used by [eth_call] on the current thread, replacing the previous one.
+pub fn set_provider<P: RpcProvider + 'static>(provider: P) {
+    PROVIDER.with(|current| *current.borrow_mut() = Some(Rc::new(provider)));
+}
+
+/// Removes the provider set with [set_provider], [eth_call] goes back to the Substreams host.
+pub fn reset_provider() {
+    PROVIDER.with(|current| *current.borrow_mut() = None);
+}
+
+/// Address and input data of a call.
+type CallKey = (Vec<u8>, Vec<u8>);
+
+type Handler = Box<dyn Fn(&[u8]) -> Option<Vec<u8>>>;
+
+/// In-memory [RpcProvider] answering calls with registered responses, so code performing RPC
+/// calls can be tested natively and deterministically.
+///
+/// A call is answered by the response registered for its exact address and data, otherwise by
+/// the handler registered for its address and selector. Calls matching neither panic, listing
+/// the address and data of the unexpected call.
+///
+/// ```ignore
+/// use substreams_ethereum::rpc::{self, MockProvider};
+///
+/// let balance_of = erc20::functions::BalanceOf { owner: owner.clone() };
+/// rpc::set_provider(
+///     MockProvider::new()
+///         .with_response(token.clone(), balance_of.encode(), BalanceOf::encode_output(&balance))
+///         .with_failure(other_token.clone(), balance_of.encode()),
+/// );
+///
+/// assert_eq!(balance_of.call(token), Some(balance));
+/// assert_eq!(balance_of.call(other_token), None);
+/// ```
+#[derive(Default)]
+pub struct MockProvider {
+    responses: HashMap<CallKey, Option<Vec<u8>>>,
+    handlers: HashMap<(Vec<u8>, [u8; 4]), Handler>,
+}
+
+impl MockProvider {
+    pub fn new() -> MockProvider {
+        MockProvider::default()
+    }
+
+    /// Answers calls to `address` with input `data` with the raw `response`.
+    pub fn with_response(mut self, address: Vec<u8>, data: Vec<u8>, response: Vec<u8>) -> Self {
+        self.responses.insert((address, data), Some(response));
+        self
+    }
+
+    /// Makes calls to `address` with input `data` fail, like a reverting call does.
+    pub fn with_failure(mut self, address: Vec<u8>, data: Vec<u8>) -> Self {
+        self.responses.insert((address, data), None);
+        self
+    }
+
+    /// Answers calls to `address` whose input starts with `selector` by calling `handler` with
+    /// the whole input, the call fails when it returns `None`.
+    pub fn with_handler<H>(mut self, address: Vec<u8>, selector: [u8; 4], handler: H) -> Self
+    where
+        H: Fn(&[u8]) -> Option<Vec<u8>> + 'static,
+    {
+        self.handlers.insert((address, selector), Box::new(handler));
+        self
+    }
+
+    fn response(&self, call: &RpcCall) -> RpcResponse {
+        let response = match self
+            .responses
+            .get(&(call.to_addr.clone(), call.data.clone()))
+        {
+            Some(response) => response.clone(),
+            None => {
+                let handler = call
+                    .data
+                    .get(0..4)
+                    .and_then(|selector| selector.try_into().ok())
+                    .and_then(|selector: [u8; 4]| {
+                        self.handlers.get(&(call.to_addr.clone(), selector))
+                    })
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "no mocked response for call to 0x{} with data 0x{}",
+                            hex::encode(&call.to_addr),
+                            hex::encode(&call.data)
+                        )
+                    });
+
+                handler(&call.data)
+            }
+        };
+
+        match response {
+            Some(raw) => RpcResponse { raw, failed: false },
+            None => RpcResponse {
+                raw: vec![],
+                failed: true,
+            },
+        }
+    }
+}
+
+impl RpcProvider for MockProvider {
+    fn eth_call(&self, calls: &RpcCalls) -> RpcResponses {
+        RpcResponses {
+            responses: calls.calls.iter().map(|call| self.response(call)).collect(),
+        }
+    }
+}
+
 #[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
 fn eth_call_internal(input: Vec<u8>) -> Vec<u8> {
     #[cfg(target_arch = "wasm32")]
@@ -68,12 +218,92 @@ fn eth_call_internal(input: Vec<u8>) -> Vec<u8> {
     }
 
     #[cfg(not(target_arch = "wasm32"))]
-    unimplemented!("this method is not implemented outside of 'wasm32' target compilation")
+    unimplemented!("this method is not implemented outside of 'wasm32' target compilation, use 'rpc::set_provider' to set a provider like 'rpc::MockProvider'")
 }
 
 pub fn eth_call(input: &RpcCalls) -> RpcResponses {
-    let raw_resp: Vec<u8> = eth_call_internal(proto::encode(input).unwrap());
-    let resp: RpcResponses = proto::decode(&raw_resp).unwrap();
+    // Cloned out of the cell so that a provider can itself perform calls
+    let provider = PROVIDER.with(|current| current.borrow().clone());
 
-    return resp;
+    match provider {
+        Some(provider) => provider.eth_call(input),
+        None => HostProvider.eth_call(input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pb::eth::rpc::{RpcCall, RpcCalls, RpcResponse};
+
+    use super::{eth_call, reset_provider, set_provider, MockProvider, RpcBatch};
+
+    fn call(to_addr: &[u8], data: &[u8]) -> RpcCall {
+        RpcCall {
+            to_addr: to_addr.to_vec(),
+            data: data.to_vec(),
+        }
+    }
+
+    fn ok(raw: &[u8]) -> RpcResponse {
+        RpcResponse {
+            raw: raw.to_vec(),
+            failed: false,
+        }
+    }
+
+    fn failed() -> RpcResponse {
+        RpcResponse {
+            raw: vec![],
+            failed: true,
+        }
+    }
+
+    #[test]
+    fn test_mock_provider() {
+        set_provider(
+            MockProvider::new()
+                .with_response(vec![1], vec![0xaa, 0xbb, 0xcc, 0xdd, 1], vec![42])
+                .with_failure(vec![1], vec![0xaa, 0xbb, 0xcc, 0xdd, 2])
+                .with_handler(vec![2], [0xaa, 0xbb, 0xcc, 0xdd], |data| match data[4] {
+                    0 => None,
+                    value => Some(vec![value * 2]),
+                }),
+        );
+
+        let responses = eth_call(&RpcCalls {
+            calls: vec![
+                call(&[1], &[0xaa, 0xbb, 0xcc, 0xdd, 1]),
+                call(&[1], &[0xaa, 0xbb, 0xcc, 0xdd, 2]),
+                call(&[2], &[0xaa, 0xbb, 0xcc, 0xdd, 3]),
+                call(&[2], &[0xaa, 0xbb, 0xcc, 0xdd, 0]),
+            ],
+        });
+        assert_eq!(
+            responses.responses,
+            vec![ok(&[42]), failed(), ok(&[6]), failed()]
+        );
+
+        reset_provider();
+    }
+
+    #[test]
+    fn test_execute_with() {
+        let provider = MockProvider::new().with_response(vec![1], vec![2], vec![3]);
+
+        let mut batch = RpcBatch::new();
+        batch.store.calls.push(call(&[1], &[2]));
+
+        let responses = batch.execute_with(&provider).unwrap();
+        assert_eq!(responses.responses, vec![ok(&[3])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no mocked response for call to 0x01 with data 0x02")]
+    fn test_mock_provider_unexpected_call() {
+        set_provider(MockProvider::new());
+
+        eth_call(&RpcCalls {
+            calls: vec![call(&[1], &[2])],
+        });
+    }
 }
@@ -0,0 +1,395 @@
+use std::fmt;
+use std::str::FromStr;
+
+use tiny_keccak::{Hasher, Keccak};
+
+/// A 20 bytes Ethereum address.
+///
+/// Displayed (and debugged) as a `0x` prefixed [EIP-55](https://eips.ethereum.org/EIPS/eip-55)
+/// checksummed hexadecimal string, use `{:x}` to get the lowercase hexadecimal form instead.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Address(pub [u8; 20]);
+
+/// A 32 bytes hash, like a block hash, a transaction hash or a storage key.
+///
+/// Displayed (and debugged) as a `0x` prefixed lowercase hexadecimal string.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct H256(pub [u8; 32]);
+
+/// Error returned when converting bytes or a string into an [Address] or a [H256].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    /// The input does not contain the expected number of bytes.
+    InvalidLength { expected: usize, actual: usize },
+    /// The hexadecimal string, without its `0x` prefix, does not contain the expected number
+    /// of characters (two per byte).
+    InvalidHexLength { expected: usize, actual: usize },
+    /// The input is not a valid hexadecimal string.
+    InvalidHex(hex::FromHexError),
+    /// The mixed-case address does not match its EIP-55 checksum.
+    InvalidChecksum,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::InvalidLength { expected, actual } => {
+                write!(f, "expected {} bytes, got {}", expected, actual)
+            }
+            ConversionError::InvalidHexLength { expected, actual } => write!(
+                f,
+                "expected {} hexadecimal characters, got {}",
+                expected, actual
+            ),
+            ConversionError::InvalidHex(err) => write!(f, "invalid hexadecimal string: {}", err),
+            ConversionError::InvalidChecksum => write!(f, "invalid EIP-55 address checksum"),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+macro_rules! impl_fixed_bytes {
+    ($name:ident, $len:expr) => {
+        impl $name {
+            /// Number of bytes of the type.
+            pub const LEN: usize = $len;
+
+            /// Returns the value with all bytes set to zero.
+            pub const fn zero() -> Self {
+                $name([0u8; $len])
+            }
+
+            /// Returns `true` if all bytes are zero.
+            pub fn is_zero(&self) -> bool {
+                self.0.iter().all(|byte| *byte == 0)
+            }
+
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            pub fn to_vec(&self) -> Vec<u8> {
+                self.0.to_vec()
+            }
+
+            /// Creates the value from `bytes`, failing if it's not exactly
+            #[doc = concat!(stringify!($len), " bytes long.")]
+            pub fn from_slice(bytes: &[u8]) -> Result<Self, ConversionError> {
+                let value: [u8; $len] =
+                    bytes
+                        .try_into()
+                        .map_err(|_| ConversionError::InvalidLength {
+                            expected: $len,
+                            actual: bytes.len(),
+                        })?;
+
+                Ok($name(value))
+            }
+
+            fn from_hex(input: &str) -> Result<Self, ConversionError> {
+                let input = input
+                    .strip_prefix("0x")
+                    .or_else(|| input.strip_prefix("0X"))
+                    .unwrap_or(input);
+
+                if input.len() != $len * 2 {
+                    return Err(ConversionError::InvalidHexLength {
+                        expected: $len * 2,
+                        actual: input.len(),
+                    });
+                }
+
+                let mut value = [0u8; $len];
+                hex::decode_to_slice(input, &mut value).map_err(ConversionError::InvalidHex)?;
+
+                Ok($name(value))
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                $name(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $len] {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$name> for Vec<u8> {
+            fn from(value: $name) -> Self {
+                value.0.to_vec()
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = ConversionError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                $name::from_slice(bytes)
+            }
+        }
+
+        impl TryFrom<&Vec<u8>> for $name {
+            type Error = ConversionError;
+
+            fn try_from(bytes: &Vec<u8>) -> Result<Self, Self::Error> {
+                $name::from_slice(bytes)
+            }
+        }
+
+        impl TryFrom<Vec<u8>> for $name {
+            type Error = ConversionError;
+
+            fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+                $name::from_slice(&bytes)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl PartialEq<[u8]> for $name {
+            fn eq(&self, other: &[u8]) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<Vec<u8>> for $name {
+            fn eq(&self, other: &Vec<u8>) -> bool {
+                self.0 == other.as_slice()
+            }
+        }
+
+        impl fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    f.write_str("0x")?;
+                }
+
+                f.write_str(&hex::encode(self.0))
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+    };
+}
+
+impl_fixed_bytes!(Address, 20);
+impl_fixed_bytes!(H256, 32);
+
+impl Address {
+    /// Returns the `0x` prefixed [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksummed
+    /// representation of the address.
+    pub fn to_checksum(&self) -> String {
+        let lower = hex::encode(self.0);
+        let hash = keccak256(lower.as_bytes());
+
+        let mut checksummed = String::with_capacity(2 + lower.len());
+        checksummed.push_str("0x");
+        for (i, c) in lower.chars().enumerate() {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if nibble >= 8 {
+                checksummed.push(c.to_ascii_uppercase());
+            } else {
+                checksummed.push(c);
+            }
+        }
+
+        checksummed
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_checksum())
+    }
+}
+
+impl FromStr for Address {
+    type Err = ConversionError;
+
+    /// Parses an hexadecimal address, with or without the `0x` prefix. An all lowercase or
+    /// all uppercase address is accepted as-is, a mixed-case address must have a valid EIP-55
+    /// checksum.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let address = Address::from_hex(input)?;
+
+        let digits = input
+            .strip_prefix("0x")
+            .or_else(|| input.strip_prefix("0X"))
+            .unwrap_or(input);
+        let has_lower = digits.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = digits.chars().any(|c| c.is_ascii_uppercase());
+        if has_lower && has_upper && address.to_checksum()[2..] != *digits {
+            return Err(ConversionError::InvalidChecksum);
+        }
+
+        Ok(address)
+    }
+}
+
+impl From<ethabi::Address> for Address {
+    fn from(address: ethabi::Address) -> Self {
+        Address(address.to_fixed_bytes())
+    }
+}
+
+impl From<Address> for ethabi::Address {
+    fn from(address: Address) -> Self {
+        ethabi::Address::from(address.0)
+    }
+}
+
+impl fmt::Display for H256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self)
+    }
+}
+
+impl FromStr for H256 {
+    type Err = ConversionError;
+
+    /// Parses an hexadecimal hash, with or without the `0x` prefix.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        H256::from_hex(input)
+    }
+}
+
+impl From<ethabi::Hash> for H256 {
+    fn from(hash: ethabi::Hash) -> Self {
+        H256(hash.to_fixed_bytes())
+    }
+}
+
+impl From<H256> for ethabi::Hash {
+    fn from(hash: H256) -> Self {
+        ethabi::Hash::from(hash.0)
+    }
+}
+
+pub(crate) fn keccak256(input: &[u8]) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    let mut output = [0u8; 32];
+    keccak.update(input);
+    keccak.finalize(&mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    use super::{Address, ConversionError, H256};
+    use substreams::hex;
+
+    #[test]
+    fn address_display_is_eip55_checksummed() {
+        let addresses = vec![
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+
+        for expected in addresses {
+            let address = Address::from_str(&expected.to_lowercase()).unwrap();
+            assert_eq!(address.to_string(), expected);
+            assert_eq!(format!("{:?}", address), expected);
+            assert_eq!(format!("{:x}", address), expected[2..].to_lowercase());
+            assert_eq!(format!("{:#x}", address), expected.to_lowercase());
+        }
+    }
+
+    #[test]
+    fn address_from_str() {
+        let expected = Address(hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+
+        assert_eq!(
+            Address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Ok(expected)
+        );
+        assert_eq!(
+            Address::from_str("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            Ok(expected)
+        );
+        assert_eq!(
+            Address::from_str("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"),
+            Ok(expected)
+        );
+        assert_eq!(
+            Address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            Err(ConversionError::InvalidChecksum)
+        );
+        assert_eq!(
+            Address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea"),
+            Err(ConversionError::InvalidHexLength {
+                expected: 40,
+                actual: 38
+            })
+        );
+        assert_eq!(
+            Address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed0")
+                .unwrap_err()
+                .to_string(),
+            "expected 40 hexadecimal characters, got 41"
+        );
+        assert!(matches!(
+            Address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beazz"),
+            Err(ConversionError::InvalidHex(_))
+        ));
+    }
+
+    #[test]
+    fn h256_display_and_from_str() {
+        let input = "0x4072b13fbf31a77f9aa5b4a956c5f20675db5db35d2a8fd8ea34c51832f0f940";
+        let hash = H256::from_str(input).unwrap();
+
+        assert_eq!(hash.to_string(), input);
+        assert_eq!(format!("{:?}", hash), input);
+        assert_eq!(H256::from_str(&input[2..]), Ok(hash));
+    }
+
+    #[test]
+    fn conversions_from_bytes() {
+        let bytes = hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").to_vec();
+
+        let address = Address::try_from(&bytes).unwrap();
+        assert_eq!(address, bytes);
+        assert_eq!(Vec::<u8>::from(address), bytes);
+        assert_eq!(Address::try_from(bytes.as_slice()), Ok(address));
+        assert_eq!(
+            H256::try_from(bytes),
+            Err(ConversionError::InvalidLength {
+                expected: 32,
+                actual: 20
+            })
+        );
+
+        let ethabi_address = ethabi::Address::from(address);
+        assert_eq!(Address::from(ethabi_address), address);
+    }
+
+    #[test]
+    fn hash_and_ord() {
+        let low = Address(hex!("0000000000000000000000000000000000000001"));
+        let high = Address(hex!("1000000000000000000000000000000000000000"));
+
+        assert!(low < high);
+        assert!(Address::zero().is_zero());
+
+        let set: HashSet<Address> = vec![low, high, low].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+}
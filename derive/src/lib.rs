@@ -11,6 +11,7 @@
 extern crate proc_macro;
 
 use ethabi::{Error, Result};
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
 use std::borrow::Cow;
 
 const ERROR_MSG: &str = "`derive(EthabiContract)` in substreams-ethereum failed";
@@ -24,16 +25,58 @@ pub fn ethabi_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
 fn impl_ethabi_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream> {
     let options = get_options(&ast.attrs, "ethabi_contract_options")?;
-    let path = get_option(&options, "path")?;
 
-    substreams_ethereum_abigen::generate_abi_code(path)
-        .map_err(|e| Error::Other(Cow::Owned(format!("{}", e))))
+    let mut abigen_options = substreams_ethereum_abigen::Options::default();
+    if let Some(enabled) = get_bool_option(&options, "address_types")? {
+        abigen_options.address_types = enabled;
+    }
+    if let Some(enabled) = get_bool_option(&options, "native_ints")? {
+        abigen_options.native_ints = enabled;
+    }
+    if let Some(enabled) = get_bool_option(&options, "rpc_all_functions")? {
+        abigen_options.rpc_all_functions = enabled;
+    }
+    if let Some(enabled) = get_bool_option(&options, "typed_overload_names")? {
+        abigen_options.typed_overload_names = enabled;
+    }
+    if let Some(events) = get_list_option(&options, "include_events")? {
+        abigen_options.events.include = events;
+    }
+    if let Some(events) = get_list_option(&options, "exclude_events")? {
+        abigen_options.events.exclude = events;
+    }
+    if let Some(functions) = get_list_option(&options, "include_functions")? {
+        abigen_options.functions.include = functions;
+    }
+    if let Some(functions) = get_list_option(&options, "exclude_functions")? {
+        abigen_options.functions.exclude = functions;
+    }
+
+    let generated = match get_list_option(&options, "signatures")? {
+        Some(signatures) => {
+            substreams_ethereum_abigen::generate_abi_code_from_signatures_with_options(
+                &signatures,
+                &abigen_options,
+            )
+        }
+        None => {
+            let path = get_option(&options, "path")?;
+            substreams_ethereum_abigen::generate_abi_code_with_options(path, &abigen_options)
+        }
+    };
+
+    generated.map_err(|e| Error::Other(Cow::Owned(format!("{}", e))))
 }
 
 fn get_options(attrs: &[syn::Attribute], name: &str) -> Result<Vec<syn::NestedMeta>> {
     let options = attrs
         .iter()
-        .flat_map(syn::Attribute::parse_meta)
+        .filter(|attr| attr.path.is_ident(name))
+        .flat_map(|attr| {
+            let mut attr = attr.clone();
+            attr.tokens = bracket_lists_to_meta_lists(attr.tokens);
+            attr.parse_meta()
+        })
         .find(|meta| meta.path().is_ident(name));
 
     match options {
@@ -42,6 +85,54 @@ fn get_options(attrs: &[syn::Attribute], name: &str) -> Result<Vec<syn::NestedMe
     }
 }
 
+/// Rewrites the `name = ["a", "b"]` options, as written in `use_contract!`, into the
+/// `name("a", "b")` form that can be parsed as a meta list.
+fn bracket_lists_to_meta_lists(tokens: TokenStream) -> TokenStream {
+    let mut output = Vec::new();
+    let mut trees = tokens.into_iter().peekable();
+
+    while let Some(tree) = trees.next() {
+        match tree {
+            TokenTree::Punct(ref punct) if punct.as_char() == '=' => {
+                match trees.peek().and_then(bracket_list) {
+                    Some(list) => {
+                        trees.next();
+                        output.push(TokenTree::Group(Group::new(Delimiter::Parenthesis, list)));
+                    }
+                    None => output.push(tree),
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut rewritten = Group::new(
+                    group.delimiter(),
+                    bracket_lists_to_meta_lists(group.stream()),
+                );
+                rewritten.set_span(group.span());
+                output.push(TokenTree::Group(rewritten));
+            }
+            tree => output.push(tree),
+        }
+    }
+
+    output.into_iter().collect()
+}
+
+/// Returns the content of a `[...]` list, which `macro_rules!` can wrap in an invisible group
+/// when it's captured as an `expr`.
+fn bracket_list(tree: &TokenTree) -> Option<TokenStream> {
+    match tree {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => Some(group.stream()),
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+            let mut inner = group.stream().into_iter();
+            match (inner.next(), inner.next()) {
+                (Some(tree), None) => bracket_list(&tree),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 fn get_option(options: &[syn::NestedMeta], name: &str) -> Result<String> {
     let item = options
         .iter()
@@ -55,6 +146,71 @@ fn get_option(options: &[syn::NestedMeta], name: &str) -> Result<String> {
     str_value_of_meta_item(item, name)
 }
 
+fn get_bool_option(options: &[syn::NestedMeta], name: &str) -> Result<Option<bool>> {
+    let item = options
+        .iter()
+        .flat_map(|nested| match *nested {
+            syn::NestedMeta::Meta(ref meta) => Some(meta),
+            _ => None,
+        })
+        .find(|meta| meta.path().is_ident(name));
+
+    match item {
+        Some(item) => bool_value_of_meta_item(item, name).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn get_list_option(options: &[syn::NestedMeta], name: &str) -> Result<Option<Vec<String>>> {
+    let item = options
+        .iter()
+        .flat_map(|nested| match *nested {
+            syn::NestedMeta::Meta(ref meta) => Some(meta),
+            _ => None,
+        })
+        .find(|meta| meta.path().is_ident(name));
+
+    match item {
+        Some(item) => str_values_of_meta_item(item, name).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn str_values_of_meta_item(item: &syn::Meta, name: &str) -> Result<Vec<String>> {
+    if let syn::Meta::List(ref list) = *item {
+        let values: Option<Vec<_>> = list
+            .nested
+            .iter()
+            .map(|nested| match nested {
+                syn::NestedMeta::Lit(syn::Lit::Str(value)) => Some(value.value()),
+                _ => None,
+            })
+            .collect();
+
+        if let Some(values) = values {
+            return Ok(values);
+        }
+    }
+
+    Err(Error::Other(Cow::Owned(format!(
+        r#"`{}` must be in the form `#[{}("something", "else")]`"#,
+        name, name
+    ))))
+}
+
+fn bool_value_of_meta_item(item: &syn::Meta, name: &str) -> Result<bool> {
+    if let syn::Meta::NameValue(ref name_value) = *item {
+        if let syn::Lit::Bool(ref value) = name_value.lit {
+            return Ok(value.value);
+        }
+    }
+
+    Err(Error::Other(Cow::Owned(format!(
+        r#"`{}` must be in the form `#[{}=true]`"#,
+        name, name
+    ))))
+}
+
 fn str_value_of_meta_item(item: &syn::Meta, name: &str) -> Result<String> {
     if let syn::Meta::NameValue(ref name_value) = *item {
         if let syn::Lit::Str(ref value) = name_value.lit {
@@ -1,5 +1,8 @@
 pub use substreams_ethereum_core::scalar;
-pub use substreams_ethereum_core::{block_view, pb, rpc, Event, Function, NULL_ADDRESS};
+pub use substreams_ethereum_core::{
+    block_view, dynamic, pb, revert, rpc, types, Address, DecodeError, Event, Function, H256,
+    NULL_ADDRESS,
+};
 pub use substreams_ethereum_derive::EthabiContract;
 
 // Those are dependencies that needs to be exported for `substreams-abigen` to work. Must not
@@ -36,6 +39,10 @@ pub use substreams_ethereum_abigen::build::Abigen;
 /// required bindings for ABI decoding/encoding in Rust, targetting `substreams` developer
 /// experience. You prefer to have the code generated directly, check out [Abigen].
 ///
+/// The file can also be a Hardhat, Foundry or Truffle compilation artifact (e.g. Foundry's
+/// `out/Pool.sol/Pool.json`), the ABI is then read from its `abi` key, or a solc standard JSON
+/// output holding a single contract.
+///
 /// ```no_run
 ///     use substreams_ethereum::use_contract;
 ///
@@ -60,7 +67,7 @@ pub use substreams_ethereum_abigen::build::Abigen;
 ///                # todo!()
 ///             }
 ///
-///             pub fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Transfer, String> {
+///             pub fn decode(log: &substreams_ethereum::pb::eth::v2::Log) -> Result<Transfer, substreams_ethereum::DecodeError> {
 ///                // ...
 ///                # todo!()
 ///             }
@@ -70,8 +77,52 @@ pub use substreams_ethereum_abigen::build::Abigen;
 ///     }
 /// }
 /// ```
+///
+/// Options tweaking the generated code can be passed after the path:
+///
+/// - `address_types = true` generates [Address] and [H256] for `address` and `bytes32` params
+///   instead of `Vec<u8>` and `[u8; 32]`.
+/// - `native_ints = true` generates `u8` to `u128` and `i8` to `i128` for `uintN` and `intN`
+///   params up to 128 bits instead of `substreams::scalar::BigInt`.
+/// - `rpc_all_functions = true` generates the RPC `call` helper for every function with outputs,
+///   not only the `view` and `pure` ones.
+/// - `typed_overload_names = true` names overloaded functions and events after their params
+///   types (e.g. `SafeTransferFromAddressAddressUint256Bytes`) instead of an index.
+/// - `include_events = ["Transfer"]`, `exclude_events`, `include_functions` and
+///   `exclude_functions` select the events and functions to generate by ABI name or by
+///   canonical signature (e.g. `"balanceOf(address)"`).
+///
+/// ```no_run
+///     use substreams_ethereum::use_contract;
+///
+///     use_contract!(erc721, "./examples/abi/erc721.json", address_types = true, native_ints = true);
+/// ```
+///
+/// Instead of a file, the contract can be described by human-readable (ethers style) signatures
+/// of the events, functions and errors that are needed, the options can be passed after them too:
+///
+/// ```no_run
+///     use substreams_ethereum::use_contract;
+///
+///     use_contract!(erc20, [
+///         "event Transfer(address indexed from, address indexed to, uint256 value)",
+///         "function balanceOf(address owner) view returns (uint256)",
+///     ]);
+/// ```
 #[macro_export]
 macro_rules! use_contract {
+    ($module: ident, [$($signature: literal),+ $(,)?] $(, $option: ident = $value: expr)* $(,)?) => {
+        #[allow(dead_code)]
+        #[allow(missing_docs)]
+        #[allow(unused_imports)]
+        #[allow(unused_mut)]
+        #[allow(unused_variables)]
+        pub mod $module {
+            #[derive(substreams_ethereum::EthabiContract)]
+            #[ethabi_contract_options(signatures($($signature),+) $(, $option = $value)*)]
+            struct _Dummy;
+        }
+    };
     ($module: ident, $path: expr) => {
         #[allow(dead_code)]
         #[allow(missing_docs)]
@@ -84,6 +135,18 @@ macro_rules! use_contract {
             struct _Dummy;
         }
     };
+    ($module: ident, $path: expr, $($option: ident = $value: expr),+ $(,)?) => {
+        #[allow(dead_code)]
+        #[allow(missing_docs)]
+        #[allow(unused_imports)]
+        #[allow(unused_mut)]
+        #[allow(unused_variables)]
+        pub mod $module {
+            #[derive(substreams_ethereum::EthabiContract)]
+            #[ethabi_contract_options(path = $path, $($option = $value),+)]
+            struct _Dummy;
+        }
+    };
 }
 
 /// The `init` macro registers a custom get random function in the system which is required